@@ -5,7 +5,7 @@
 
 use tokio::sync::{mpsc, watch};
 
-use crate::common::messages::DashboardEvent;
+use crate::common::messages::{ChannelEvent, DashboardEvent, SocialNotice};
 use crate::common::{ActivityStatus, BridgeCommand, BridgeMessage};
 use crate::discord::commands::CommandResponse;
 
@@ -30,6 +30,10 @@ pub struct GameChannels {
     pub status_tx: mpsc::UnboundedSender<ActivityStatus>,
     /// Sender for dashboard updates (Game -> Discord).
     pub dashboard_tx: mpsc::UnboundedSender<DashboardEvent>,
+    /// Sender for custom channel events (Game -> Discord).
+    pub channel_event_tx: mpsc::UnboundedSender<ChannelEvent>,
+    /// Sender for answered social requests (Game -> Discord).
+    pub social_notice_tx: mpsc::UnboundedSender<SocialNotice>,
 }
 
 /// Channels for the Discord handler.
@@ -46,6 +50,10 @@ pub struct DiscordSideChannels {
     pub status_rx: mpsc::UnboundedReceiver<ActivityStatus>,
     /// Receiver for dashboard updates.
     pub dashboard_rx: mpsc::UnboundedReceiver<DashboardEvent>,
+    /// Receiver for custom channel events.
+    pub channel_event_rx: mpsc::UnboundedReceiver<ChannelEvent>,
+    /// Receiver for answered social requests.
+    pub social_notice_rx: mpsc::UnboundedReceiver<SocialNotice>,
 }
 
 /// Control channels for shutdown coordination.
@@ -85,6 +93,8 @@ impl ChannelBundle {
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
         let (status_tx, status_rx) = mpsc::unbounded_channel();
         let (dashboard_tx, dashboard_rx) = mpsc::unbounded_channel();
+        let (channel_event_tx, channel_event_rx) = mpsc::unbounded_channel();
+        let (social_notice_tx, social_notice_rx) = mpsc::unbounded_channel();
 
         Self {
             game: GameChannels {
@@ -96,6 +106,8 @@ impl ChannelBundle {
                 shutdown_rx,
                 status_tx,
                 dashboard_tx,
+                channel_event_tx,
+                social_notice_tx,
             },
             discord: DiscordSideChannels {
                 wow_rx,
@@ -103,6 +115,8 @@ impl ChannelBundle {
                 cmd_response_rx,
                 status_rx,
                 dashboard_rx,
+                channel_event_rx,
+                social_notice_rx,
             },
             control: ControlChannels { shutdown_tx },
         }
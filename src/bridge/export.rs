@@ -0,0 +1,180 @@
+//! Guild roster export.
+//!
+//! Serialises the guild roster to CSV or JSON for `!export roster`, one row
+//! per member with rank, zone and class names resolved.
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+
+use crate::common::resources::get_zone_name;
+use crate::common::types::{ExportFormat, GuildMember};
+
+/// Column names, in order.
+const COLUMNS: [&str; 9] = [
+    "name",
+    "level",
+    "class",
+    "rank",
+    "zone",
+    "online",
+    "last_logoff",
+    "note",
+    "officer_note",
+];
+
+/// File name for a roster export taken at `now`.
+pub fn roster_filename(format: ExportFormat, now: DateTime<Utc>) -> String {
+    format!("roster-{}.{}", now.format("%Y-%m-%d"), format.extension())
+}
+
+/// Serialise the roster in the given format.
+///
+/// Offline members' `last_logoff` is turned into a UTC timestamp relative to `now`.
+pub fn export_roster(members: &[GuildMember], format: ExportFormat, now: DateTime<Utc>) -> String {
+    match format {
+        ExportFormat::Csv => roster_csv(members, now),
+        ExportFormat::Json => roster_json(members, now),
+    }
+}
+
+fn roster_csv(members: &[GuildMember], now: DateTime<Utc>) -> String {
+    let mut lines = vec![COLUMNS.join(",")];
+    for m in members {
+        let fields = [
+            m.name.clone(),
+            m.level.to_string(),
+            class_name(m).to_string(),
+            m.rank_name.clone(),
+            get_zone_name(m.zone_id).to_string(),
+            m.online.to_string(),
+            last_logoff(m, now).unwrap_or_default(),
+            m.note.clone(),
+            m.officer_note.clone(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        lines.push(fields.join(","));
+    }
+    lines.join("\r\n") + "\r\n"
+}
+
+fn roster_json(members: &[GuildMember], now: DateTime<Utc>) -> String {
+    let rows: Vec<_> = members
+        .iter()
+        .map(|m| {
+            json!({
+                "name": m.name,
+                "level": m.level,
+                "class": class_name(m),
+                "rank": m.rank_name,
+                "zone": get_zone_name(m.zone_id),
+                "online": m.online,
+                "last_logoff": last_logoff(m, now),
+                "note": m.note,
+                "officer_note": m.officer_note,
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&rows).unwrap_or_default()
+}
+
+fn class_name(member: &GuildMember) -> &'static str {
+    member.class.map(|c| c.name()).unwrap_or("Unknown")
+}
+
+/// Last logoff as a UTC timestamp, `None` for online members.
+fn last_logoff(member: &GuildMember, now: DateTime<Utc>) -> Option<String> {
+    if member.online {
+        return None;
+    }
+    let ago = Duration::seconds((member.last_logoff as f64 * 86400.0).round() as i64);
+    Some((now - ago).format("%Y-%m-%d %H:%M").to_string())
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break.
+///
+/// Fields a spreadsheet would read as a formula (notes are member-editable)
+/// are prefixed with `'` so they open as plain text.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::resources::Class;
+    use chrono::TimeZone;
+
+    fn member(name: &str, online: bool, last_logoff: f32, note: &str) -> GuildMember {
+        GuildMember {
+            guid: 0,
+            name: name.to_string(),
+            level: 80,
+            class: Some(Class::Mage),
+            rank: 1,
+            rank_name: "Officer".to_string(),
+            zone_id: 0,
+            online,
+            last_logoff,
+            note: note.to_string(),
+            officer_note: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_roster_csv() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 2, 12, 0, 0).unwrap();
+        let members = vec![
+            member("Jaina", true, 0.0, "Main"),
+            member("Thrall", false, 1.5, "Alt of \"Go'el\", tank"),
+            member("Garrosh", true, 0.0, "=HYPERLINK(\"http://x\")"),
+            member("Sylvanas", true, 0.0, "@SUM(A1)"),
+        ];
+
+        let csv = export_roster(&members, ExportFormat::Csv, now);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], COLUMNS.join(","));
+        assert_eq!(
+            lines[1],
+            format!("Jaina,80,Mage,Officer,{},true,,Main,", get_zone_name(0))
+        );
+        assert_eq!(
+            lines[2],
+            format!(
+                "Thrall,80,Mage,Officer,{},false,2024-06-01 00:00,\"Alt of \"\"Go'el\"\", tank\",",
+                get_zone_name(0)
+            )
+        );
+        assert!(lines[3].ends_with(",\"'=HYPERLINK(\"\"http://x\"\")\","));
+        assert!(lines[4].ends_with(",'@SUM(A1),"));
+        assert_eq!(
+            roster_filename(ExportFormat::Csv, now),
+            "roster-2024-06-02.csv"
+        );
+    }
+
+    #[test]
+    fn test_roster_json() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 2, 12, 0, 0).unwrap();
+        let members = vec![
+            member("Jaina", true, 0.0, ""),
+            member("Thrall", false, 2.0, ""),
+        ];
+
+        let json = export_roster(&members, ExportFormat::Json, now);
+        let rows: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(rows[0]["name"], "Jaina");
+        assert_eq!(rows[0]["class"], "Mage");
+        assert_eq!(rows[0]["last_logoff"], serde_json::Value::Null);
+        assert_eq!(rows[1]["rank"], "Officer");
+        assert_eq!(rows[1]["last_logoff"], "2024-05-31 12:00");
+    }
+}
@@ -7,17 +7,19 @@
 //! ## Module Structure
 //!
 //! - `channels`: Communication channel structures
+//! - `export`: Guild roster export (CSV/JSON)
 //! - `orchestrator`: Main bridge orchestrator (`Bridge` struct)
 //! - `state`: Bridge state types (pending, resolved, task contexts)
 
 pub mod channels;
+pub mod export;
 pub mod filter;
 pub mod orchestrator;
 pub mod state;
 
 // Re-export main types for convenience
 pub use channels::{ChannelBundle, GameChannels};
-pub use orchestrator::Bridge;
+pub use orchestrator::{Bridge, DiscordTarget};
 pub use state::{
     parse_channel_config, BridgeConfig, ChannelConfig, PendingBridgeState, ResolvedBridgeState,
 };
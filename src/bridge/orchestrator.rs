@@ -6,11 +6,15 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use tracing::{debug, info};
+use serenity::model::id::ChannelId;
+use tracing::{debug, info, warn};
 
 use crate::common::messages::split_message;
 use crate::common::resources::get_zone_name;
-use crate::common::types::{ChatType, GuildMember};
+use crate::common::types::{
+    ActivitySummary, ChannelMember, ChatType, CustomChannel, EntityKind, GuildMember, NearbyEntity,
+    WhoPlayer,
+};
 use crate::common::{BridgeMessage, CommandResponseData, DiscordMessage};
 use crate::config::types::{ChannelMapping, ChatConfig, Config, Direction, FiltersConfig};
 use crate::discord::resolver::MessageResolver;
@@ -19,7 +23,25 @@ use crate::game::formatter::{
 };
 
 use super::filter::{FilterDirection, MessageFilter};
-use super::state::{parse_channel_config, BridgeConfig};
+use super::state::{parse_channel_config, BridgeConfig, EventChannel};
+
+/// Discord destination of a message from WoW.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscordTarget {
+    /// Discord channel of a chat mapping, as configured.
+    Mapped(String),
+    /// Resolved guild event channel override.
+    Channel(ChannelId),
+}
+
+impl std::fmt::Display for DiscordTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscordTarget::Mapped(name) => write!(f, "{}", name),
+            DiscordTarget::Channel(id) => write!(f, "{}", id),
+        }
+    }
+}
 
 /// The main bridge that orchestrates message flow.
 pub struct Bridge {
@@ -65,8 +87,15 @@ impl Bridge {
     }
 
     /// Get the list of custom channels to join in WoW.
-    pub fn channels_to_join(&self) -> Vec<String> {
-        self.router.get_channels_to_join()
+    pub fn channels_to_join(&self) -> Vec<CustomChannel> {
+        self.router
+            .get_channels_to_join()
+            .into_iter()
+            .map(|name| CustomChannel {
+                password: self.router.get_channel_password(&name),
+                name,
+            })
+            .collect()
     }
 
     /// Process a dot command message from Discord and prepare for WoW.
@@ -228,17 +257,23 @@ impl Bridge {
 
     /// Process a message from WoW and prepare for Discord.
     ///
+    /// `content` is the pre-processed message text. Guild events with a channel
+    /// override go to the channel it resolved to in `event_channels`.
+    ///
     /// Returns the formatted Discord messages with filtering applied.
     /// Messages that fail filtering are excluded from results.
     pub fn handle_wow_to_discord(
         &self,
-        chat_type: u8,
-        channel_name: Option<&str>,
-        sender: Option<&str>,
+        msg: &BridgeMessage,
         content: &str,
-        format_override: Option<&str>,
-        guild_event: Option<&crate::common::messages::GuildEventInfo>,
-    ) -> Vec<(String, String)> {
+        event_channels: &HashMap<String, EventChannel>,
+    ) -> Vec<(DiscordTarget, String)> {
+        let chat_type = msg.chat_type;
+        let channel_name = msg.channel_name.as_deref();
+        let sender = msg.sender.as_deref();
+        let format_override = msg.format.as_deref();
+        let guild_event = msg.guild_event.as_ref();
+
         // Check if this is a guild event and if it's enabled
         let event_name = guild_event.as_ref().map(|e| e.event_name.as_str());
         if let Some(event_name) = event_name {
@@ -257,14 +292,48 @@ impl Bridge {
             .router
             .get_discord_targets(chat_type, channel_name_lower.as_deref());
 
-        if routes.is_empty() {
+        // Guild events with a channel override go there instead of the guild chat channel,
+        // using the guild chat channel's format as the fallback
+        let event_channel = event_name.and_then(|e| {
+            let configured = self.config.guild.get_event_channel(e)?;
+            let resolved = event_channels.get(e);
+            if resolved.is_none() {
+                warn!(
+                    "Guild event '{}' channel '{}' is not resolved, sending to the guild chat channels",
+                    e, configured
+                );
+            }
+            resolved
+        });
+        // (target, default format, per-channel filter key)
+        let targets: Vec<(DiscordTarget, &str, Option<&str>)> = match event_channel {
+            Some(event_channel) => vec![(
+                DiscordTarget::Channel(event_channel.id),
+                routes.first().map_or(DEFAULT_WOW_TO_DISCORD_FORMAT, |r| {
+                    r.wow_to_discord_format.as_str()
+                }),
+                event_channel.mapping.as_deref(),
+            )],
+            None => routes
+                .iter()
+                .map(|r| {
+                    (
+                        DiscordTarget::Mapped(r.discord_channel_name.clone()),
+                        r.wow_to_discord_format.as_str(),
+                        Some(r.discord_channel_name.as_str()),
+                    )
+                })
+                .collect(),
+        };
+
+        if targets.is_empty() {
             debug!(chat_type, channel_name, "No Discord route for WoW message");
             return Vec::new();
         }
 
         let mut results = Vec::new();
 
-        for route in routes {
+        for (discord_target, default_format, filter_key) in targets {
             // Get format (use override if provided, otherwise use config or default)
             // For guild events, look up format from guild event config
             let format = format_override
@@ -277,7 +346,7 @@ impl Bridge {
                         None
                     }
                 })
-                .unwrap_or_else(|| route.wow_to_discord_format.clone());
+                .unwrap_or_else(|| default_format.to_string());
 
             let formatter = MessageFormatter::new(&format);
             let target = guild_event
@@ -307,22 +376,22 @@ impl Bridge {
             {
                 info!(
                     chat_type,
-                    discord_channel = %route.discord_channel_name,
+                    discord_channel = %discord_target,
                     "FILTERED WoW -> Discord (global) [{}]: {}",
-                    route.discord_channel_name,
+                    discord_target,
                     formatted
                 );
                 continue;
             }
 
             // Apply per-channel filter
-            if let Some(filter) = self.per_channel_filters.get(&route.discord_channel_name) {
+            if let Some(filter) = filter_key.and_then(|key| self.per_channel_filters.get(key)) {
                 if filter.should_filter(FilterDirection::WowToDiscord, &formatted) {
                     info!(
                         chat_type,
-                        discord_channel = %route.discord_channel_name,
+                        discord_channel = %discord_target,
                         "FILTERED WoW -> Discord (channel) [{}]: {}",
-                        route.discord_channel_name,
+                        discord_target,
                         formatted
                     );
                     continue;
@@ -331,13 +400,13 @@ impl Bridge {
 
             info!(
                 chat_type,
-                discord_channel = %route.discord_channel_name,
+                discord_channel = %discord_target,
                 "WoW -> Discord [{}]: {}",
-                route.discord_channel_name,
+                discord_target,
                 formatted
             );
 
-            results.push((route.discord_channel_name.clone(), formatted));
+            results.push((discord_target, formatted));
         }
 
         results
@@ -353,9 +422,35 @@ impl Bridge {
             CommandResponseData::WhoSearch(name, member, guild_name) => {
                 self.format_who_search(name, member.as_ref(), guild_name.as_deref())
             }
+            CommandResponseData::WhoServer(name, players) => self.format_who_server(name, players),
+            CommandResponseData::Nearby(entities) => self.format_nearby(entities),
+            CommandResponseData::ChannelMembers(channel_name, members) => {
+                self.format_channel_members(channel_name, members)
+            }
             CommandResponseData::GuildMotd(motd, _guild_name) => {
                 self.format_guild_motd(motd.as_deref())
             }
+            CommandResponseData::GuildInfoText(info, _guild_name) => {
+                self.format_guild_info(info.as_deref())
+            }
+            CommandResponseData::GuildLog(entries) => self.format_guild_log(entries),
+            CommandResponseData::Inactive(members, days, rank) => {
+                self.format_inactive(members, *days, rank.as_deref())
+            }
+            CommandResponseData::Activity(name, summary) => {
+                self.format_activity(name, summary.as_ref())
+            }
+            CommandResponseData::TopPlaytime(members, days) => {
+                self.format_top_playtime(members, *days)
+            }
+            CommandResponseData::RosterExport(members, _format) => format!(
+                "Guild roster: {} member{}",
+                members.len(),
+                if members.len() == 1 { "" } else { "s" }
+            ),
+            CommandResponseData::GuildNote(name, note, officer_note) => {
+                self.format_guild_note(name, note, officer_note.as_deref())
+            }
             CommandResponseData::Error(msg) => format!("⚠️ {}", msg),
         }
     }
@@ -457,6 +552,185 @@ impl Bridge {
         }
     }
 
+    fn format_who_server(&self, search_input: &str, players: &[WhoPlayer]) -> String {
+        let enable_markdown = self.config.enable_markdown;
+        let describe = |p: &WhoPlayer| {
+            let race_name = p.race.map(|r| r.name()).unwrap_or("Unknown");
+            let class_name = p.class.map(|c| c.name()).unwrap_or("Unknown");
+            let guild_str = if p.guild_name.is_empty() {
+                String::new()
+            } else {
+                format!(" <{}>", p.guild_name)
+            };
+            let name = if enable_markdown {
+                format!("**{}**", p.name)
+            } else {
+                p.name.clone()
+            };
+            (
+                name,
+                guild_str,
+                race_name,
+                class_name,
+                get_zone_name(p.zone_id),
+            )
+        };
+
+        // An exact name match wins over partial matches returned by the server
+        if let Some(p) = players
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(search_input))
+        {
+            let (name, guild_str, race_name, class_name, zone_name) = describe(p);
+            return format!(
+                "{}{} is a Level {} {} {} currently in {}.",
+                name, guild_str, p.level, race_name, class_name, zone_name
+            );
+        }
+
+        if players.is_empty() {
+            return format!("Player '{}' not found.", search_input);
+        }
+
+        let count = players.len();
+        let mut lines = vec![format!(
+            "{} player{} found matching '{}':",
+            count,
+            if count == 1 { "" } else { "s" },
+            search_input
+        )];
+        for p in players {
+            let (name, guild_str, race_name, class_name, zone_name) = describe(p);
+            lines.push(format!(
+                "• {}{} (Lvl {} {} {}) - {}",
+                name, guild_str, p.level, race_name, class_name, zone_name
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    fn format_channel_members(&self, channel_name: &str, members: &[ChannelMember]) -> String {
+        const MAX_LISTED_MEMBERS: usize = 50;
+
+        if members.is_empty() {
+            return format!("No members in [{}].", channel_name);
+        }
+
+        // Owner first, then moderators, then everyone else by name
+        let mut sorted: Vec<&ChannelMember> = members.iter().collect();
+        sorted.sort_by_key(|m| {
+            (
+                !m.owner,
+                !m.moderator,
+                m.name.is_none(),
+                m.name.as_ref().map(|n| n.to_lowercase()),
+            )
+        });
+
+        let enable_markdown = self.config.enable_markdown;
+        let count = members.len();
+        let mut lines = vec![format!(
+            "{} member{} in [{}]:",
+            count,
+            if count == 1 { "" } else { "s" },
+            channel_name
+        )];
+        for m in sorted.iter().take(MAX_LISTED_MEMBERS) {
+            let name = m
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("Unknown ({})", m.guid));
+            let mut tags = Vec::new();
+            if m.owner {
+                tags.push("owner");
+            }
+            if m.moderator {
+                tags.push("moderator");
+            }
+            if m.muted {
+                tags.push("muted");
+            }
+            let tags = if tags.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", tags.join(", "))
+            };
+            if enable_markdown {
+                lines.push(format!("• **{}**{}", name, tags));
+            } else {
+                lines.push(format!("• {}{}", name, tags));
+            }
+        }
+        if count > MAX_LISTED_MEMBERS {
+            lines.push(format!("...and {} more.", count - MAX_LISTED_MEMBERS));
+        }
+
+        lines.join("\n")
+    }
+
+    fn format_nearby(&self, entities: &[NearbyEntity]) -> String {
+        const MAX_LISTED_PLAYERS: usize = 25;
+
+        let enable_markdown = self.config.enable_markdown;
+        let players: Vec<&NearbyEntity> = entities
+            .iter()
+            .filter(|e| e.kind == EntityKind::Player)
+            .collect();
+        let creatures = entities
+            .iter()
+            .filter(|e| e.kind == EntityKind::Creature)
+            .count();
+        let objects = entities.len() - players.len() - creatures;
+
+        let mut lines = Vec::new();
+        if players.is_empty() {
+            lines.push("No players nearby.".to_string());
+        } else {
+            let count = players.len();
+            lines.push(format!(
+                "{} player{} nearby:",
+                count,
+                if count == 1 { "" } else { "s" }
+            ));
+            for p in players.iter().take(MAX_LISTED_PLAYERS) {
+                let name = p.name.as_deref().unwrap_or("Unknown");
+                let race_name = p.race.map(|r| r.name()).unwrap_or("Unknown");
+                let class_name = p.class.map(|c| c.name()).unwrap_or("Unknown");
+                let distance = p
+                    .distance
+                    .map(|d| format!(" - {:.0} yd", d))
+                    .unwrap_or_default();
+                if enable_markdown {
+                    lines.push(format!(
+                        "• **{}** (Lvl {} {} {}){}",
+                        name, p.level, race_name, class_name, distance
+                    ));
+                } else {
+                    lines.push(format!(
+                        "• {} (Lvl {} {} {}){}",
+                        name, p.level, race_name, class_name, distance
+                    ));
+                }
+            }
+            if count > MAX_LISTED_PLAYERS {
+                lines.push(format!("...and {} more.", count - MAX_LISTED_PLAYERS));
+            }
+        }
+
+        if creatures > 0 || objects > 0 {
+            lines.push(format!(
+                "Also nearby: {} NPC{}, {} object{}.",
+                creatures,
+                if creatures == 1 { "" } else { "s" },
+                objects,
+                if objects == 1 { "" } else { "s" }
+            ));
+        }
+
+        lines.join("\n")
+    }
+
     fn format_guild_motd(&self, motd: Option<&str>) -> String {
         if let Some(m) = motd {
             if !m.is_empty() {
@@ -474,6 +748,149 @@ impl Bridge {
         "No guild MOTD set.".to_string()
     }
 
+    fn format_guild_info(&self, info: Option<&str>) -> String {
+        match info {
+            Some(i) if !i.is_empty() => format!("Guild Information:\n{}", i),
+            _ => "No guild information set.".to_string(),
+        }
+    }
+
+    fn format_guild_log(&self, entries: &[crate::common::messages::GuildLogEntryInfo]) -> String {
+        if entries.is_empty() {
+            return "The guild event log is empty.".to_string();
+        }
+
+        let mut lines = Vec::with_capacity(entries.len() + 1);
+        lines.push(if self.config.enable_markdown {
+            "**Guild Event Log:**".to_string()
+        } else {
+            "Guild Event Log:".to_string()
+        });
+        for entry in entries {
+            let e = &entry.event;
+            let target = e.target_name.as_deref().unwrap_or("Unknown");
+            let rank = e.rank_name.as_deref().unwrap_or("");
+            let description = match e.event_name.as_str() {
+                "invited" => format!("{} invited {} to the guild", e.player_name, target),
+                "joined" => format!("{} joined the guild", e.player_name),
+                "promoted" => format!("{} promoted {} to {}", e.player_name, target, rank),
+                "demoted" => format!("{} demoted {} to {}", e.player_name, target, rank),
+                "removed" => format!("{} removed {} from the guild", e.player_name, target),
+                "left" => format!("{} left the guild", e.player_name),
+                other => format!("{} ({})", e.player_name, other),
+            };
+            let age = self.format_duration(entry.seconds_ago as f32 / 86400.0);
+            lines.push(format!("• {} ({})", description, age));
+        }
+        lines.join("\n")
+    }
+
+    fn format_inactive(&self, members: &[GuildMember], days: u32, rank: Option<&str>) -> String {
+        let count = members.len();
+        let noun = if count == 1 { "member" } else { "members" };
+        let scope = match rank {
+            Some(rank) => format!("{} {}", rank, noun),
+            None => noun.to_string(),
+        };
+        let days_str = format!("{} day{}", days, if days == 1 { "" } else { "s" });
+        if members.is_empty() {
+            return format!("No {} offline for {} or more.", scope, days_str);
+        }
+
+        let header = format!("{} {} offline for {} or more:", count, scope, days_str);
+        let mut lines = vec![if self.config.enable_markdown {
+            format!("**{}**", header)
+        } else {
+            header
+        }];
+
+        for m in members {
+            let name = if self.config.enable_markdown {
+                format!("**{}**", m.name)
+            } else {
+                m.name.clone()
+            };
+            lines.push(format!(
+                "• {} ({}, Lvl {}) - last seen {}",
+                name,
+                m.rank_name,
+                m.level,
+                self.format_duration(m.last_logoff)
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn format_activity(&self, player_name: &str, summary: Option<&ActivitySummary>) -> String {
+        let Some(a) = summary else {
+            return format!("No activity recorded for '{}'.", player_name);
+        };
+
+        let name = if self.config.enable_markdown {
+            format!("**{}**", a.name)
+        } else {
+            a.name.clone()
+        };
+        let status = if a.online { " (online now)" } else { "" };
+        let hours = if a.usual_hours.is_empty() {
+            "no usual play hours yet".to_string()
+        } else {
+            let ranges: Vec<String> = a
+                .usual_hours
+                .iter()
+                .map(|(start, end)| format!("{:02}:00-{:02}:00", start, end % 24))
+                .collect();
+            format!("usually plays {} UTC", ranges.join(", "))
+        };
+        format!(
+            "{}{}: {:.1} sessions and {:.1} hours a week, {} (last {} day{})",
+            name,
+            status,
+            a.sessions_per_week,
+            a.hours_per_week,
+            hours,
+            a.tracked_days,
+            if a.tracked_days == 1 { "" } else { "s" }
+        )
+    }
+
+    fn format_top_playtime(&self, members: &[(String, f32)], days: u32) -> String {
+        if members.is_empty() {
+            return format!("No play time recorded in the last {} days.", days);
+        }
+
+        let mut lines = vec![if self.config.enable_markdown {
+            format!("**Most played, last {} days:**", days)
+        } else {
+            format!("Most played, last {} days:", days)
+        }];
+        for (i, (name, hours)) in members.iter().enumerate() {
+            lines.push(format!("{}. {} - {:.1} hours", i + 1, name, hours));
+        }
+        lines.join("\n")
+    }
+
+    fn format_guild_note(&self, name: &str, note: &str, officer_note: Option<&str>) -> String {
+        let label = |text: &str| {
+            if text.is_empty() {
+                "(none)".to_string()
+            } else {
+                text.to_string()
+            }
+        };
+        let name = if self.config.enable_markdown {
+            format!("**{}**", name)
+        } else {
+            name.to_string()
+        };
+
+        let mut lines = vec![name, format!("Note: {}", label(note))];
+        if let Some(officer_note) = officer_note {
+            lines.push(format!("Officer note: {}", label(officer_note)));
+        }
+        lines.join("\n")
+    }
+
     fn format_duration(&self, days: f32) -> String {
         let total_minutes = (days * 24.0 * 60.0).round() as u64;
 
@@ -563,6 +980,8 @@ pub struct Route {
     pub chat_type: ChatType,
     /// WoW channel name (for custom channels).
     pub wow_channel_name: Option<String>,
+    /// WoW channel password (for password-protected custom channels).
+    pub wow_channel_password: Option<String>,
     /// Discord channel name.
     pub discord_channel_name: String,
     /// Message flow direction.
@@ -614,6 +1033,7 @@ impl MessageRouter {
             let route = Route {
                 chat_type,
                 wow_channel_name: wow_channel_name.clone(),
+                wow_channel_password: mapping.wow.password.clone(),
                 discord_channel_name: mapping.discord.channel.clone(),
                 direction: mapping.direction,
                 // discord.format is used for messages going TO Discord (WoW → Discord)
@@ -637,6 +1057,11 @@ impl MessageRouter {
             if route.direction.allows_wow_to_discord() {
                 let key = WowChannelKey::new(route.chat_type, route.wow_channel_name.as_deref());
                 wow_to_discord.entry(key).or_default().push(idx);
+                // Raid leader messages are part of raid chat, as they are in the game
+                if route.chat_type == ChatType::Raid {
+                    let key = WowChannelKey::new(ChatType::RaidLeader, None);
+                    wow_to_discord.entry(key).or_default().push(idx);
+                }
             }
 
             // Build Discord -> WoW index
@@ -704,6 +1129,19 @@ impl MessageRouter {
             .filter(|name| seen.insert(name.to_lowercase()))
             .collect()
     }
+
+    /// Get the configured password for a custom channel, if any.
+    pub fn get_channel_password(&self, channel_name: &str) -> Option<String> {
+        self.routes
+            .iter()
+            .filter(|r| r.chat_type == ChatType::Channel)
+            .filter(|r| {
+                r.wow_channel_name
+                    .as_ref()
+                    .is_some_and(|name| name.eq_ignore_ascii_case(channel_name))
+            })
+            .find_map(|r| r.wow_channel_password.clone())
+    }
 }
 
 /// Shared router reference for use across async tasks.
@@ -729,6 +1167,7 @@ mod tests {
                     wow: WowChannelConfig {
                         channel_type: "Guild".to_string(),
                         channel: None,
+                        password: None,
                         format: Some("[%user]: %message".to_string()),
                         filters: None,
                     },
@@ -766,6 +1205,7 @@ mod tests {
             wow: WowChannelConfig {
                 channel_type: "Guild".to_string(),
                 channel: None,
+                password: None,
                 format: None,
                 filters: None,
             },
@@ -783,6 +1223,43 @@ mod tests {
         assert_eq!(targets[0].discord_channel_name, "guild-chat");
     }
 
+    #[test]
+    fn test_router_raid_includes_raid_leader() {
+        let mapping = |channel_type: &str, channel: &str| ChannelMapping {
+            direction: Direction::WowToDiscord,
+            wow: WowChannelConfig {
+                channel_type: channel_type.to_string(),
+                channel: None,
+                password: None,
+                format: None,
+                filters: None,
+            },
+            discord: DiscordChannelConfig {
+                channel: channel.to_string(),
+                format: None,
+                filters: None,
+            },
+        };
+        let config = make_config(vec![
+            mapping("Raid", "raid-chat"),
+            mapping("RaidLeader", "raid-lead"),
+        ]);
+
+        let router = MessageRouter::from_config(&config);
+        let targets = router.get_discord_targets(chat_events::CHAT_MSG_RAID, None);
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].discord_channel_name, "raid-chat");
+
+        // A type=Raid route still sees the raid leader's messages
+        let targets = router.get_discord_targets(chat_events::CHAT_MSG_RAID_LEADER, None);
+        let mut channels: Vec<_> = targets
+            .iter()
+            .map(|r| r.discord_channel_name.as_str())
+            .collect();
+        channels.sort();
+        assert_eq!(channels, vec!["raid-chat", "raid-lead"]);
+    }
+
     #[test]
     fn test_router_discord_to_wow() {
         let config = make_config(vec![ChannelMapping {
@@ -790,6 +1267,7 @@ mod tests {
             wow: WowChannelConfig {
                 channel_type: "Officer".to_string(),
                 channel: None,
+                password: None,
                 format: None,
                 filters: None,
             },
@@ -814,6 +1292,7 @@ mod tests {
             wow: WowChannelConfig {
                 channel_type: "Guild".to_string(),
                 channel: None,
+                password: None,
                 format: None,
                 filters: None,
             },
@@ -842,6 +1321,7 @@ mod tests {
             wow: WowChannelConfig {
                 channel_type: "Channel".to_string(),
                 channel: Some("World".to_string()),
+                password: None,
                 format: None,
                 filters: None,
             },
@@ -871,6 +1351,7 @@ mod tests {
                 wow: WowChannelConfig {
                     channel_type: "Guild".to_string(),
                     channel: None,
+                    password: None,
                     format: None,
                     filters: None,
                 },
@@ -885,6 +1366,7 @@ mod tests {
                 wow: WowChannelConfig {
                     channel_type: "Channel".to_string(),
                     channel: Some("World".to_string()),
+                    password: None,
                     format: None,
                     filters: None,
                 },
@@ -899,6 +1381,7 @@ mod tests {
                 wow: WowChannelConfig {
                     channel_type: "Channel".to_string(),
                     channel: Some("Trade".to_string()),
+                    password: Some("secret".to_string()),
                     format: None,
                     filters: None,
                 },
@@ -916,6 +1399,12 @@ mod tests {
         assert_eq!(channels.len(), 2);
         assert!(channels.contains(&"World".to_string()));
         assert!(channels.contains(&"Trade".to_string()));
+
+        assert_eq!(
+            router.get_channel_password("trade").as_deref(),
+            Some("secret")
+        );
+        assert_eq!(router.get_channel_password("World"), None);
     }
 
     #[test]
@@ -926,6 +1415,7 @@ mod tests {
                 wow: WowChannelConfig {
                     channel_type: "Guild".to_string(),
                     channel: None,
+                    password: None,
                     format: None,
                     filters: None,
                 },
@@ -940,6 +1430,7 @@ mod tests {
                 wow: WowChannelConfig {
                     channel_type: "Guild".to_string(),
                     channel: None,
+                    password: None,
                     format: None,
                     filters: None,
                 },
@@ -999,6 +1490,91 @@ mod tests {
         // Dot commands are sent directly without formatting
         assert_eq!(result.unwrap().content, ".help");
     }
+
+    #[test]
+    fn test_guild_event_channel_override() {
+        use crate::common::messages::GuildEventInfo;
+        use crate::config::types::{FiltersConfig, GuildEventConfig};
+
+        let mut config = make_test_config();
+        config.guild.online = Some(GuildEventConfig {
+            enabled: true,
+            format: Some("%user is online".to_string()),
+            channel: Some("guild-activity".to_string()),
+            batch_format: None,
+        });
+        config.guild.offline = Some(GuildEventConfig {
+            enabled: true,
+            format: Some("%user is offline".to_string()),
+            channel: None,
+            batch_format: None,
+        });
+        config.guild.joined = Some(GuildEventConfig {
+            enabled: true,
+            format: Some("%user joined".to_string()),
+            channel: Some("missing-channel".to_string()),
+            batch_format: None,
+        });
+        config.chat.channels[0].discord.filters = Some(FiltersConfig {
+            enabled: true,
+            patterns: Some(vec!["Arthas".to_string()]),
+        });
+        let bridge = Bridge::new(&config);
+
+        let event = |name: &str, player: &str| {
+            BridgeMessage::guild_event(
+                GuildEventInfo {
+                    event_name: name.to_string(),
+                    player_name: player.to_string(),
+                    target_name: None,
+                    rank_name: None,
+                    achievement_id: None,
+                },
+                String::new(),
+            )
+        };
+        // "guild-activity" resolved to the same Discord channel as guild chat
+        let activity = ChannelId::new(42);
+        let event_channels = HashMap::from([(
+            "online".to_string(),
+            EventChannel {
+                id: activity,
+                mapping: Some("guild-chat".to_string()),
+            },
+        )]);
+
+        let results = bridge.handle_wow_to_discord(&event("online", "Thrall"), "", &event_channels);
+        assert_eq!(
+            results,
+            vec![(
+                DiscordTarget::Channel(activity),
+                "Thrall is online".to_string()
+            )]
+        );
+        // The filter of the mapping sharing the resolved channel applies
+        let results = bridge.handle_wow_to_discord(&event("online", "Arthas"), "", &event_channels);
+        assert!(results.is_empty());
+
+        let results =
+            bridge.handle_wow_to_discord(&event("offline", "Thrall"), "", &event_channels);
+        assert_eq!(
+            results,
+            vec![(
+                DiscordTarget::Mapped("guild-chat".to_string()),
+                "Thrall is offline".to_string()
+            )]
+        );
+
+        // An override that did not resolve falls back to the guild chat route
+        let results = bridge.handle_wow_to_discord(&event("joined", "Thrall"), "", &event_channels);
+        assert_eq!(
+            results,
+            vec![(
+                DiscordTarget::Mapped("guild-chat".to_string()),
+                "Thrall joined".to_string()
+            )]
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1073,10 +1649,154 @@ mod formatting_tests {
         assert!(response.contains("not found"));
     }
 
+    #[test]
+    fn test_format_who_server() {
+        use crate::common::resources::{Class, Race};
+
+        let bridge = make_bridge();
+        let player = |name: &str| WhoPlayer {
+            name: name.to_string(),
+            guild_name: "Horde".to_string(),
+            level: 80,
+            class: Some(Class::Shaman),
+            race: Some(Race::Orc),
+            zone_id: 0,
+        };
+
+        let exact = bridge.format_who_server("thrall", &[player("Thrall"), player("Thrallson")]);
+        assert!(exact.contains("Thrall"));
+        assert!(exact.contains("<Horde>"));
+        assert!(exact.contains("Level 80 Orc Shaman"));
+        assert!(!exact.contains("Thrallson"));
+
+        let partial = bridge.format_who_server("thr", &[player("Thrall"), player("Thrallson")]);
+        assert!(partial.contains("2 players found matching 'thr'"));
+        assert!(partial.contains("Thrallson"));
+
+        let none = bridge.format_who_server("Nobody", &[]);
+        assert!(none.contains("not found"));
+    }
+
+    #[test]
+    fn test_format_nearby() {
+        let bridge = make_bridge();
+        let entity = |kind: EntityKind, name: Option<&str>, distance: Option<f32>| NearbyEntity {
+            guid: 0,
+            kind,
+            name: name.map(|n| n.to_string()),
+            entry: 0,
+            level: 80,
+            class: None,
+            race: None,
+            distance,
+        };
+
+        let response = bridge.format_nearby(&[
+            entity(EntityKind::Player, Some("Thrall"), Some(4.2)),
+            entity(EntityKind::Player, None, None),
+            entity(EntityKind::Creature, None, Some(1.0)),
+        ]);
+        assert!(response.contains("2 players nearby"));
+        assert!(response.contains("Thrall"));
+        assert!(response.contains("4 yd"));
+        assert!(response.contains("Unknown"));
+        assert!(response.contains("1 NPC, 0 objects"));
+
+        let empty = bridge.format_nearby(&[]);
+        assert_eq!(empty, "No players nearby.");
+    }
+
+    #[test]
+    fn test_format_channel_members() {
+        let bridge = make_bridge();
+        let member = |guid: u64, name: Option<&str>, owner: bool, muted: bool| ChannelMember {
+            guid,
+            name: name.map(|n| n.to_string()),
+            owner,
+            moderator: false,
+            muted,
+        };
+
+        let response = bridge.format_channel_members(
+            "RaidTeam",
+            &[
+                member(1, Some("Zul"), false, true),
+                member(2, Some("Thrall"), true, false),
+                member(3, None, false, false),
+            ],
+        );
+        let lines: Vec<&str> = response.lines().collect();
+        assert_eq!(lines[0], "3 members in [RaidTeam]:");
+        assert_eq!(lines[1], "• Thrall (owner)");
+        assert_eq!(lines[2], "• Zul (muted)");
+        assert_eq!(lines[3], "• Unknown (3)");
+
+        let empty = bridge.format_channel_members("RaidTeam", &[]);
+        assert_eq!(empty, "No members in [RaidTeam].");
+    }
+
     #[test]
     fn test_format_guild_motd() {
         let bridge = make_bridge();
         let response = bridge.format_guild_motd(Some("Welcome!"));
         assert!(response.contains("Welcome!"));
     }
+
+    #[test]
+    fn test_format_activity() {
+        let bridge = make_bridge();
+        let summary = ActivitySummary {
+            name: "Thrall".to_string(),
+            online: true,
+            tracked_days: 28,
+            sessions_per_week: 5.25,
+            hours_per_week: 12.0,
+            usual_hours: vec![(12, 13), (22, 2)],
+        };
+        assert_eq!(
+            bridge.format_activity("thrall", Some(&summary)),
+            "Thrall (online now): 5.2 sessions and 12.0 hours a week, \
+usually plays 12:00-13:00, 22:00-02:00 UTC (last 28 days)"
+        );
+        assert_eq!(
+            bridge.format_activity("Jaina", None),
+            "No activity recorded for 'Jaina'."
+        );
+
+        let top = vec![("Thrall".to_string(), 20.5), ("Jaina".to_string(), 3.0)];
+        assert_eq!(
+            bridge.format_top_playtime(&top, 7),
+            "Most played, last 7 days:\n1. Thrall - 20.5 hours\n2. Jaina - 3.0 hours"
+        );
+    }
+
+    #[test]
+    fn test_format_inactive() {
+        let bridge = make_bridge();
+        let members = vec![
+            make_test_member("Jaina", 80, false, 90.25),
+            make_test_member("Thrall", 79, false, 45.0),
+        ];
+
+        let response = bridge.format_inactive(&members, 30, None);
+        let lines: Vec<&str> = response.lines().collect();
+        assert_eq!(lines[0], "2 members offline for 30 days or more:");
+        assert_eq!(
+            lines[1],
+            "• Jaina (Member, Lvl 80) - last seen 90 days, 6 hours ago"
+        );
+        assert_eq!(
+            lines[2],
+            "• Thrall (Member, Lvl 79) - last seen 45 days ago"
+        );
+
+        assert_eq!(
+            bridge.format_inactive(&members[..1], 1, Some("Member")),
+            "1 Member member offline for 1 day or more:\n• Jaina (Member, Lvl 80) - last seen 90 days, 6 hours ago"
+        );
+        assert_eq!(
+            bridge.format_inactive(&[], 14, Some("Officer")),
+            "No Officer members offline for 14 days or more."
+        );
+    }
 }
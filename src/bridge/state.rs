@@ -8,7 +8,9 @@ use tokio::sync::mpsc;
 
 use crate::common::types::ChatType;
 use crate::common::BridgeMessage;
-use crate::config::types::{Direction, GuildDashboardConfig, GuildEventsConfig, WowChannelConfig};
+use crate::config::types::{
+    Direction, GuildDashboardConfig, GuildEventsConfig, InactivityReportConfig, WowChannelConfig,
+};
 use crate::discord::commands::WowCommand;
 use crate::discord::resolver::MessageResolver;
 
@@ -28,6 +30,27 @@ pub struct ChannelConfig {
     pub wow_channel_name: Option<String>,
 }
 
+/// A guild event channel override resolved to a Discord channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventChannel {
+    pub id: ChannelId,
+    /// Configured Discord channel of the chat mapping that resolved to the same
+    /// channel, if any. Its filter applies to events sent there.
+    pub mapping: Option<String>,
+}
+
+/// Channels for bot output other than chat: dashboards, guild event
+/// overrides and the inactivity report.
+#[derive(Debug, Clone)]
+pub struct OutputChannelConfigs {
+    /// Enabled dashboards.
+    pub dashboard_configs: Vec<GuildDashboardConfig>,
+    /// Guild event channel overrides: (event name, channel name or ID).
+    pub guild_event_channels: Vec<(String, String)>,
+    /// Weekly inactivity report settings.
+    pub inactivity_report: InactivityReportConfig,
+}
+
 /// Pending state before Discord channels are resolved.
 ///
 /// This holds configuration loaded that needs Discord guild data
@@ -50,8 +73,8 @@ pub struct PendingBridgeState {
     pub enable_markdown: bool,
     /// Whether to send tag resolution error notifications.
     pub enable_tag_failed_notifications: bool,
-    /// Dashboard configuration.
-    pub dashboard_config: Option<GuildDashboardConfig>,
+    /// Dashboard, guild event and report channels.
+    pub outputs: OutputChannelConfigs,
 }
 
 impl PendingBridgeState {
@@ -65,7 +88,7 @@ impl PendingBridgeState {
         enable_commands_channels: Option<Vec<String>>,
         enable_markdown: bool,
         enable_tag_failed_notifications: bool,
-        dashboard_config: Option<GuildDashboardConfig>,
+        outputs: OutputChannelConfigs,
     ) -> Self {
         Self {
             pending_channel_configs,
@@ -76,7 +99,7 @@ impl PendingBridgeState {
             enable_commands_channels,
             enable_markdown,
             enable_tag_failed_notifications,
-            dashboard_config,
+            outputs,
         }
     }
 
@@ -95,16 +118,7 @@ impl PendingBridgeState {
 
         for (channel_name, direction, mut config) in self.pending_channel_configs {
             // Find matching Discord channel by name OR ID
-            if let Some(discord_channel) = guild_channels.iter().find(|ch| {
-                // First try matching as a channel ID (if the config value is numeric)
-                if let Ok(channel_id) = channel_name.parse::<u64>() {
-                    if ch.id.get() == channel_id {
-                        return true;
-                    }
-                }
-                // Then try matching by channel name (case-insensitive)
-                ch.name().to_lowercase() == channel_name.to_lowercase()
-            }) {
+            if let Some(discord_channel) = find_discord_channel(guild_channels, &channel_name) {
                 // Update config with resolved channel ID
                 config.discord_channel_id = Some(discord_channel.id);
 
@@ -114,6 +128,13 @@ impl PendingBridgeState {
                     config.wow_channel_name.as_ref().map(|s| s.to_lowercase()),
                 );
                 wow_to_discord.entry(key).or_default().push(config.clone());
+                // Raid chat mappings also carry the raid leader's messages
+                if config.wow_chat_type == ChatType::Raid.to_id() {
+                    wow_to_discord
+                        .entry((ChatType::RaidLeader.to_id(), None))
+                        .or_default()
+                        .push(config.clone());
+                }
 
                 // Add to discord_to_wow mapping if bidirectional
                 if direction.allows_discord_to_wow() {
@@ -147,32 +168,83 @@ impl PendingBridgeState {
             }
         }
 
-        // Resolve dashboard channel if config present
-        let dashboard_channel_id = self.dashboard_config.as_ref().and_then(|config| {
-            if !config.enabled {
-                return None;
-            }
+        // Resolve dashboard channels
+        let mut dashboard_channel_ids = HashMap::new();
+        for config in self.outputs.dashboard_configs.iter().filter(|c| c.enabled) {
             let channel_name = &config.channel;
-            guild_channels
-                .iter()
-                .find(|ch| {
-                    if let Ok(channel_id) = channel_name.parse::<u64>() {
-                        if ch.id.get() == channel_id {
-                            return true;
-                        }
-                    }
-                    ch.name().to_lowercase() == channel_name.to_lowercase()
-                })
-                .map(|ch| {
+            match find_discord_channel(guild_channels, channel_name) {
+                Some(ch) => {
                     tracing::info!(
-                        "Resolved Dashboard channel '{}' -> #{} (ID {})",
+                        "Resolved Dashboard '{}' channel '{}' -> #{} (ID {})",
+                        config.id,
                         channel_name,
                         ch.name(),
                         ch.id
                     );
-                    ch.id
-                })
-        });
+                    dashboard_channel_ids.insert(config.id.clone(), ch.id);
+                }
+                None => {
+                    tracing::warn!(
+                        "Could not resolve Discord channel for dashboard '{}': {}",
+                        config.id,
+                        channel_name
+                    );
+                }
+            }
+        }
+
+        // Resolve guild event channel overrides
+        let mut guild_event_channels = HashMap::new();
+        for (event, channel_name) in &self.outputs.guild_event_channels {
+            match find_discord_channel(guild_channels, channel_name) {
+                Some(ch) => {
+                    tracing::info!(
+                        "Resolved guild event '{}' channel '{}' -> #{} (ID {})",
+                        event,
+                        channel_name,
+                        ch.name(),
+                        ch.id
+                    );
+                    let mapping = wow_to_discord
+                        .values()
+                        .flatten()
+                        .find(|c| c.discord_channel_id == Some(ch.id))
+                        .map(|c| c.discord_channel_name.clone());
+                    guild_event_channels.insert(event.clone(), EventChannel { id: ch.id, mapping });
+                }
+                None => {
+                    tracing::warn!(
+                        "Could not resolve Discord channel for guild event '{}': {} \
+                         (sending to the guild chat channels instead)",
+                        event,
+                        channel_name
+                    );
+                }
+            }
+        }
+
+        // Resolve the inactivity report channel
+        let mut inactivity_report_channel_id = None;
+        if self.outputs.inactivity_report.enabled {
+            let channel_name = &self.outputs.inactivity_report.channel;
+            match find_discord_channel(guild_channels, channel_name) {
+                Some(ch) => {
+                    tracing::info!(
+                        "Resolved inactivity report channel '{}' -> #{} (ID {})",
+                        channel_name,
+                        ch.name(),
+                        ch.id
+                    );
+                    inactivity_report_channel_id = Some(ch.id);
+                }
+                None => {
+                    tracing::warn!(
+                        "Could not resolve Discord channel for inactivity report: {}",
+                        channel_name
+                    );
+                }
+            }
+        }
 
         if !unresolved.is_empty() {
             let remaining: Vec<&str> = unresolved
@@ -199,8 +271,10 @@ impl PendingBridgeState {
             enable_commands_channels: self.enable_commands_channels,
             self_user_id,
             enable_tag_failed_notifications: self.enable_tag_failed_notifications,
-            dashboard_config: self.dashboard_config,
-            dashboard_channel_id,
+            dashboard_channel_ids,
+            guild_event_channels,
+            inactivity_report: self.outputs.inactivity_report,
+            inactivity_report_channel_id,
         }
     }
 }
@@ -231,10 +305,14 @@ pub struct ResolvedBridgeState {
     pub self_user_id: u64,
     /// Whether to send tag resolution error notifications.
     pub enable_tag_failed_notifications: bool,
-    /// Dashboard configuration.
-    pub dashboard_config: Option<GuildDashboardConfig>,
-    /// Resolved dashboard channel ID.
-    pub dashboard_channel_id: Option<ChannelId>,
+    /// Resolved dashboard channel IDs, keyed by dashboard id.
+    pub dashboard_channel_ids: HashMap<String, ChannelId>,
+    /// Resolved guild event channel overrides, keyed by event name.
+    pub guild_event_channels: HashMap<String, EventChannel>,
+    /// Weekly inactivity report settings.
+    pub inactivity_report: InactivityReportConfig,
+    /// Resolved inactivity report channel ID (None when disabled or unresolved).
+    pub inactivity_report_channel_id: Option<ChannelId>,
 }
 
 impl ResolvedBridgeState {
@@ -261,6 +339,22 @@ impl ResolvedBridgeState {
         }
     }
 
+    /// Check if a Discord channel is mapped to Officer chat (in either direction).
+    pub fn is_officer_channel(&self, channel_id: ChannelId) -> bool {
+        let officer = ChatType::Officer.to_id();
+        self.discord_to_wow
+            .get(&channel_id)
+            .is_some_and(|c| c.wow_chat_type == officer)
+            || self
+                .wow_to_discord
+                .get(&(officer, None))
+                .is_some_and(|configs| {
+                    configs
+                        .iter()
+                        .any(|c| c.discord_channel_id == Some(channel_id))
+                })
+    }
+
     /// Check if commands are allowed in a given channel.
     pub fn command_allowed_in_channel(&self, channel_name: &str, channel_id: u64) -> bool {
         match &self.enable_commands_channels {
@@ -276,6 +370,21 @@ impl ResolvedBridgeState {
     }
 }
 
+/// Find a Discord channel by ID (if the value is numeric) or case-insensitive name.
+fn find_discord_channel<'a>(
+    guild_channels: &'a [GuildChannel],
+    channel_name: &str,
+) -> Option<&'a GuildChannel> {
+    guild_channels.iter().find(|ch| {
+        if let Ok(channel_id) = channel_name.parse::<u64>() {
+            if ch.id.get() == channel_id {
+                return true;
+            }
+        }
+        ch.name().to_lowercase() == channel_name.to_lowercase()
+    })
+}
+
 /// Parse a ChatType from WowChannelConfig, matching Scala's parse() function.
 /// Corresponds to GamePackets.ChatEvents.parse() in the Scala code.
 pub fn parse_channel_config(config: &WowChannelConfig) -> (ChatType, Option<String>) {
@@ -284,6 +393,10 @@ pub fn parse_channel_config(config: &WowChannelConfig) -> (ChatType, Option<Stri
         "say" => (ChatType::Say, None),
         "guild" => (ChatType::Guild, None),
         "officer" => (ChatType::Officer, None),
+        "party" => (ChatType::Party, None),
+        "raid" => (ChatType::Raid, None),
+        "raidleader" | "raid_leader" => (ChatType::RaidLeader, None),
+        "raidwarning" | "raid_warning" => (ChatType::RaidWarning, None),
         "yell" => (ChatType::Yell, None),
         "emote" => (ChatType::Emote, None),
         "whisper" => (ChatType::Whisper, None),
@@ -316,8 +429,10 @@ mod tests {
             enable_commands_channels: None,
             self_user_id: 123456789,
             enable_tag_failed_notifications: false,
-            dashboard_config: None,
-            dashboard_channel_id: None,
+            dashboard_channel_ids: HashMap::new(),
+            guild_event_channels: HashMap::new(),
+            inactivity_report: InactivityReportConfig::default(),
+            inactivity_report_channel_id: None,
         }
     }
 
@@ -347,8 +462,10 @@ mod tests {
             enable_commands_channels: None,
             self_user_id: 123456789,
             enable_tag_failed_notifications: false,
-            dashboard_config: None,
-            dashboard_channel_id: None,
+            dashboard_channel_ids: HashMap::new(),
+            guild_event_channels: HashMap::new(),
+            inactivity_report: InactivityReportConfig::default(),
+            inactivity_report_channel_id: None,
         };
 
         assert!(state.should_send_dot_command_directly(".help"));
@@ -374,19 +491,50 @@ mod tests {
             enable_commands_channels: None,
             self_user_id: 123456789,
             enable_tag_failed_notifications: false,
-            dashboard_config: None,
-            dashboard_channel_id: None,
+            dashboard_channel_ids: HashMap::new(),
+            guild_event_channels: HashMap::new(),
+            inactivity_report: InactivityReportConfig::default(),
+            inactivity_report_channel_id: None,
         };
 
         assert!(!state.should_send_dot_command_directly(".help"));
         assert!(!state.should_send_dot_command_directly(".anything"));
     }
 
+    #[test]
+    fn test_is_officer_channel() {
+        let mut state = create_test_resolved_state();
+        let officer = ChatType::Officer.to_id();
+        state.wow_to_discord.insert(
+            (officer, None),
+            vec![ChannelConfig {
+                discord_channel_id: Some(ChannelId::new(10)),
+                discord_channel_name: "officers".to_string(),
+                wow_chat_type: officer,
+                wow_channel_name: None,
+            }],
+        );
+        state.discord_to_wow.insert(
+            ChannelId::new(20),
+            ChannelConfig {
+                discord_channel_id: Some(ChannelId::new(20)),
+                discord_channel_name: "guild-chat".to_string(),
+                wow_chat_type: ChatType::Guild.to_id(),
+                wow_channel_name: None,
+            },
+        );
+
+        assert!(state.is_officer_channel(ChannelId::new(10)));
+        assert!(!state.is_officer_channel(ChannelId::new(20)));
+        assert!(!state.is_officer_channel(ChannelId::new(30)));
+    }
+
     #[test]
     fn test_parse_channel_config() {
         let config = WowChannelConfig {
             channel_type: "guild".to_string(),
             channel: None,
+            password: None,
             format: None,
             filters: None,
         };
@@ -397,6 +545,7 @@ mod tests {
         let config = WowChannelConfig {
             channel_type: "GUILD".to_string(),
             channel: None,
+            password: None,
             format: None,
             filters: None,
         };
@@ -406,11 +555,30 @@ mod tests {
         let config = WowChannelConfig {
             channel_type: "channel".to_string(),
             channel: Some("World".to_string()),
+            password: None,
             format: None,
             filters: None,
         };
         let (chat_type, channel_name) = parse_channel_config(&config);
         assert_eq!(chat_type, ChatType::Channel);
         assert_eq!(channel_name, Some("World".to_string()));
+
+        for (channel_type, expected) in [
+            ("Party", ChatType::Party),
+            ("Raid", ChatType::Raid),
+            ("RaidLeader", ChatType::RaidLeader),
+            ("raid_warning", ChatType::RaidWarning),
+        ] {
+            let config = WowChannelConfig {
+                channel_type: channel_type.to_string(),
+                channel: None,
+                password: None,
+                format: None,
+                filters: None,
+            };
+            let (chat_type, channel_name) = parse_channel_config(&config);
+            assert_eq!(chat_type, expected);
+            assert_eq!(channel_name, None);
+        }
     }
 }
@@ -3,7 +3,10 @@
 //! This module defines the single source of truth for message types
 //! used in communication between Discord and WoW.
 
-use crate::common::types::{ChatMessage, GuildMember};
+use crate::common::types::{
+    ActivitySummary, ChannelMember, ChannelModeration, ChatMessage, ExportFormat, GuildMember,
+    GuildMemberAction, NearbyEntity, SocialAction, SocialRequest, WhoPlayer,
+};
 use crate::protocol::game::chat::chat_events;
 
 /// Guild event data extracted from SMSG_GUILD_EVENT.
@@ -21,6 +24,14 @@ pub struct GuildEventInfo {
     pub achievement_id: Option<u32>,
 }
 
+/// A guild event log entry (!guildlog).
+#[derive(Debug, Clone)]
+pub struct GuildLogEntryInfo {
+    pub event: GuildEventInfo,
+    /// Seconds since the event happened.
+    pub seconds_ago: u64,
+}
+
 /// Bridge message for Discord <-> WoW communication.
 ///
 /// Used for bidirectional message flow between Discord and the WoW game client.
@@ -102,6 +113,75 @@ pub enum BridgeCommand {
     },
     /// Request guild MOTD.
     Gmotd { reply_channel: u64 },
+    /// Change the guild MOTD (!gmotd set).
+    SetGuildMotd { text: String, reply_channel: u64 },
+    /// Request the guild information text (!ginfo).
+    GuildInfo { reply_channel: u64 },
+    /// Change the guild information text (!ginfo set).
+    SetGuildInfo { text: String, reply_channel: u64 },
+    /// Look up a guild member's notes (!note).
+    GuildNote {
+        name: String,
+        officer: bool,
+        reply_channel: u64,
+    },
+    /// Request the last `count` guild event log entries (!guildlog).
+    GuildLog { count: usize, reply_channel: u64 },
+    /// List members offline for at least `days`, optionally of one rank (!inactive).
+    Inactive {
+        days: u32,
+        rank: Option<String>,
+        reply_channel: u64,
+    },
+    /// Export the full guild roster as a file (!export roster).
+    ExportRoster {
+        format: ExportFormat,
+        reply_channel: u64,
+    },
+    /// Show a member's recorded play activity (!activity).
+    Activity { name: String, reply_channel: u64 },
+    /// Show the members with the most play time this week (!top playtime).
+    TopPlaytime { reply_channel: u64 },
+    /// Change a guild member's public or officer note (!note set).
+    SetGuildNote {
+        name: String,
+        note: String,
+        officer: bool,
+        reply_channel: u64,
+    },
+    /// Request objects around the bot.
+    Nearby { reply_channel: u64 },
+    /// Show connection status and relay counters (!status).
+    Status { reply_channel: u64 },
+    /// Join a custom WoW channel (!channel join).
+    ChannelJoin {
+        name: String,
+        password: Option<String>,
+        reply_channel: u64,
+    },
+    /// Leave a custom WoW channel (!channel leave).
+    ChannelLeave { name: String, reply_channel: u64 },
+    /// Moderate a custom WoW channel (!channel kick, ban, ...).
+    ChannelModerate {
+        name: String,
+        action: ChannelModeration,
+        reply_channel: u64,
+    },
+    /// List members of a custom WoW channel (!members).
+    Members {
+        channel_name: String,
+        reply_channel: u64,
+    },
+    /// Leave the current party/raid (!group leave).
+    GroupLeave { reply_channel: u64 },
+    /// Remove everyone from the bot's party/raid (!group disband).
+    GroupDisband { reply_channel: u64 },
+    /// Invite, remove, promote or demote a guild member (!guild ...).
+    GuildMember {
+        action: GuildMemberAction,
+        name: String,
+        reply_channel: u64,
+    },
 }
 
 /// Structured response data for Discord commands.
@@ -113,8 +193,28 @@ pub enum CommandResponseData {
     WhoList(Vec<GuildMember>, Option<String>), // (members, guild_name)
     /// Single member search result (!who <name>).
     WhoSearch(String, Option<GuildMember>, Option<String>), // (search_input, member, guild_name)
+    /// Server-wide WHO results for a non-guild player (!who <name>).
+    WhoServer(String, Vec<WhoPlayer>), // (search_input, players)
+    /// Players, creatures and game objects around the bot (!nearby).
+    Nearby(Vec<NearbyEntity>),
+    /// Custom channel member list (!members).
+    ChannelMembers(String, Vec<ChannelMember>), // (channel_name, members)
     /// Guild MOTD (!gmotd).
     GuildMotd(Option<String>, Option<String>), // (motd, guild_name)
+    /// Guild information text (!ginfo).
+    GuildInfoText(Option<String>, Option<String>), // (info, guild_name)
+    /// Guild member notes (!note).
+    GuildNote(String, String, Option<String>), // (name, note, officer_note)
+    /// Recent guild event log entries, oldest first (!guildlog).
+    GuildLog(Vec<GuildLogEntryInfo>),
+    /// Inactive guild members, longest absent first (!inactive).
+    Inactive(Vec<GuildMember>, u32, Option<String>), // (members, days, rank)
+    /// Full guild roster, uploaded as a file attachment (!export roster).
+    RosterExport(Vec<GuildMember>, ExportFormat),
+    /// Recorded play activity of one member (!activity).
+    Activity(String, Option<ActivitySummary>), // (search_input, summary)
+    /// Members with the most play time, as hours (!top playtime).
+    TopPlaytime(Vec<(String, f32)>, u32), // (members, days)
     /// Error response (e.g., game disconnected).
     Error(String),
 }
@@ -137,6 +237,7 @@ pub enum ActivityStatus {
 pub struct GuildDashboardData {
     pub guild_name: String,
     pub realm: String,
+    /// All guild members; each dashboard picks the ones it shows.
     pub members: Vec<GuildMember>,
     pub online: bool,
 }
@@ -150,6 +251,57 @@ pub enum DashboardEvent {
     SetOffline,
 }
 
+/// State of a custom WoW channel as seen by the game client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelStatus {
+    /// Bot is in the channel.
+    Joined,
+    /// Bot was removed from the channel and will retry joining.
+    Rejoining,
+    /// Joining is impossible (banned, wrong password, ...).
+    Degraded,
+    /// Informational notice (throttled, muted); the channel still works.
+    Notice,
+}
+
+/// Custom channel state change reported by the game client.
+#[derive(Debug, Clone)]
+pub struct ChannelEvent {
+    /// WoW channel name.
+    pub channel_name: String,
+    pub status: ChannelStatus,
+    /// Human-readable description for the admin channel.
+    pub message: String,
+}
+
+/// Social request answered by the game client.
+#[derive(Debug, Clone)]
+pub struct SocialNotice {
+    pub request: SocialRequest,
+    /// Player who sent the request.
+    pub player: String,
+    pub action: SocialAction,
+}
+
+impl SocialNotice {
+    /// Human-readable description for the admin channel.
+    pub fn message(&self) -> String {
+        let request = match self.request {
+            SocialRequest::Duel => "challenged the bot to a duel",
+            SocialRequest::Trade => "tried to trade the bot",
+            SocialRequest::Group => "invited the bot to a group",
+            SocialRequest::Guild => "invited the bot to a guild",
+            SocialRequest::Petition => "asked the bot to sign a petition",
+        };
+        let action = match self.action {
+            SocialAction::Accept => "accepted",
+            SocialAction::Decline => "declined",
+            SocialAction::Ignore => "ignored",
+        };
+        format!("{} {} ({})", self.player, request, action)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Text splitting utilities
 // ---------------------------------------------------------------------------
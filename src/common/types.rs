@@ -16,6 +16,76 @@ pub struct Player {
     pub zone_id: u32,
 }
 
+/// A player entry returned by a server-wide WHO query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhoPlayer {
+    pub name: String,
+    pub guild_name: String,
+    pub level: u8,
+    pub class: Option<Class>,
+    pub race: Option<Race>,
+    pub zone_id: u32,
+}
+
+/// A member of a custom chat channel (!members).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelMember {
+    pub guid: Guid,
+    /// Resolved character name (None if the name query did not answer in time).
+    pub name: Option<String>,
+    pub owner: bool,
+    pub moderator: bool,
+    pub muted: bool,
+}
+
+/// Moderation action on a custom chat channel (!channel kick, ban, ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelModeration {
+    Kick(String),
+    Ban(String),
+    Unban(String),
+    /// Set the channel password (empty removes it).
+    Password(String),
+    SetOwner(String),
+    Mute(String),
+    Unmute(String),
+    /// Toggle moderated mode.
+    Moderate,
+    /// Toggle join/leave announcements.
+    Announcements,
+}
+
+/// A custom chat channel the bot joins on login.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomChannel {
+    pub name: String,
+    pub password: Option<String>,
+}
+
+/// Kind of world object tracked around the bot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityKind {
+    Player,
+    Creature,
+    GameObject,
+}
+
+/// A world object near the bot (!nearby).
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearbyEntity {
+    pub guid: Guid,
+    pub kind: EntityKind,
+    /// Resolved name (players only, from the name cache).
+    pub name: Option<String>,
+    /// Creature or game object template entry.
+    pub entry: u32,
+    pub level: u8,
+    pub class: Option<Class>,
+    pub race: Option<Race>,
+    /// Distance from the bot in yards, if both positions are known.
+    pub distance: Option<f32>,
+}
+
 /// Represents a guild member.
 #[derive(Debug, Clone, PartialEq)]
 pub struct GuildMember {
@@ -32,12 +102,53 @@ pub struct GuildMember {
     pub officer_note: String,
 }
 
+/// Guild membership action sent from Discord (!guild invite, kick, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuildMemberAction {
+    Invite,
+    Remove,
+    Promote,
+    Demote,
+}
+
+/// Play activity of a guild member over the last weeks (!activity).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivitySummary {
+    pub name: String,
+    pub online: bool,
+    /// Days of history the averages are taken over.
+    pub tracked_days: u32,
+    pub sessions_per_week: f32,
+    pub hours_per_week: f32,
+    /// Usual play hours as (start, end) UTC hour ranges, end exclusive.
+    pub usual_hours: Vec<(u8, u8)>,
+}
+
+/// File format for roster exports (!export roster).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    /// File extension for this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
 /// Type of chat message.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChatType {
     Say,
     Party,
     Raid,
+    RaidLeader,
+    RaidWarning,
     Guild,
     Officer,
     Whisper,
@@ -64,8 +175,8 @@ impl ChatType {
             0x09 => Some(Self::WhisperInform),
             0x0A => Some(Self::Emote),
             0x11 => Some(Self::Channel),
-            0x27 => Some(Self::Raid),
-            0x28 => Some(Self::Raid),
+            0x27 => Some(Self::RaidLeader),
+            0x28 => Some(Self::RaidWarning),
             0x33 => Some(Self::Party),
             0x30 => Some(Self::Achievement),
             0x31 => Some(Self::GuildAchievement),
@@ -88,6 +199,8 @@ impl ChatType {
             Self::WhisperInform => 0x09,
             Self::Emote => 0x0A,
             Self::Channel => 0x11,
+            Self::RaidLeader => 0x27,
+            Self::RaidWarning => 0x28,
             Self::Achievement => 0x30,
             Self::GuildAchievement => 0x31,
         }
@@ -104,7 +217,7 @@ pub struct ChatMessage {
     pub channel_name: Option<String>,
     pub content: String,
     pub format: Option<String>,
-    /// Achievement ID for guild achievement messages.
+    /// Achievement ID for achievement messages.
     pub achievement_id: Option<u32>,
 }
 
@@ -160,3 +273,46 @@ impl GuildEvent {
         }
     }
 }
+
+/// Request from another player that the bot answers automatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocialRequest {
+    Duel,
+    Trade,
+    Group,
+    Guild,
+    Petition,
+}
+
+impl SocialRequest {
+    /// Returns the config key name for this request type.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            Self::Duel => "duel",
+            Self::Trade => "trade",
+            Self::Group => "group",
+            Self::Guild => "guild",
+            Self::Petition => "petition",
+        }
+    }
+}
+
+/// How the bot answers a social request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocialAction {
+    Accept,
+    Decline,
+    /// Leave the request pending (the inviter sees no answer).
+    Ignore,
+}
+
+impl SocialAction {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "accept" => Some(Self::Accept),
+            "decline" => Some(Self::Decline),
+            "ignore" => Some(Self::Ignore),
+            _ => None,
+        }
+    }
+}
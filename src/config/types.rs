@@ -1,5 +1,6 @@
 //! Configuration type definitions.
 
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc, Weekday};
 use serde::{Deserialize, Deserializer};
 
 /// Deserialize a boolean from either a bool or an integer (1=true, 0=false).
@@ -417,8 +418,19 @@ pub struct Config {
     pub filters: Option<FiltersConfig>,
     #[serde(rename = "guild-dashboard", default)]
     pub guild_dashboard: GuildDashboardConfig,
+    /// Additional dashboards, each with its own channel and view.
+    #[serde(rename = "guild-dashboards", default)]
+    pub guild_dashboards: Vec<GuildDashboardConfig>,
     #[serde(default)]
     pub quirks: QuirksConfig,
+    #[serde(default)]
+    pub group: GroupConfig,
+    #[serde(default)]
+    pub social: SocialConfig,
+    #[serde(rename = "inactivity-report", default)]
+    pub inactivity_report: InactivityReportConfig,
+    #[serde(default)]
+    pub activity: ActivityConfig,
 }
 
 fn default_enabled() -> bool {
@@ -433,6 +445,10 @@ fn default_empty_string() -> String {
     String::new()
 }
 
+fn default_social_action() -> String {
+    "decline".to_string()
+}
+
 /// Discord bot configuration.
 #[derive(Debug, Clone, Deserialize)]
 pub struct DiscordConfig {
@@ -455,6 +471,14 @@ pub struct DiscordConfig {
     /// Enable markdown in messages sent from WoW to Discord
     #[serde(default = "default_disabled", deserialize_with = "bool_or_int")]
     pub enable_markdown: bool,
+    /// Discord roles allowed to use admin commands (!channel, ...)
+    /// Can be role names (strings) or role IDs (integers). Empty = admin commands disabled.
+    #[serde(default, deserialize_with = "option_vec_string_or_int")]
+    pub admin_roles: Option<Vec<String>>,
+    /// Discord channel for bot admin notices (kicked/banned from WoW channels, ...)
+    /// Can be a channel name or ID. Empty = notices are only logged.
+    #[serde(default, deserialize_with = "string_or_int_default")]
+    pub admin_channel: String,
 }
 
 /// WoW server connection configuration.
@@ -485,6 +509,13 @@ pub struct WowConfig {
     pub password: String,
     /// Character name to login with
     pub character: String,
+    /// File the player name cache is kept in across restarts (empty = not saved)
+    #[serde(default = "default_name_cache")]
+    pub name_cache: String,
+}
+
+fn default_name_cache() -> String {
+    "name-cache.json".to_string()
 }
 
 fn default_platform() -> String {
@@ -516,9 +547,45 @@ pub struct GuildEventsConfig {
     pub motd: Option<GuildEventConfig>,
     #[serde(default, deserialize_with = "option_struct")]
     pub achievement: Option<GuildEventConfig>,
+    /// Roster-diff events, detected by comparing successive guild rosters.
+    #[serde(default, deserialize_with = "option_struct")]
+    pub level_up: Option<GuildEventConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub rank_changed: Option<GuildEventConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub zone_changed: Option<GuildEventConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub note_changed: Option<GuildEventConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub first_seen: Option<GuildEventConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub gone: Option<GuildEventConfig>,
+    /// Seconds to hold online/offline events so relogs cancel out and bursts are
+    /// sent as one message (default 0, sending them immediately).
+    #[serde(default, deserialize_with = "option_u32")]
+    pub presence_window: Option<u32>,
 }
 
 impl GuildEventsConfig {
+    /// Names of all configurable guild events.
+    pub const EVENT_NAMES: [&'static str; 15] = [
+        "online",
+        "offline",
+        "promoted",
+        "demoted",
+        "joined",
+        "left",
+        "removed",
+        "motd",
+        "achievement",
+        "level_up",
+        "rank_changed",
+        "zone_changed",
+        "note_changed",
+        "first_seen",
+        "gone",
+    ];
+
     /// Look up the config for a guild event by name.
     fn get_event_config(&self, event: &str) -> Option<&GuildEventConfig> {
         match event {
@@ -531,6 +598,25 @@ impl GuildEventsConfig {
             "removed" => self.removed.as_ref(),
             "motd" => self.motd.as_ref(),
             "achievement" => self.achievement.as_ref(),
+            "level_up" => self.level_up.as_ref(),
+            "rank_changed" => self.rank_changed.as_ref(),
+            "zone_changed" => self.zone_changed.as_ref(),
+            "note_changed" => self.note_changed.as_ref(),
+            "first_seen" => self.first_seen.as_ref(),
+            "gone" => self.gone.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Built-in format for roster-diff events, used when none is configured.
+    fn default_event_format(event: &str) -> Option<&'static str> {
+        match event {
+            "level_up" => Some("`[%user] has reached level %message.`"),
+            "rank_changed" => Some("`[%user]'s rank changed to [%rank].`"),
+            "zone_changed" => Some("`[%user] is now in %message.`"),
+            "note_changed" => Some("`[%user]'s note changed to: %message`"),
+            "first_seen" => Some("`[%user] appeared in the guild roster.`"),
+            "gone" => Some("`[%user] is no longer in the guild roster.`"),
             _ => None,
         }
     }
@@ -542,7 +628,43 @@ impl GuildEventsConfig {
 
     /// Get format string for a guild event.
     pub fn get_event_format(&self, event: &str) -> Option<String> {
-        self.get_event_config(event).and_then(|c| c.format.clone())
+        self.get_event_config(event)
+            .and_then(|c| c.format.clone())
+            .or_else(|| Self::default_event_format(event).map(String::from))
+    }
+
+    /// Get the format for several players batched into one online/offline event.
+    pub fn get_event_batch_format(&self, event: &str) -> Option<String> {
+        self.get_event_config(event)
+            .and_then(|c| c.batch_format.clone())
+            .or_else(|| match event {
+                "online" => Some("`[%user] have come online.`".to_string()),
+                "offline" => Some("`[%user] have gone offline.`".to_string()),
+                _ => None,
+            })
+    }
+
+    /// How long online/offline events are held for aggregation.
+    pub fn presence_window(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.presence_window.unwrap_or(0).into())
+    }
+
+    /// Get the Discord channel (name or ID) a guild event is sent to instead of
+    /// the channel mapped to guild chat.
+    pub fn get_event_channel(&self, event: &str) -> Option<&str> {
+        self.get_event_config(event)
+            .and_then(|c| c.channel.as_deref())
+    }
+
+    /// All events with a channel override: (event name, channel name or ID).
+    pub fn event_channels(&self) -> Vec<(String, String)> {
+        Self::EVENT_NAMES
+            .iter()
+            .filter_map(|event| {
+                self.get_event_channel(event)
+                    .map(|channel| (event.to_string(), channel.to_string()))
+            })
+            .collect()
     }
 }
 
@@ -555,6 +677,12 @@ pub struct GuildEventConfig {
     /// Format string for the event message
     #[serde(default, deserialize_with = "option_string")]
     pub format: Option<String>,
+    /// Discord channel (name or ID) to send the event to instead of the guild chat channel
+    #[serde(default, deserialize_with = "option_string")]
+    pub channel: Option<String>,
+    /// Format for several online/offline events batched into one message
+    #[serde(default, deserialize_with = "option_string")]
+    pub batch_format: Option<String>,
 }
 
 /// Chat channel mappings.
@@ -646,6 +774,9 @@ pub struct WowChannelConfig {
     /// Channel name (for custom channels)
     #[serde(default, deserialize_with = "option_string")]
     pub channel: Option<String>,
+    /// Channel password (for password-protected custom channels, e.g. "${?RAID_CHANNEL_PASSWORD}")
+    #[serde(default, deserialize_with = "option_string")]
+    pub password: Option<String>,
     /// Format string for messages from WoW
     #[serde(default, deserialize_with = "option_string")]
     pub format: Option<String>,
@@ -680,8 +811,11 @@ pub struct FiltersConfig {
 }
 
 /// Guild dashboard configuration.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct GuildDashboardConfig {
+    /// Dashboard id, used to keep dashboards apart (defaults to "default" or "dashboard-N")
+    #[serde(default = "default_empty_string")]
+    pub id: String,
     /// Whether the guild dashboard is enabled
     #[serde(default = "default_disabled", deserialize_with = "bool_or_int")]
     pub enabled: bool,
@@ -691,6 +825,81 @@ pub struct GuildDashboardConfig {
         deserialize_with = "string_or_int_default"
     )]
     pub channel: String,
+    /// "online" (default) lists online members, "roster" lists the whole guild
+    #[serde(default = "default_dashboard_mode")]
+    pub mode: String,
+    /// Grouping: "rank", "class", "zone" or "none" (default: rank in roster mode, none otherwise)
+    #[serde(default, deserialize_with = "option_string")]
+    pub group_by: Option<String>,
+    /// Sort columns, "-" prefix for descending (default: last_seen, name)
+    #[serde(default, deserialize_with = "option_vec_string")]
+    pub sort: Option<Vec<String>>,
+    /// Extra note column: "none" (default), "public" or "officer"
+    #[serde(default = "default_dashboard_note")]
+    pub note: String,
+    /// Title shown after the guild name (default: realm name)
+    #[serde(default, deserialize_with = "option_string")]
+    pub title: Option<String>,
+}
+
+impl GuildDashboardConfig {
+    /// Columns the roster dashboard can be sorted by.
+    pub const SORT_COLUMNS: [&'static str; 6] =
+        ["name", "level", "rank", "class", "zone", "last_seen"];
+
+    /// Check if the dashboard lists the whole roster instead of online members.
+    pub fn is_roster_mode(&self) -> bool {
+        self.mode.eq_ignore_ascii_case("roster")
+    }
+
+    /// Grouping for this dashboard, lowercase.
+    pub fn group_by(&self) -> String {
+        match &self.group_by {
+            Some(group_by) => group_by.to_lowercase(),
+            None if self.is_roster_mode() => "rank".to_string(),
+            None => "none".to_string(),
+        }
+    }
+
+    /// Sort columns as (column, descending) pairs.
+    pub fn sort_columns(&self) -> Vec<(String, bool)> {
+        match &self.sort {
+            Some(columns) if !columns.is_empty() => columns
+                .iter()
+                .map(|c| match c.strip_prefix('-') {
+                    Some(column) => (column.to_lowercase(), true),
+                    None => (c.to_lowercase(), false),
+                })
+                .collect(),
+            _ => vec![
+                ("last_seen".to_string(), false),
+                ("name".to_string(), false),
+            ],
+        }
+    }
+}
+
+impl Default for GuildDashboardConfig {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            enabled: false,
+            channel: String::new(),
+            mode: default_dashboard_mode(),
+            group_by: None,
+            sort: None,
+            note: default_dashboard_note(),
+            title: None,
+        }
+    }
+}
+
+fn default_dashboard_mode() -> String {
+    "online".to_string()
+}
+
+fn default_dashboard_note() -> String {
+    "none".to_string()
 }
 
 /// Server quirks configuration.
@@ -700,6 +909,173 @@ pub struct QuirksConfig {
     pub sit: bool,
 }
 
+/// Party/raid group configuration.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct GroupConfig {
+    /// Accept group invites from guild members
+    #[serde(default = "default_disabled", deserialize_with = "bool_or_int")]
+    pub accept_guild_invites: bool,
+    /// Character names whose group invites are always accepted
+    #[serde(default, deserialize_with = "option_vec_string")]
+    pub invite_allowlist: Option<Vec<String>>,
+}
+
+/// Automatic answers to duel, trade, group, guild and petition requests.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct SocialConfig {
+    #[serde(default, deserialize_with = "option_struct")]
+    pub duel: Option<SocialPolicyConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub trade: Option<SocialPolicyConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub group: Option<SocialPolicyConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub guild: Option<SocialPolicyConfig>,
+    #[serde(default, deserialize_with = "option_struct")]
+    pub petition: Option<SocialPolicyConfig>,
+}
+
+impl SocialConfig {
+    /// Look up the policy for a social request by name.
+    pub fn get_policy_config(&self, request: &str) -> Option<&SocialPolicyConfig> {
+        match request {
+            "duel" => self.duel.as_ref(),
+            "trade" => self.trade.as_ref(),
+            "group" => self.group.as_ref(),
+            "guild" => self.guild.as_ref(),
+            "petition" => self.petition.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Get the action for a social request ("decline" when not configured).
+    pub fn get_action(&self, request: &str) -> &str {
+        self.get_policy_config(request)
+            .map_or("decline", |c| c.action.as_str())
+    }
+
+    /// Check if a Discord notice should be posted for a social request.
+    pub fn is_notify_enabled(&self, request: &str) -> bool {
+        self.get_policy_config(request).is_some_and(|c| c.notify)
+    }
+}
+
+/// Policy for a single kind of social request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SocialPolicyConfig {
+    /// "decline" (default), "accept" or "ignore"
+    #[serde(default = "default_social_action")]
+    pub action: String,
+    /// Post a notice to the Discord admin channel
+    #[serde(default = "default_disabled", deserialize_with = "bool_or_int")]
+    pub notify: bool,
+}
+
+/// Weekly report of inactive guild members.
+#[derive(Debug, Clone, Deserialize)]
+pub struct InactivityReportConfig {
+    /// Whether the weekly report is posted
+    #[serde(default = "default_disabled", deserialize_with = "bool_or_int")]
+    pub enabled: bool,
+    /// Discord channel name or ID to post the report to
+    #[serde(
+        default = "default_empty_string",
+        deserialize_with = "string_or_int_default"
+    )]
+    pub channel: String,
+    /// Members offline at least this many days are listed
+    #[serde(default = "default_inactive_days")]
+    pub days: u32,
+    /// Day of the week to post on, e.g. "monday" or "mon"
+    #[serde(default = "default_report_weekday")]
+    pub weekday: String,
+    /// Hour of the day to post at (UTC)
+    #[serde(default = "default_report_hour")]
+    pub hour: u32,
+    /// File the date of the last posted report is kept in (empty = not kept)
+    #[serde(default = "default_report_file")]
+    pub file: String,
+}
+
+impl InactivityReportConfig {
+    /// Parsed report weekday.
+    pub fn weekday(&self) -> Option<Weekday> {
+        self.weekday.parse().ok()
+    }
+
+    /// Check if the report should be posted at `now`, given the date it was last posted.
+    pub fn is_due(&self, now: DateTime<Utc>, last_posted: Option<NaiveDate>) -> bool {
+        self.enabled
+            && self.weekday() == Some(now.weekday())
+            && now.hour() >= self.hour
+            && last_posted != Some(now.date_naive())
+    }
+}
+
+impl Default for InactivityReportConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            channel: String::new(),
+            days: default_inactive_days(),
+            weekday: default_report_weekday(),
+            hour: default_report_hour(),
+            file: default_report_file(),
+        }
+    }
+}
+
+/// Days offline before a member counts as inactive.
+pub const DEFAULT_INACTIVE_DAYS: u32 = 30;
+
+fn default_inactive_days() -> u32 {
+    DEFAULT_INACTIVE_DAYS
+}
+
+fn default_report_weekday() -> String {
+    "monday".to_string()
+}
+
+fn default_report_hour() -> u32 {
+    18
+}
+
+fn default_report_file() -> String {
+    "inactivity-report.json".to_string()
+}
+
+/// Guild member activity tracking.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActivityConfig {
+    /// Whether play sessions are recorded
+    #[serde(default = "default_disabled", deserialize_with = "bool_or_int")]
+    pub enabled: bool,
+    /// File the session history is kept in
+    #[serde(default = "default_activity_file")]
+    pub file: String,
+    /// Sessions older than this many days are dropped
+    #[serde(default = "default_activity_retention_days")]
+    pub retention_days: u32,
+}
+
+impl Default for ActivityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            file: default_activity_file(),
+            retention_days: default_activity_retention_days(),
+        }
+    }
+}
+
+fn default_activity_file() -> String {
+    "activity.json".to_string()
+}
+
+fn default_activity_retention_days() -> u32 {
+    90
+}
+
 impl Config {
     /// Get the realmlist host and port.
     /// If realmlist contains a port, it's extracted. Otherwise defaults to 3724.
@@ -738,6 +1114,29 @@ impl Config {
     pub fn get_guild_event_format(&self, event: &str) -> Option<String> {
         self.guild.get_event_format(event)
     }
+
+    /// Enabled dashboards with their ids filled in: `guild-dashboard` first, then
+    /// each `guild-dashboards` entry.
+    pub fn dashboards(&self) -> Vec<GuildDashboardConfig> {
+        let mut dashboards = Vec::new();
+        if self.guild_dashboard.enabled {
+            let mut dashboard = self.guild_dashboard.clone();
+            if dashboard.id.is_empty() {
+                dashboard.id = "default".to_string();
+            }
+            dashboards.push(dashboard);
+        }
+        for (i, dashboard) in self.guild_dashboards.iter().enumerate() {
+            if dashboard.enabled {
+                let mut dashboard = dashboard.clone();
+                if dashboard.id.is_empty() {
+                    dashboard.id = format!("dashboard-{}", i + 1);
+                }
+                dashboards.push(dashboard);
+            }
+        }
+        dashboards
+    }
 }
 
 #[cfg(test)]
@@ -750,6 +1149,8 @@ impl Default for DiscordConfig {
             enable_commands_channels: None,
             enable_tag_failed_notifications: false,
             enable_markdown: false,
+            admin_roles: None,
+            admin_channel: String::new(),
         }
     }
 }
@@ -768,6 +1169,7 @@ impl Default for WowConfig {
             account: "testuser".to_string(),
             password: "testpass".to_string(),
             character: "TestChar".to_string(),
+            name_cache: String::new(),
         }
     }
 }
@@ -782,7 +1184,12 @@ impl Default for Config {
             chat: ChatConfig::default(),
             filters: None,
             guild_dashboard: GuildDashboardConfig::default(),
+            guild_dashboards: Vec::new(),
             quirks: QuirksConfig::default(),
+            group: GroupConfig::default(),
+            social: SocialConfig::default(),
+            inactivity_report: InactivityReportConfig::default(),
+            activity: ActivityConfig::default(),
         }
     }
 }
@@ -791,6 +1198,7 @@ impl Default for Config {
 mod tests {
     use super::*;
     use crate::config::parser::load_config_str;
+    use chrono::TimeZone;
 
     #[test]
     fn test_bool_or_int_deserializer_integers() {
@@ -864,6 +1272,219 @@ mod tests {
         assert!(!config.filters.unwrap().enabled);
     }
 
+    #[test]
+    fn test_roster_diff_events_config() {
+        let config_str = r#"
+            discord {
+                token="test"
+            }
+            wow {
+                realmlist=localhost
+                realm=Test
+                account=testuser
+                password=testpass
+                character=TestChar
+            }
+            guild {
+                level_up {
+                    enabled=true
+                }
+                rank_changed {
+                    enabled=true
+                    format="%user is now %rank"
+                }
+            }
+        "#;
+
+        let config = load_config_str(config_str).expect("Should parse roster-diff events");
+        assert!(config.is_guild_event_enabled("level_up"));
+        assert!(config.is_guild_event_enabled("rank_changed"));
+        assert!(!config.is_guild_event_enabled("note_changed"));
+        assert_eq!(
+            config.guild.get_event_format("level_up").as_deref(),
+            Some("`[%user] has reached level %message.`")
+        );
+        assert_eq!(
+            config.guild.get_event_format("rank_changed").as_deref(),
+            Some("%user is now %rank")
+        );
+    }
+
+    #[test]
+    fn test_multiple_dashboards_config() {
+        let config_str = r#"
+            discord {
+                token="test"
+            }
+            wow {
+                realmlist=localhost
+                realm=Test
+                account=testuser
+                password=testpass
+                character=TestChar
+            }
+            guild-dashboard {
+                enabled=true
+                channel=guild-online
+            }
+            guild-dashboards = [
+                {
+                    id=officers
+                    enabled=true
+                    channel=officers
+                    mode=roster
+                    note=officer
+                    sort=["-last_seen"]
+                }
+                {
+                    enabled=true
+                    channel=guild-zones
+                    group_by=zone
+                }
+                {
+                    channel=unused
+                }
+            ]
+        "#;
+
+        let config = load_config_str(config_str).expect("Should parse dashboards");
+        let dashboards = config.dashboards();
+        let ids: Vec<&str> = dashboards.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, vec!["default", "officers", "dashboard-2"]);
+        assert_eq!(dashboards[0].group_by(), "none");
+        assert_eq!(dashboards[1].group_by(), "rank");
+        assert_eq!(dashboards[1].note, "officer");
+        assert_eq!(
+            dashboards[1].sort_columns(),
+            vec![("last_seen".to_string(), true)]
+        );
+        assert_eq!(dashboards[2].group_by(), "zone");
+    }
+
+    #[test]
+    fn test_presence_window_config() {
+        let config_str = r#"
+            discord {
+                token="test"
+            }
+            wow {
+                realmlist=localhost
+                realm=Test
+                account=testuser
+                password=testpass
+                character=TestChar
+            }
+            guild {
+                presence_window=30
+                online {
+                    batch_format="%user are online"
+                }
+            }
+        "#;
+
+        let config = load_config_str(config_str).expect("Should parse presence window");
+        assert_eq!(
+            config.guild.presence_window(),
+            std::time::Duration::from_secs(30)
+        );
+        assert_eq!(
+            config.guild.get_event_batch_format("online").as_deref(),
+            Some("%user are online")
+        );
+        assert_eq!(
+            config.guild.get_event_batch_format("offline").as_deref(),
+            Some("`[%user] have gone offline.`")
+        );
+        // Aggregation is off unless configured
+        assert!(GuildEventsConfig::default().presence_window().is_zero());
+    }
+
+    #[test]
+    fn test_inactivity_report_config() {
+        let config_str = r#"
+            discord {
+                token="test"
+            }
+            wow {
+                realmlist=localhost
+                realm=Test
+                account=testuser
+                password=testpass
+                character=TestChar
+            }
+            inactivity-report {
+                enabled=1
+                channel=officers
+                days=14
+                weekday=sun
+                hour=20
+            }
+        "#;
+
+        let config = load_config_str(config_str).expect("Should parse inactivity report");
+        let report = &config.inactivity_report;
+        assert_eq!(report.channel, "officers");
+        assert_eq!(report.days, 14);
+        assert_eq!(report.weekday(), Some(Weekday::Sun));
+        assert_eq!(report.file, "inactivity-report.json");
+
+        // 2024-06-02 is a Sunday
+        let before = Utc.with_ymd_and_hms(2024, 6, 2, 19, 59, 0).unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 6, 2, 20, 0, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2024, 6, 3, 20, 0, 0).unwrap();
+        assert!(!report.is_due(before, None));
+        assert!(report.is_due(at, None));
+        assert!(!report.is_due(at, Some(at.date_naive())));
+        assert!(!report.is_due(monday, None));
+
+        let default = InactivityReportConfig::default();
+        assert_eq!(default.days, DEFAULT_INACTIVE_DAYS);
+        assert!(!default.is_due(at, None));
+    }
+
+    #[test]
+    fn test_guild_event_channel_override() {
+        let config_str = r#"
+            discord {
+                token="test"
+            }
+            wow {
+                realmlist=localhost
+                realm=Test
+                account=testuser
+                password=testpass
+                character=TestChar
+            }
+            guild {
+                online {
+                    enabled=true
+                    channel="guild-activity"
+                }
+                promoted {
+                    enabled=true
+                    channel="123456789"
+                }
+                demoted {
+                    enabled=true
+                }
+            }
+        "#;
+
+        let config = load_config_str(config_str).expect("Should parse event channels");
+        assert_eq!(
+            config.guild.get_event_channel("online"),
+            Some("guild-activity")
+        );
+        assert_eq!(config.guild.get_event_channel("demoted"), None);
+        assert_eq!(
+            config.guild.event_channels(),
+            vec![
+                ("online".to_string(), "guild-activity".to_string()),
+                ("promoted".to_string(), "123456789".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_direction_deserializes_from_hocon() {
         let config_str = r#"
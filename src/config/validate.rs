@@ -2,7 +2,7 @@
 //!
 //! Validates configuration values and provides helpful error messages.
 
-use crate::config::types::Config;
+use crate::config::types::{Config, GuildDashboardConfig};
 use anyhow::{anyhow, Result};
 
 /// Validate a configuration and return detailed errors.
@@ -60,6 +60,12 @@ pub fn validate_config(config: &Config) -> Result<()> {
         let valid_types = [
             "guild",
             "officer",
+            "party",
+            "raid",
+            "raidleader",
+            "raid_leader",
+            "raidwarning",
+            "raid_warning",
             "say",
             "yell",
             "emote",
@@ -89,6 +95,17 @@ pub fn validate_config(config: &Config) -> Result<()> {
             ));
         }
 
+        // Passwords only apply to custom channels
+        if mapping.wow.password.is_some()
+            && channel_type_lower != "channel"
+            && channel_type_lower != "custom"
+        {
+            errors.push(format!(
+                "chat.channels[{}].wow.password is only supported when type is 'Channel'",
+                i
+            ));
+        }
+
         // Discord channel name is required
         if mapping.discord.channel.is_empty() {
             errors.push(format!("chat.channels[{}].discord.channel is required", i));
@@ -99,6 +116,71 @@ pub fn validate_config(config: &Config) -> Result<()> {
         errors.push("chat.channels is empty - no message routing configured".to_string());
     }
 
+    // Validate social request policies
+    for request in ["duel", "trade", "group", "guild", "petition"] {
+        let Some(policy) = config.social.get_policy_config(request) else {
+            continue;
+        };
+        let action = policy.action.to_lowercase();
+        if !["accept", "decline", "ignore"].contains(&action.as_str()) {
+            errors.push(format!(
+                "social.{}.action '{}' is invalid (use: accept, decline, ignore)",
+                request, policy.action
+            ));
+        } else if request == "trade" && action == "accept" {
+            errors
+                .push("social.trade.action cannot be 'accept' (use: decline, ignore)".to_string());
+        }
+    }
+
+    // Validate dashboards
+    validate_dashboard("guild-dashboard", &config.guild_dashboard, &mut errors);
+    for (i, dashboard) in config.guild_dashboards.iter().enumerate() {
+        let prefix = format!("guild-dashboards[{}]", i);
+        validate_dashboard(&prefix, dashboard, &mut errors);
+        if dashboard.enabled && dashboard.channel.is_empty() {
+            errors.push(format!("{}.channel is required", prefix));
+        }
+    }
+    let mut dashboard_ids = std::collections::HashSet::new();
+    for dashboard in config.dashboards() {
+        if !dashboard_ids.insert(dashboard.id.clone()) {
+            errors.push(format!(
+                "dashboard id '{}' is used more than once",
+                dashboard.id
+            ));
+        }
+    }
+
+    // Validate the weekly inactivity report
+    let report = &config.inactivity_report;
+    if report.enabled && report.channel.is_empty() {
+        errors.push("inactivity-report.channel is required".to_string());
+    }
+    if report.days == 0 {
+        errors.push("inactivity-report.days must be at least 1".to_string());
+    }
+    if report.weekday().is_none() {
+        errors.push(format!(
+            "inactivity-report.weekday '{}' is invalid (use: monday .. sunday)",
+            report.weekday
+        ));
+    }
+    if report.hour > 23 {
+        errors.push(format!(
+            "inactivity-report.hour {} is invalid (use: 0-23)",
+            report.hour
+        ));
+    }
+
+    // Validate activity tracking
+    if config.activity.enabled && config.activity.file.is_empty() {
+        errors.push("activity.file is required".to_string());
+    }
+    if config.activity.retention_days == 0 {
+        errors.push("activity.retention_days must be at least 1".to_string());
+    }
+
     // Validate filter patterns (try to compile them)
     if let Some(ref filters) = config.filters {
         if let Some(ref patterns) = filters.patterns {
@@ -123,6 +205,39 @@ pub fn validate_config(config: &Config) -> Result<()> {
     }
 }
 
+/// Validate the view settings of one dashboard.
+fn validate_dashboard(prefix: &str, dashboard: &GuildDashboardConfig, errors: &mut Vec<String>) {
+    if !["online", "roster"].contains(&dashboard.mode.to_lowercase().as_str()) {
+        errors.push(format!(
+            "{}.mode '{}' is invalid (use: online, roster)",
+            prefix, dashboard.mode
+        ));
+    }
+    let group_by = dashboard.group_by();
+    if !["rank", "class", "zone", "none"].contains(&group_by.as_str()) {
+        errors.push(format!(
+            "{}.group_by '{}' is invalid (use: rank, class, zone, none)",
+            prefix, group_by
+        ));
+    }
+    for (column, _) in dashboard.sort_columns() {
+        if !GuildDashboardConfig::SORT_COLUMNS.contains(&column.as_str()) {
+            errors.push(format!(
+                "{}.sort column '{}' is invalid (use: {})",
+                prefix,
+                column,
+                GuildDashboardConfig::SORT_COLUMNS.join(", ")
+            ));
+        }
+    }
+    if !["none", "public", "officer"].contains(&dashboard.note.to_lowercase().as_str()) {
+        errors.push(format!(
+            "{}.note '{}' is invalid (use: none, public, officer)",
+            prefix, dashboard.note
+        ));
+    }
+}
+
 /// Quick check if config has the minimum required fields populated.
 pub fn has_required_fields(config: &Config) -> bool {
     !config.discord.token.is_empty()
@@ -146,6 +261,7 @@ mod tests {
                     wow: WowChannelConfig {
                         channel_type: "Guild".to_string(),
                         channel: None,
+                        password: None,
                         format: Some("[%user]: %message".to_string()),
                         filters: None,
                     },
@@ -226,6 +342,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_group_channel_types_accepted() {
+        for channel_type in &["Party", "Raid", "RaidLeader", "raid_leader", "RaidWarning"] {
+            let mut config = make_valid_config();
+            config.chat.channels[0].wow.channel_type = channel_type.to_string();
+            assert!(
+                validate_config(&config).is_ok(),
+                "channel type '{}' should be valid",
+                channel_type
+            );
+        }
+    }
+
     #[test]
     fn test_custom_channel_type_accepted() {
         let mut config = make_valid_config();
@@ -247,6 +376,19 @@ mod tests {
             .contains("channel is required"));
     }
 
+    #[test]
+    fn test_password_requires_custom_channel() {
+        let mut config = make_valid_config();
+        config.chat.channels[0].wow.password = Some("secret".to_string());
+        let result = validate_config(&config);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("wow.password"));
+
+        config.chat.channels[0].wow.channel_type = "Channel".to_string();
+        config.chat.channels[0].wow.channel = Some("RaidTeam".to_string());
+        assert!(validate_config(&config).is_ok());
+    }
+
     #[test]
     fn test_invalid_channel_type_rejected() {
         let mut config = make_valid_config();
@@ -256,6 +398,96 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("invalid"));
     }
 
+    #[test]
+    fn test_social_action_validated() {
+        let mut config = make_valid_config();
+        config.social.duel = Some(SocialPolicyConfig {
+            action: "Accept".to_string(),
+            notify: true,
+        });
+        assert!(validate_config(&config).is_ok());
+
+        config.social.duel.as_mut().unwrap().action = "maybe".to_string();
+        let result = validate_config(&config);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("social.duel.action"));
+
+        config.social.duel = None;
+        config.social.trade = Some(SocialPolicyConfig {
+            action: "accept".to_string(),
+            notify: false,
+        });
+        assert!(validate_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_dashboard_layout_validated() {
+        let mut config = make_valid_config();
+        config.guild_dashboard.mode = "Roster".to_string();
+        config.guild_dashboard.group_by = Some("class".to_string());
+        config.guild_dashboard.sort = Some(vec!["-level".to_string(), "name".to_string()]);
+        assert!(validate_config(&config).is_ok());
+
+        config.guild_dashboard.sort = Some(vec!["gear".to_string()]);
+        let result = validate_config(&config);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("guild-dashboard.sort"));
+
+        config.guild_dashboard.sort = None;
+        config.guild_dashboard.mode = "everyone".to_string();
+        assert!(validate_config(&config).is_err());
+
+        // Dashboard ids must be unique
+        config.guild_dashboard.mode = "online".to_string();
+        config.guild_dashboard.enabled = true;
+        config.guild_dashboard.channel = "guild-online".to_string();
+        let mut officers = config.guild_dashboard.clone();
+        officers.id = "default".to_string();
+        config.guild_dashboards.push(officers);
+        let result = validate_config(&config);
+        assert!(result.unwrap_err().to_string().contains("more than once"));
+
+        config.guild_dashboards[0].id = "officers".to_string();
+        assert!(validate_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_inactivity_report_validated() {
+        let mut config = make_valid_config();
+        config.inactivity_report.enabled = true;
+        config.inactivity_report.channel = "officers".to_string();
+        assert!(validate_config(&config).is_ok());
+
+        config.inactivity_report.weekday = "someday".to_string();
+        config.inactivity_report.hour = 24;
+        let error = validate_config(&config).unwrap_err().to_string();
+        assert!(error.contains("inactivity-report.weekday"));
+        assert!(error.contains("inactivity-report.hour"));
+
+        config.inactivity_report.weekday = "Fri".to_string();
+        config.inactivity_report.hour = 0;
+        config.inactivity_report.channel = String::new();
+        assert!(validate_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_activity_validated() {
+        let mut config = make_valid_config();
+        config.activity.enabled = true;
+        assert!(validate_config(&config).is_ok());
+
+        config.activity.file = String::new();
+        config.activity.retention_days = 0;
+        let error = validate_config(&config).unwrap_err().to_string();
+        assert!(error.contains("activity.file"));
+        assert!(error.contains("activity.retention_days"));
+    }
+
     #[test]
     fn test_has_required_fields() {
         let config = make_valid_config();
@@ -19,9 +19,9 @@ use tracing::{debug, error, info, warn};
 use backon::BackoffBuilder;
 
 use crate::bridge::{Bridge, ChannelConfig, PendingBridgeState};
-use crate::bridge::state::parse_channel_config;
+use crate::bridge::state::{parse_channel_config, OutputChannelConfigs};
 use crate::common::{ActivityStatus, BridgeMessage};
-use crate::common::messages::DashboardEvent;
+use crate::common::messages::{ChannelEvent, DashboardEvent, SocialNotice};
 use crate::config::types::{Config, Direction, GuildDashboardConfig};
 use crate::discord::commands::{CommandResponse, WowCommand};
 use crate::discord::handler::{BridgeHandler, TaskChannels};
@@ -88,6 +88,10 @@ pub struct DiscordChannels {
     pub status_rx: mpsc::UnboundedReceiver<ActivityStatus>,
     /// Receiver for dashboard updates from game client.
     pub dashboard_rx: mpsc::UnboundedReceiver<DashboardEvent>,
+    /// Receiver for custom channel events from game client.
+    pub channel_event_rx: mpsc::UnboundedReceiver<ChannelEvent>,
+    /// Receiver for answered social requests from game client.
+    pub social_notice_rx: mpsc::UnboundedReceiver<SocialNotice>,
     /// Receiver for shutdown signal.
     pub shutdown_rx: watch::Receiver<bool>,
 }
@@ -154,7 +158,11 @@ impl DiscordBotBuilder {
             self.config.discord.enable_commands_channels.clone(),
             self.config.discord.enable_markdown,
             self.config.discord.enable_tag_failed_notifications,
-            Some(self.config.guild_dashboard.clone()),
+            OutputChannelConfigs {
+                dashboard_configs: self.config.dashboards(),
+                guild_event_channels: self.config.guild.event_channels(),
+                inactivity_report: self.config.inactivity_report.clone(),
+            },
         );
 
         // Create task channels bundle
@@ -163,6 +171,8 @@ impl DiscordBotBuilder {
             cmd_response_rx: self.channels.cmd_response_rx,
             status_rx: self.channels.status_rx,
             dashboard_rx: self.channels.dashboard_rx,
+            channel_event_rx: self.channels.channel_event_rx,
+            social_notice_rx: self.channels.social_notice_rx,
         };
 
         let (discord_events_tx, discord_events_rx) = mpsc::unbounded_channel::<DiscordBotEvent>();
@@ -173,7 +183,9 @@ impl DiscordBotBuilder {
             self.bridge.clone(),
             pending_state,
             self.channels.command_tx.clone(),
-            self.config.guild_dashboard.clone(),
+            self.config.dashboards(),
+            self.config.discord.admin_roles.clone(),
+            self.config.discord.admin_channel.clone(),
             init_complete_tx,
         );
 
@@ -336,6 +348,8 @@ impl DiscordBot {
     ) {
         let mut discord_user = None;
         let mut discord_connection = None;
+        // Scheduled reports only need minute precision
+        let mut report_interval = tokio::time::interval(Duration::from_secs(60));
 
         loop {
             tokio::select! {
@@ -375,6 +389,13 @@ impl DiscordBot {
                     }
                 }
 
+                // Scheduled reports (skip if not connected)
+                _ = report_interval.tick() => {
+                    if discord_connection.is_some() {
+                        handler.handle_report_tick();
+                    }
+                }
+
                 // WoW -> Discord messages (drop if not connected)
                 message = task_channels.wow_rx.recv() => {
                     match message {
@@ -443,6 +464,40 @@ impl DiscordBot {
                     }
                 }
 
+                // Custom channel events (drop if not connected)
+                event = task_channels.channel_event_rx.recv() => {
+                    match event {
+                        Some(event) => {
+                            if let Some(ref context) = discord_connection {
+                                handler.handle_channel_event(context, event).await;
+                            } else {
+                                debug!("Dropping channel event - Discord not connected");
+                            }
+                        }
+                        None => {
+                            warn!("Channel event channel closed");
+                            break;
+                        }
+                    }
+                }
+
+                // Social request notices (drop if not connected)
+                notice = task_channels.social_notice_rx.recv() => {
+                    match notice {
+                        Some(notice) => {
+                            if let Some(ref context) = discord_connection {
+                                handler.handle_social_notice(context, notice).await;
+                            } else {
+                                debug!("Dropping social notice - Discord not connected");
+                            }
+                        }
+                        None => {
+                            warn!("Social notice channel closed");
+                            break;
+                        }
+                    }
+                }
+
                 // Shutdown signal
                 _ = shutdown_rx.changed() => {
                     if *shutdown_rx.borrow() {
@@ -5,9 +5,15 @@
 use serenity::model::channel::Message;
 use serenity::prelude::*;
 use tokio::sync::mpsc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use crate::common::messages::CommandResponseData;
+use crate::config::types::DEFAULT_INACTIVE_DAYS;
+use crate::common::types::{ChannelModeration, ExportFormat, GuildMemberAction};
+
+const CHANNEL_USAGE: &str = "Usage: `!channel join <name> [password]`, `!channel leave <name>`, \
+`!channel kick|ban|unban|mute|unmute|owner <name> <player>`, `!channel password <name> [password]`, \
+`!channel moderate|announcements <name>`";
 
 /// Commands that can be sent to the WoW handler.
 #[derive(Debug, Clone)]
@@ -16,6 +22,77 @@ pub enum WowCommand {
     Who { args: Option<String>, reply_channel: u64 },
     /// Request guild MOTD (!gmotd).
     GuildMotd { reply_channel: u64 },
+    /// Change the guild MOTD (!gmotd set <text>).
+    SetGuildMotd { text: String, reply_channel: u64 },
+    /// Request the guild information text (!ginfo).
+    GuildInfo { reply_channel: u64 },
+    /// Change the guild information text (!ginfo set <text>).
+    SetGuildInfo { text: String, reply_channel: u64 },
+    /// Request objects around the bot (!nearby).
+    Nearby { reply_channel: u64 },
+    /// Show connection status and relay counters (!status).
+    Status { reply_channel: u64 },
+    /// Join a custom channel (!channel join <name> [password]).
+    ChannelJoin {
+        name: String,
+        password: Option<String>,
+        reply_channel: u64,
+    },
+    /// Leave a custom channel (!channel leave <name>).
+    ChannelLeave { name: String, reply_channel: u64 },
+    /// Moderate a custom channel (!channel kick/ban/unban/password/owner/mute/unmute/moderate/announcements).
+    ChannelModerate {
+        name: String,
+        action: ChannelModeration,
+        reply_channel: u64,
+    },
+    /// List members of a custom channel (!members <channel>).
+    Members {
+        channel_name: String,
+        reply_channel: u64,
+    },
+    /// Leave the current party/raid (!group leave).
+    GroupLeave { reply_channel: u64 },
+    /// Remove everyone from the bot's party/raid (!group disband).
+    GroupDisband { reply_channel: u64 },
+    /// Show recent guild event log entries (!guildlog [n]).
+    GuildLog { count: usize, reply_channel: u64 },
+    /// List members offline for at least `days` (!inactive [days] [rank]).
+    Inactive {
+        days: u32,
+        rank: Option<String>,
+        reply_channel: u64,
+    },
+    /// Upload the guild roster as a file (!export roster [csv|json]).
+    ExportRoster {
+        format: ExportFormat,
+        reply_channel: u64,
+    },
+    /// Show a member's recorded play activity (!activity <name>).
+    Activity { name: String, reply_channel: u64 },
+    /// Show the members with the most play time (!top playtime).
+    TopPlaytime { reply_channel: u64 },
+    /// Look up a guild member's notes (!note <name>).
+    GuildNote {
+        name: String,
+        /// Include the officer note (only in Officer-mapped channels).
+        officer: bool,
+        reply_channel: u64,
+    },
+    /// Change a guild member's note (!note set [public|officer] <name> [text]).
+    SetGuildNote {
+        name: String,
+        note: String,
+        /// Set the officer note instead of the public note.
+        officer: bool,
+        reply_channel: u64,
+    },
+    /// Manage guild membership (!guild invite|kick|promote|demote <player>).
+    GuildMember {
+        action: GuildMemberAction,
+        name: String,
+        reply_channel: u64,
+    },
 }
 
 /// Responses from the WoW handler.
@@ -29,14 +106,45 @@ pub struct CommandResponse {
 pub struct CommandHandler {
     /// Channel to send commands to WoW handler.
     pub command_tx: mpsc::UnboundedSender<WowCommand>,
+    /// Role names or IDs allowed to use admin commands.
+    admin_roles: Vec<String>,
 }
 
 impl CommandHandler {
-    pub fn new(command_tx: mpsc::UnboundedSender<WowCommand>) -> Self {
-        Self { command_tx }
+    pub fn new(
+        command_tx: mpsc::UnboundedSender<WowCommand>,
+        admin_roles: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            command_tx,
+            admin_roles: admin_roles.unwrap_or_default(),
+        }
+    }
+
+    /// Check whether the message author has one of the configured admin roles.
+    fn is_admin(&self, ctx: &Context, msg: &Message) -> bool {
+        if self.admin_roles.is_empty() {
+            return false;
+        }
+        let Some(member) = msg.member.as_ref() else {
+            return false;
+        };
+        let guild = msg.guild(&ctx.cache);
+
+        member.roles.iter().any(|role_id| {
+            let role_name = guild
+                .as_ref()
+                .and_then(|g| g.roles.get(role_id))
+                .map(|r| r.name.as_str());
+            self.admin_roles.iter().any(|allowed| {
+                *allowed == role_id.get().to_string()
+                    || role_name.is_some_and(|name| name.eq_ignore_ascii_case(allowed))
+            })
+        })
     }
 
     /// Parse and execute a command from Discord.
+    /// `officer_channel` is set when the channel is mapped to Officer chat.
     ///
     /// Returns `true` if the message was a command, `false` otherwise.
     pub async fn handle_command(
@@ -44,8 +152,10 @@ impl CommandHandler {
         ctx: &Context,
         msg: &Message,
         content: &str,
+        officer_channel: bool,
     ) -> anyhow::Result<bool> {
-        if content.len() > 100 {
+        // Long enough for `!ginfo set` with a full 500 character text
+        if content.chars().count() > 600 {
             return Ok(false);
         }
         if !content.starts_with('!') && !content.starts_with('?') {
@@ -64,7 +174,59 @@ impl CommandHandler {
                 Ok(true)
             }
             "gmotd" => {
-                self.handle_gmotd(ctx, msg).await?;
+                self.handle_gmotd(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "ginfo" => {
+                self.handle_ginfo(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "nearby" => {
+                self.handle_nearby(ctx, msg).await?;
+                Ok(true)
+            }
+            "status" => {
+                self.handle_status(ctx, msg).await?;
+                Ok(true)
+            }
+            "members" => {
+                self.handle_members(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "channel" => {
+                self.handle_channel(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "group" => {
+                self.handle_group(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "guild" => {
+                self.handle_guild(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "guildlog" => {
+                self.handle_guildlog(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "inactive" => {
+                self.handle_inactive(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "activity" => {
+                self.handle_activity(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "top" => {
+                self.handle_top(ctx, msg, args).await?;
+                Ok(true)
+            }
+            "export" => {
+                self.handle_export(ctx, msg, args, officer_channel).await?;
+                Ok(true)
+            }
+            "note" => {
+                self.handle_note(ctx, msg, args, officer_channel).await?;
                 Ok(true)
             }
             "help" => {
@@ -96,12 +258,65 @@ impl CommandHandler {
         Ok(())
     }
 
-    /// Handle !gmotd command.
-    async fn handle_gmotd(&self, ctx: &Context, msg: &Message) -> anyhow::Result<()> {
-        info!("!gmotd command from {}", msg.author.name);
+    /// Handle !gmotd and !gmotd set commands (set is admin only).
+    async fn handle_gmotd(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        info!("!gmotd command from {} with args: {:?}", msg.author.name, args);
+
+        let reply_channel = msg.channel_id.get();
+        let command = match parse_set_text(args.as_deref().unwrap_or_default()) {
+            None => WowCommand::GuildMotd { reply_channel },
+            Some(_) if !self.is_admin(ctx, msg) => {
+                warn!("!gmotd set from {} denied: missing admin role", msg.author.name);
+                msg.react(&ctx.http, '⛔').await.ok();
+                return Ok(());
+            }
+            Some("") => {
+                msg.channel_id.say(&ctx.http, "Usage: `!gmotd set <text>`").await?;
+                return Ok(());
+            }
+            Some(text) => WowCommand::SetGuildMotd {
+                text: text.to_string(),
+                reply_channel,
+            },
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '📜').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !ginfo and !ginfo set commands (set is admin only).
+    async fn handle_ginfo(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        info!("!ginfo command from {} with args: {:?}", msg.author.name, args);
 
-        let command = WowCommand::GuildMotd {
-            reply_channel: msg.channel_id.get(),
+        let reply_channel = msg.channel_id.get();
+        let command = match parse_set_text(args.as_deref().unwrap_or_default()) {
+            None => WowCommand::GuildInfo { reply_channel },
+            Some(_) if !self.is_admin(ctx, msg) => {
+                warn!("!ginfo set from {} denied: missing admin role", msg.author.name);
+                msg.react(&ctx.http, '⛔').await.ok();
+                return Ok(());
+            }
+            Some("") => {
+                msg.channel_id.say(&ctx.http, "Usage: `!ginfo set <text>`").await?;
+                return Ok(());
+            }
+            Some(text) => WowCommand::SetGuildInfo {
+                text: text.to_string(),
+                reply_channel,
+            },
         };
 
         self.command_tx.send(command)?;
@@ -111,15 +326,645 @@ impl CommandHandler {
         Ok(())
     }
 
+    /// Handle !nearby command.
+    async fn handle_nearby(&self, ctx: &Context, msg: &Message) -> anyhow::Result<()> {
+        info!("!nearby command from {}", msg.author.name);
+
+        let command = WowCommand::Nearby {
+            reply_channel: msg.channel_id.get(),
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '🧭').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !status command.
+    async fn handle_status(&self, ctx: &Context, msg: &Message) -> anyhow::Result<()> {
+        info!("!status command from {}", msg.author.name);
+
+        let command = WowCommand::Status {
+            reply_channel: msg.channel_id.get(),
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '📡').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !members command.
+    async fn handle_members(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        info!("!members command from {} with args: {:?}", msg.author.name, args);
+
+        let Some(channel_name) = args.filter(|a| !a.is_empty()) else {
+            msg.channel_id
+                .say(&ctx.http, "Usage: `!members <channel>`")
+                .await?;
+            return Ok(());
+        };
+
+        let command = WowCommand::Members {
+            channel_name,
+            reply_channel: msg.channel_id.get(),
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '👥').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !channel join/leave commands (admin only).
+    async fn handle_channel(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        if !self.is_admin(ctx, msg) {
+            warn!("!channel command from {} denied: missing admin role", msg.author.name);
+            msg.react(&ctx.http, '⛔').await.ok();
+            return Ok(());
+        }
+
+        info!("!channel command from {} with args: {:?}", msg.author.name, args);
+
+        let args = args.unwrap_or_default();
+        let Some(command) = parse_channel_command(&args, msg.channel_id.get()) else {
+            msg.channel_id.say(&ctx.http, CHANNEL_USAGE).await?;
+            return Ok(());
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '📡').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !group leave/disband commands (admin only).
+    async fn handle_group(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        if !self.is_admin(ctx, msg) {
+            warn!("!group command from {} denied: missing admin role", msg.author.name);
+            msg.react(&ctx.http, '⛔').await.ok();
+            return Ok(());
+        }
+
+        info!("!group command from {} with args: {:?}", msg.author.name, args);
+
+        let reply_channel = msg.channel_id.get();
+        let command = match args.unwrap_or_default().to_lowercase().as_str() {
+            "leave" => WowCommand::GroupLeave { reply_channel },
+            "disband" => WowCommand::GroupDisband { reply_channel },
+            _ => {
+                msg.channel_id
+                    .say(&ctx.http, "Usage: `!group leave`, `!group disband`")
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '⚔').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !guild invite/kick/promote/demote commands (admin only).
+    async fn handle_guild(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        if !self.is_admin(ctx, msg) {
+            warn!("!guild command from {} denied: missing admin role", msg.author.name);
+            msg.react(&ctx.http, '⛔').await.ok();
+            return Ok(());
+        }
+
+        info!("!guild command from {} with args: {:?}", msg.author.name, args);
+
+        let args = args.unwrap_or_default();
+        let Some(command) = parse_guild_command(&args, msg.channel_id.get()) else {
+            msg.channel_id
+                .say(&ctx.http, "Usage: `!guild invite|kick|promote|demote <player>`")
+                .await?;
+            return Ok(());
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '🏰').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !guildlog command.
+    async fn handle_guildlog(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        info!("!guildlog command from {} with args: {:?}", msg.author.name, args);
+
+        let Some(count) = parse_guildlog_count(args.as_deref().unwrap_or_default()) else {
+            msg.channel_id
+                .say(&ctx.http, "Usage: `!guildlog [1-100]`")
+                .await?;
+            return Ok(());
+        };
+
+        let command = WowCommand::GuildLog {
+            count,
+            reply_channel: msg.channel_id.get(),
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '📖').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !inactive command.
+    async fn handle_inactive(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        info!("!inactive command from {} with args: {:?}", msg.author.name, args);
+
+        let Some((days, rank)) = parse_inactive_args(args.as_deref().unwrap_or_default()) else {
+            msg.channel_id
+                .say(&ctx.http, "Usage: `!inactive [days] [rank]`")
+                .await?;
+            return Ok(());
+        };
+
+        let command = WowCommand::Inactive {
+            days,
+            rank,
+            reply_channel: msg.channel_id.get(),
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '💤').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !activity command.
+    async fn handle_activity(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        info!("!activity command from {} with args: {:?}", msg.author.name, args);
+
+        let name = args.unwrap_or_default();
+        if name.is_empty() || name.contains(' ') {
+            msg.channel_id
+                .say(&ctx.http, "Usage: `!activity <player>`")
+                .await?;
+            return Ok(());
+        }
+
+        let command = WowCommand::Activity {
+            name,
+            reply_channel: msg.channel_id.get(),
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '📊').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !top command.
+    async fn handle_top(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+    ) -> anyhow::Result<()> {
+        info!("!top command from {} with args: {:?}", msg.author.name, args);
+
+        if !args.is_some_and(|a| a.eq_ignore_ascii_case("playtime")) {
+            msg.channel_id
+                .say(&ctx.http, "Usage: `!top playtime`")
+                .await?;
+            return Ok(());
+        }
+
+        let command = WowCommand::TopPlaytime {
+            reply_channel: msg.channel_id.get(),
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '🏆').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !export command (only in Officer-mapped channels).
+    async fn handle_export(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+        officer_channel: bool,
+    ) -> anyhow::Result<()> {
+        if !officer_channel {
+            warn!("!export command from {} denied: not an officer channel", msg.author.name);
+            msg.react(&ctx.http, '⛔').await.ok();
+            return Ok(());
+        }
+
+        info!("!export command from {} with args: {:?}", msg.author.name, args);
+
+        let Some(format) = parse_export_args(args.as_deref().unwrap_or_default()) else {
+            msg.channel_id
+                .say(&ctx.http, "Usage: `!export roster [csv|json]`")
+                .await?;
+            return Ok(());
+        };
+
+        let command = WowCommand::ExportRoster {
+            format,
+            reply_channel: msg.channel_id.get(),
+        };
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '📤').await.ok();
+
+        Ok(())
+    }
+
+    /// Handle !note and !note set commands (set is admin only).
+    /// Officer notes are only read and written in Officer-mapped channels.
+    async fn handle_note(
+        &self,
+        ctx: &Context,
+        msg: &Message,
+        args: Option<String>,
+        officer_channel: bool,
+    ) -> anyhow::Result<()> {
+        info!("!note command from {} with args: {:?}", msg.author.name, args);
+
+        let args = args.unwrap_or_default();
+        let Some(command) = parse_note_command(&args, officer_channel, msg.channel_id.get()) else {
+            msg.channel_id
+                .say(
+                    &ctx.http,
+                    "Usage: `!note <player>`, `!note set [public|officer] <player> [text]` (officer notes only in officer channels)",
+                )
+                .await?;
+            return Ok(());
+        };
+
+        if matches!(command, WowCommand::SetGuildNote { .. }) && !self.is_admin(ctx, msg) {
+            warn!("!note set from {} denied: missing admin role", msg.author.name);
+            msg.react(&ctx.http, '⛔').await.ok();
+            return Ok(());
+        }
+
+        self.command_tx.send(command)?;
+
+        msg.react(&ctx.http, '📝').await.ok();
+
+        Ok(())
+    }
+
     /// Handle !help command.
     async fn handle_help(&self, ctx: &Context, msg: &Message) -> anyhow::Result<()> {
         let help_text = r#"**Available Commands:**
 • `!who` - List online guild members
 • `!who <name>` - Search for a player
 • `!gmotd` - Show guild Message of the Day
+• `!gmotd set <text>` - Change the guild Message of the Day (admin)
+• `!ginfo` - Show the guild information text
+• `!ginfo set <text>` - Change the guild information text (admin)
+• `!nearby` - Show players standing around the bot
+• `!status` - Show the connection and how many messages were relayed late or dropped
+• `!members <channel>` - List members of a WoW channel
+• `!channel join <name> [password]` - Join a WoW channel (admin)
+• `!channel leave <name>` - Leave a WoW channel (admin)
+• `!channel kick|ban|unban|mute|unmute|owner <name> <player>` - Moderate a WoW channel (admin)
+• `!channel password <name> [password]` - Set or clear a WoW channel password (admin)
+• `!channel moderate|announcements <name>` - Toggle WoW channel modes (admin)
+• `!group leave` - Leave the current party or raid (admin)
+• `!group disband` - Remove everyone from the bot's party or raid (admin)
+• `!guild invite|kick|promote|demote <player>` - Manage guild members (admin)
+• `!guildlog [n]` - Show the last n guild event log entries (default 10)
+• `!inactive [days] [rank]` - List members offline for at least n days (default 30)
+• `!activity <player>` - Show a guild member's weekly sessions, play time and usual hours
+• `!top playtime` - Show who played the most in the last 7 days
+• `!export roster [csv|json]` - Upload the guild roster as a file (officer channels only)
+• `!note <player>` - Show a guild member's note (officer note too in officer channels)
+• `!note set [public|officer] <player> [text]` - Set or clear a guild member's note; officer channels default to the officer note (admin)
 • `!help` - Show this help message"#;
 
         msg.channel_id.say(&ctx.http, help_text).await?;
         Ok(())
     }
 }
+
+/// Parse `!channel` arguments into a command.
+fn parse_channel_command(args: &str, reply_channel: u64) -> Option<WowCommand> {
+    let mut parts = args.split_whitespace();
+    let subcommand = parts.next()?.to_lowercase();
+    let name = parts.next()?.to_string();
+    let argument = parts.next().map(|s| s.to_string());
+
+    let action = match (subcommand.as_str(), argument) {
+        ("join", password) => {
+            return Some(WowCommand::ChannelJoin {
+                name,
+                password,
+                reply_channel,
+            })
+        }
+        ("leave", None) => {
+            return Some(WowCommand::ChannelLeave {
+                name,
+                reply_channel,
+            })
+        }
+        ("kick", Some(player)) => ChannelModeration::Kick(player),
+        ("ban", Some(player)) => ChannelModeration::Ban(player),
+        ("unban", Some(player)) => ChannelModeration::Unban(player),
+        ("mute", Some(player)) => ChannelModeration::Mute(player),
+        ("unmute", Some(player)) => ChannelModeration::Unmute(player),
+        ("owner", Some(player)) => ChannelModeration::SetOwner(player),
+        ("password", password) => ChannelModeration::Password(password.unwrap_or_default()),
+        ("moderate", None) => ChannelModeration::Moderate,
+        ("announcements", None) => ChannelModeration::Announcements,
+        _ => return None,
+    };
+
+    Some(WowCommand::ChannelModerate {
+        name,
+        action,
+        reply_channel,
+    })
+}
+
+/// Split the text off a `set <text>` subcommand.
+/// Returns None when the arguments are not a `set` subcommand.
+fn parse_set_text(args: &str) -> Option<&str> {
+    let (subcommand, text) = args.split_once(' ').unwrap_or((args, ""));
+    subcommand
+        .eq_ignore_ascii_case("set")
+        .then_some(text.trim())
+}
+
+/// Parse the `!guildlog` entry count (default 10, the server keeps at most 100).
+fn parse_guildlog_count(args: &str) -> Option<usize> {
+    if args.is_empty() {
+        return Some(10);
+    }
+    args.parse().ok().filter(|n| (1..=100).contains(n))
+}
+
+/// Parse `!inactive` arguments: an optional day count followed by an optional rank name.
+fn parse_inactive_args(args: &str) -> Option<(u32, Option<String>)> {
+    let (first, rest) = args.split_once(' ').unwrap_or((args, ""));
+    let (days, rank) = match first.parse::<u32>() {
+        Ok(days) => (days, rest.trim()),
+        Err(_) if first.starts_with(|c: char| c.is_ascii_digit() || c == '-') => return None,
+        Err(_) => (DEFAULT_INACTIVE_DAYS, args),
+    };
+    if days == 0 {
+        return None;
+    }
+    let rank = (!rank.is_empty()).then(|| rank.to_string());
+    Some((days, rank))
+}
+
+/// Parse `!export` arguments into a roster file format (CSV by default).
+fn parse_export_args(args: &str) -> Option<ExportFormat> {
+    let mut parts = args.split_whitespace();
+    if !parts.next()?.eq_ignore_ascii_case("roster") {
+        return None;
+    }
+    let format = match parts.next().map(|s| s.to_lowercase()).as_deref() {
+        None | Some("csv") => ExportFormat::Csv,
+        Some("json") => ExportFormat::Json,
+        Some(_) => return None,
+    };
+    parts.next().is_none().then_some(format)
+}
+
+/// Parse `!note` arguments into a command.
+fn parse_note_command(args: &str, officer: bool, reply_channel: u64) -> Option<WowCommand> {
+    let (first, rest) = args.split_once(' ').unwrap_or((args, ""));
+    if first.is_empty() {
+        return None;
+    }
+
+    if first.eq_ignore_ascii_case("set") {
+        let mut rest = rest.trim();
+        let mut officer = officer;
+        // An explicit public/officer picks the note; officer notes stay in officer channels
+        if let Some((scope, after)) = rest.split_once(' ') {
+            if scope.eq_ignore_ascii_case("public") {
+                officer = false;
+                rest = after.trim();
+            } else if scope.eq_ignore_ascii_case("officer") {
+                if !officer {
+                    return None;
+                }
+                rest = after.trim();
+            }
+        }
+        let (name, note) = rest.split_once(' ').unwrap_or((rest, ""));
+        if name.is_empty() {
+            return None;
+        }
+        return Some(WowCommand::SetGuildNote {
+            name: name.to_string(),
+            note: note.trim().to_string(),
+            officer,
+            reply_channel,
+        });
+    }
+
+    if !rest.trim().is_empty() {
+        return None;
+    }
+    Some(WowCommand::GuildNote {
+        name: first.to_string(),
+        officer,
+        reply_channel,
+    })
+}
+
+/// Parse `!guild` arguments into a command.
+fn parse_guild_command(args: &str, reply_channel: u64) -> Option<WowCommand> {
+    let mut parts = args.split_whitespace();
+    let action = match parts.next()?.to_lowercase().as_str() {
+        "invite" => GuildMemberAction::Invite,
+        "kick" | "remove" => GuildMemberAction::Remove,
+        "promote" => GuildMemberAction::Promote,
+        "demote" => GuildMemberAction::Demote,
+        _ => return None,
+    };
+    let name = parts.next()?.to_string();
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(WowCommand::GuildMember {
+        action,
+        name,
+        reply_channel,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_channel_command() {
+        assert!(matches!(
+            parse_channel_command("join RaidTeam secret", 1),
+            Some(WowCommand::ChannelJoin { name, password: Some(p), .. })
+                if name == "RaidTeam" && p == "secret"
+        ));
+        assert!(matches!(
+            parse_channel_command("KICK RaidTeam Thrall", 1),
+            Some(WowCommand::ChannelModerate { action: ChannelModeration::Kick(p), .. })
+                if p == "Thrall"
+        ));
+        assert!(matches!(
+            parse_channel_command("password RaidTeam", 1),
+            Some(WowCommand::ChannelModerate { action: ChannelModeration::Password(p), .. })
+                if p.is_empty()
+        ));
+        assert!(matches!(
+            parse_channel_command("moderate RaidTeam", 1),
+            Some(WowCommand::ChannelModerate { action: ChannelModeration::Moderate, .. })
+        ));
+        assert!(parse_channel_command("kick RaidTeam", 1).is_none());
+        assert!(parse_channel_command("bogus RaidTeam", 1).is_none());
+        assert!(parse_channel_command("", 1).is_none());
+    }
+
+    #[test]
+    fn test_parse_guild_command() {
+        assert!(matches!(
+            parse_guild_command("Kick Thrall", 1),
+            Some(WowCommand::GuildMember { action: GuildMemberAction::Remove, name, .. })
+                if name == "Thrall"
+        ));
+        assert!(matches!(
+            parse_guild_command("promote Jaina", 1),
+            Some(WowCommand::GuildMember { action: GuildMemberAction::Promote, .. })
+        ));
+        assert!(parse_guild_command("invite", 1).is_none());
+        assert!(parse_guild_command("invite Two Names", 1).is_none());
+        assert!(parse_guild_command("disband Thrall", 1).is_none());
+    }
+
+    #[test]
+    fn test_parse_set_text() {
+        assert_eq!(parse_set_text(""), None);
+        assert_eq!(parse_set_text("settings"), None);
+        assert_eq!(parse_set_text("set"), Some(""));
+        assert_eq!(parse_set_text("SET  Raid at 8 tonight "), Some("Raid at 8 tonight"));
+    }
+
+    #[test]
+    fn test_parse_note_command() {
+        assert!(matches!(
+            parse_note_command("Thrall", false, 1),
+            Some(WowCommand::GuildNote { name, officer: false, .. }) if name == "Thrall"
+        ));
+        assert!(matches!(
+            parse_note_command("set Thrall Alt of Jaina", true, 1),
+            Some(WowCommand::SetGuildNote { name, note, officer: true, .. })
+                if name == "Thrall" && note == "Alt of Jaina"
+        ));
+        assert!(matches!(
+            parse_note_command("set Thrall", false, 1),
+            Some(WowCommand::SetGuildNote { note, .. }) if note.is_empty()
+        ));
+        assert!(parse_note_command("", false, 1).is_none());
+        assert!(parse_note_command("set", false, 1).is_none());
+        assert!(parse_note_command("Thrall Jaina", false, 1).is_none());
+
+        // Officers can pick the public note explicitly
+        assert!(matches!(
+            parse_note_command("set public Thrall Raid lead", true, 1),
+            Some(WowCommand::SetGuildNote { name, note, officer: false, .. })
+                if name == "Thrall" && note == "Raid lead"
+        ));
+        assert!(matches!(
+            parse_note_command("set officer Thrall", true, 1),
+            Some(WowCommand::SetGuildNote { name, officer: true, .. }) if name == "Thrall"
+        ));
+        assert!(parse_note_command("set officer Thrall Alt", false, 1).is_none());
+    }
+
+    #[test]
+    fn test_parse_guildlog_count() {
+        assert_eq!(parse_guildlog_count(""), Some(10));
+        assert_eq!(parse_guildlog_count("25"), Some(25));
+        assert_eq!(parse_guildlog_count("0"), None);
+        assert_eq!(parse_guildlog_count("101"), None);
+        assert_eq!(parse_guildlog_count("all"), None);
+    }
+
+    #[test]
+    fn test_parse_inactive_args() {
+        assert_eq!(parse_inactive_args(""), Some((30, None)));
+        assert_eq!(parse_inactive_args("14"), Some((14, None)));
+        assert_eq!(
+            parse_inactive_args("60 Raider Alt"),
+            Some((60, Some("Raider Alt".to_string())))
+        );
+        assert_eq!(
+            parse_inactive_args("Officer"),
+            Some((30, Some("Officer".to_string())))
+        );
+        assert_eq!(parse_inactive_args("0"), None);
+        assert_eq!(parse_inactive_args("-5"), None);
+        assert_eq!(parse_inactive_args("14d"), None);
+    }
+
+    #[test]
+    fn test_parse_export_args() {
+        assert_eq!(parse_export_args("roster"), Some(ExportFormat::Csv));
+        assert_eq!(parse_export_args("Roster CSV"), Some(ExportFormat::Csv));
+        assert_eq!(parse_export_args("roster json"), Some(ExportFormat::Json));
+        assert_eq!(parse_export_args(""), None);
+        assert_eq!(parse_export_args("roster xml"), None);
+        assert_eq!(parse_export_args("roster json now"), None);
+        assert_eq!(parse_export_args("bank"), None);
+    }
+}
@@ -1,14 +1,75 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::time::{SystemTime, UNIX_EPOCH};
-use serenity::all::{ChannelId, Context, CreateEmbed, CreateMessage, EditMessage, Message, MessageId};
-use tracing::{error, info};
+use serenity::all::{ChannelId, Context, CreateEmbed, CreateMessage, EditMessage, GetMessages, Message, MessageId, UserId};
+use tracing::{error, info, warn};
 use crate::common::messages::GuildDashboardData;
 use crate::config::types::GuildDashboardConfig;
-use crate::common::resources::get_zone_name;
+use crate::common::resources::{get_zone_name, Class};
+use crate::common::types::GuildMember;
+
+/// Discord's limit on embed description length.
+const EMBED_DESCRIPTION_LIMIT: usize = 4096;
+
+/// Width of the note column; WoW guild notes are at most 31 characters.
+const NOTE_WIDTH: usize = 31;
+
+/// Messages fetched per history request when looking for an existing dashboard.
+const HISTORY_PAGE_SIZE: u8 = 100;
+
+/// History requests made before giving up on finding an existing dashboard.
+const MAX_HISTORY_PAGES: usize = 5;
 
 pub struct DashboardRenderer {
     config: GuildDashboardConfig,
     message_ids: Vec<MessageId>,
     last_data: Option<GuildDashboardData>,
+    /// Last view sent, to skip edits that would not change the dashboard.
+    last_view: Option<DashboardView>,
+}
+
+/// What a dashboard shows, computed from the guild roster.
+#[derive(Debug, Clone, PartialEq)]
+struct DashboardView {
+    title: String,
+    online: bool,
+    online_count: usize,
+    member_count: usize,
+    rows: Vec<String>,
+}
+
+/// Fetch recent channel history, newest first, paging back until the first page
+/// of the dashboard titled `title` is found or the page limit is reached.
+async fn fetch_history(ctx: &Context, channel_id: ChannelId, self_id: UserId, title: &str) -> Vec<Message> {
+    let mut messages = Vec::new();
+    let mut request = GetMessages::new().limit(HISTORY_PAGE_SIZE);
+    for _ in 0..MAX_HISTORY_PAGES {
+        let page = match channel_id.messages(&ctx.http, request).await {
+            Ok(page) => page,
+            Err(e) => {
+                warn!("Failed to read dashboard channel history: {}", e);
+                break;
+            }
+        };
+        let done = page.len() < HISTORY_PAGE_SIZE as usize
+            || page.iter().any(|m| is_first_page(m, self_id, title));
+        let Some(oldest) = page.last().map(|m| m.id) else {
+            break;
+        };
+        messages.extend(page);
+        if done {
+            break;
+        }
+        request = GetMessages::new().before(oldest).limit(HISTORY_PAGE_SIZE);
+    }
+    messages
+}
+
+/// Whether a message is the first (titled) page of our dashboard.
+fn is_first_page(m: &Message, self_id: UserId, title: &str) -> bool {
+    m.author.id == self_id
+        && m.embeds.len() == 1
+        && m.embeds[0].title.as_ref().is_some_and(|t| t.to_lowercase() == title)
 }
 
 impl DashboardRenderer {
@@ -17,6 +78,7 @@ impl DashboardRenderer {
             config,
             message_ids: Vec::new(),
             last_data: None,
+            last_view: None,
         }
     }
 
@@ -34,47 +96,41 @@ impl DashboardRenderer {
         };
 
         // Deduplicate updates
-        if let Some(last) = &self.last_data {
-            if last == &data {
-                return;
-            }
+        let view = self.build_view(&data);
+        if self.last_view.as_ref() == Some(&view) {
+            return;
         }
 
-        info!("Updating guild dashboard for {} members", data.members.len());
+        info!(dashboard = %self.config.id, "Updating guild dashboard for {} members", view.member_count);
 
         // Format the dashboard
-        let mut embeds = self.format_dashboard(&data);
+        let mut embeds = self.format_dashboard(&view);
 
         // If we don't have message IDs, try to find them in history
         if self.message_ids.is_empty() {
-            let title = format!("{} — {}", data.guild_name, data.realm).to_lowercase();
-            if let Ok(messages) = channel_id.messages(&ctx.http, serenity::all::GetMessages::new().limit(10)).await {
-                let self_id = ctx.cache.current_user().id;
-
-                let found_messages: Vec<Message> = messages.into_iter()
-                    .rev() // Oldest first (like Scala .reverse)
-                    .skip_while(|m| {
-                        // Skip until we find the first dashboard message (first page with title)
-                        m.author.id != self_id
-                        || m.embeds.len() != 1
-                        || m.embeds[0].title.as_ref().map_or(true, |t| t.to_lowercase() != title)
-                    })
-                    .take_while(|m| {
-                        // Take all contiguous messages authored by us with embeds
-                        // First page has the title, subsequent pages have null title but are contiguous
-                        m.author.id == self_id
-                        && m.embeds.len() == 1
-                        && m.embeds[0].title.as_ref().map_or(true, |t| t.is_empty() || t.to_lowercase() == title)
-                        && m.embeds[0].description.as_ref().map_or(false, |d| !d.is_empty())
-                    })
-                    .collect();
-
-                //found_messages.sort_by_key(|m| m.timestamp);
-                self.message_ids = found_messages.iter().map(|m| m.id).collect();
-
-                if !self.message_ids.is_empty() {
-                    info!("Found {} existing dashboard messages", self.message_ids.len());
-                }
+            let title = view.title.to_lowercase();
+            let self_id = ctx.cache.current_user().id;
+            let messages = fetch_history(ctx, channel_id, self_id, &title).await;
+
+            let found_messages: Vec<Message> = messages.into_iter()
+                .rev() // Oldest first (like Scala .reverse)
+                // Skip until we find the first dashboard message (first page with title)
+                .skip_while(|m| !is_first_page(m, self_id, &title))
+                .take_while(|m| {
+                    // Take all contiguous messages authored by us with embeds
+                    // First page has the title, subsequent pages have null title but are contiguous
+                    m.author.id == self_id
+                    && m.embeds.len() == 1
+                    && m.embeds[0].title.as_ref().map_or(true, |t| t.is_empty() || t.to_lowercase() == title)
+                    && m.embeds[0].description.as_ref().map_or(false, |d| !d.is_empty())
+                })
+                .collect();
+
+            //found_messages.sort_by_key(|m| m.timestamp);
+            self.message_ids = found_messages.iter().map(|m| m.id).collect();
+
+            if !self.message_ids.is_empty() {
+                info!("Found {} existing dashboard messages", self.message_ids.len());
             }
         }
 
@@ -122,7 +178,8 @@ impl DashboardRenderer {
 
         // Only update last_data if we successfully performed the update (or at least tried to send new ones)
         if !self.message_ids.is_empty() {
-            self.last_data = Some(data.clone());
+            self.last_data = Some(data);
+            self.last_view = Some(view);
         }
     }
 
@@ -135,41 +192,70 @@ impl DashboardRenderer {
         }
     }
 
-    fn format_dashboard(&self, data: &GuildDashboardData) -> Vec<CreateEmbed> {
-        let title = format!("{} — {}", data.guild_name, data.realm);
-        let group_size = 13;
-        let block_size = 5;
+    /// Pick and lay out the members this dashboard shows.
+    fn build_view(&self, data: &GuildDashboardData) -> DashboardView {
+        let title = format!("{} — {}", data.guild_name, self.config.title.as_deref().unwrap_or(&data.realm));
+        let members: Vec<GuildMember> = if self.config.is_roster_mode() {
+            data.members.clone()
+        } else {
+            data.members.iter().filter(|m| m.online).cloned().collect()
+        };
 
-        // Prepare data rows: (Name, Level, Area)
+        // Prepare data lines: Name, Level, Area
         // This unifies the logic for empty and non-empty states, ensuring the empty state
         // uses the exact same padding/height/coloring logic as regular members.
-        let all_rows: Vec<(String, String, String)> = if data.members.is_empty() {
-            vec![("—".to_string(), "".to_string(), "".to_string())]
+        let rows: Vec<String> = if members.is_empty() {
+            vec![format_row(&color_pad("—", 13), "", "")]
+        } else if self.is_detailed() {
+            self.detailed_rows(&members)
         } else {
-            data.members.iter().map(|m| (
-                m.name.clone(),
-                m.level.to_string(),
-                get_zone_name(m.zone_id).to_string()
+            members.iter().map(|m| format_row(
+                // Name: Truncate to 12, Pad to 13 (using color_pad logic)
+                // Note: The original logic truncates BEFORE padding.
+                &color_pad(&truncate(&m.name, 12), 13),
+                &m.level.to_string(),
+                get_zone_name(m.zone_id)
             )).collect()
         };
 
-        // Generate blocks
-        // Group into chunks of 13
-        let blocks: Vec<String> = all_rows.chunks(group_size).map(|chunk| {
-            let lines: Vec<String> = chunk.iter().map(|(name, level, area)| {
-                // Name: Truncate to 12, Pad to 13 (using color_pad logic)
-                // Level: Truncate to 3, Pad to 3
-                // Area: Truncate to 24, Pad to 24
+        DashboardView {
+            title,
+            online: data.online,
+            online_count: members.iter().filter(|m| m.online).count(),
+            member_count: members.len(),
+            rows,
+        }
+    }
 
-                // Note: The original logic truncates BEFORE padding.
-                let name_fmt = color_pad(&truncate(name, 12), 13);
-                let level_fmt = pad1(&truncate(level, 3), 3);
-                let area_fmt = pad1(&truncate(area, 24), 24);
+    /// Check if the dashboard needs more than the plain online list.
+    fn is_detailed(&self) -> bool {
+        self.config.is_roster_mode()
+            || self.config.group_by() != "none"
+            || !self.config.note.eq_ignore_ascii_case("none")
+    }
 
-                format!("{}{}{}", name_fmt, level_fmt, area_fmt)
-            }).collect();
+    fn format_dashboard(&self, view: &DashboardView) -> Vec<CreateEmbed> {
+        self.format_pages(view)
+            .into_iter()
+            .enumerate()
+            .map(|(i, description)| {
+                let embed = CreateEmbed::new().description(description);
+                if i == 0 { embed.title(&view.title) } else { embed }
+            })
+            .collect()
+    }
+
+    /// Embed descriptions, one per dashboard message.
+    fn format_pages(&self, view: &DashboardView) -> Vec<String> {
+        let group_size = 13;
+        let block_size = 5;
+        let roster_mode = self.config.is_roster_mode();
+        let all_rows = &view.rows;
 
-            let mut content = lines.join("\n");
+        // Generate blocks
+        // Group into chunks of 13
+        let blocks: Vec<String> = all_rows.chunks(group_size).map(|chunk| {
+            let mut content = chunk.join("\n");
 
             // Pad with empty lines if chunk is smaller than group_size
             // Scala: .padTo(group, "\u3164")
@@ -184,47 +270,193 @@ impl DashboardRenderer {
             format!("```ansi\n{}\n```", content)
         }).collect();
 
-        let mut embeds = Vec::new();
+        // First page header: status line and column headers
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
 
-        // Split blocks into embeds (5 blocks per embed)
-        for (i, chunk) in blocks.chunks(block_size).enumerate() {
-            let mut embed = CreateEmbed::new();
-            let desc_content = chunk.join("");
+        let status_icon = if view.online { ":green_circle:" } else { ":red_circle:" };
+        let status_text = if view.online { "online" } else { "were online" };
 
-            if i == 0 {
-                embed = embed.title(&title);
-                let timestamp = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_secs();
+        let mut description = format!("{} {} {}", status_icon, view.online_count, status_text);
+        if roster_mode {
+            description = format!("{} of {}", description, view.member_count);
+        }
+        // Pad description to 28 chars using pad2 (Hangul Filler)
+        description = format!("{}<t:{}:R>", pad2(&description, 28), timestamp);
+
+        // Header line: pad2 separators match Scala exactly
+        // Scala: pad2("", 3) + "**Name**" + pad2("", 3) + "**Level**" + pad2("", 3) + "**Area**"
+        let area_header = if roster_mode { "**Area / Last seen**" } else { "**Area**" };
+        let mut header_line = format!("{}**Name**{}**Level**{}{}",
+            pad2("", 3), pad2("", 3), pad2("", 3), area_header
+        );
+        match self.config.note.to_lowercase().as_str() {
+            "public" => header_line.push_str(&format!("{}**Note**", pad2("", 3))),
+            "officer" => header_line.push_str(&format!("{}**Officer note**", pad2("", 3))),
+            _ => {}
+        }
 
-                let status_icon = if data.online { ":green_circle:" } else { ":red_circle:" };
-                let status_text = if data.online { "online" } else { "were online" };
-                let count = data.members.len();
+        // Split blocks into pages of up to 5 blocks, fewer when wide rows
+        // (e.g. a note column) would go past Discord's description limit
+        let mut pages = Vec::new();
+        let mut page = format!("{}\n\n{}\n", description, header_line);
+        let mut page_blocks = 0;
+        for block in blocks {
+            let too_long = page.chars().count() + block.chars().count() > EMBED_DESCRIPTION_LIMIT;
+            if page_blocks == block_size || (page_blocks > 0 && too_long) {
+                pages.push(std::mem::take(&mut page));
+                page_blocks = 0;
+            }
+            page.push_str(&block);
+            page_blocks += 1;
+        }
+        pages.push(page);
+        pages
+    }
 
-                let mut description = format!("{} {} {}", status_icon, count, status_text);
-                // Pad description to 28 chars using pad2 (Hangul Filler)
-                description = format!("{}<t:{}:R>", pad2(&description, 28), timestamp);
+    /// Detailed lines: members sorted and grouped, with a header line per group.
+    /// Offline members show when they were last seen instead of their area.
+    fn detailed_rows(&self, members: &[GuildMember]) -> Vec<String> {
+        let columns = self.config.sort_columns();
+        let mut members: Vec<&GuildMember> = members.iter().collect();
+        members.sort_by(|a, b| compare_members(a, b, &columns));
+
+        // Group key: (order, label); class and zone groups are alphabetical,
+        // with unknown classes and offline members last
+        let group_by = self.config.group_by();
+        let mut groups: BTreeMap<(u8, String), Vec<&GuildMember>> = BTreeMap::new();
+        for member in members {
+            let key = match group_by.as_str() {
+                "rank" => (member.rank, member.rank_name.clone()),
+                "class" => match member.class {
+                    Some(class) => (0, class.name().to_string()),
+                    None => (1, "Unknown".to_string()),
+                },
+                "zone" if member.online => (0, get_zone_name(member.zone_id).to_string()),
+                "zone" => (1, "Offline".to_string()),
+                _ => (0, String::new()),
+            };
+            groups.entry(key).or_default().push(member);
+        }
 
-                // Header line: pad2 separators match Scala exactly
-                // Scala: pad2("", 3) + "**Name**" + pad2("", 3) + "**Level**" + pad2("", 3) + "**Area**"
-                let header_line = format!("{}**Name**{}**Level**{}**Area**",
-                    pad2("", 3), pad2("", 3), pad2("", 3)
+        let mut rows = Vec::new();
+        for ((_, label), members) in groups {
+            if !label.is_empty() {
+                rows.push(format!("\u{001b}[1;37m{} ({})\u{001b}[0m", truncate(&label, 30), members.len()));
+            }
+            for m in members {
+                let area = if m.online {
+                    get_zone_name(m.zone_id).to_string()
+                } else {
+                    format_last_seen(m.last_logoff)
+                };
+                let mut row = format_row(
+                    &class_color_pad(&truncate(&m.name, 12), 13, m.class),
+                    &m.level.to_string(),
+                    &area,
                 );
-
-                embed = embed.description(format!("{}\n\n{}\n{}", description, header_line, desc_content));
-            } else {
-                embed = embed.description(desc_content);
+                let note = match self.config.note.to_lowercase().as_str() {
+                    "public" => Some(&m.note),
+                    "officer" => Some(&m.officer_note),
+                    _ => None,
+                };
+                if let Some(note) = note {
+                    row.push_str(&format_note(note));
+                }
+                rows.push(row);
             }
+        }
+        rows
+    }
+}
 
-            embeds.push(embed);
+// Helpers
+
+/// Format a dashboard line from a padded name, a level and an area.
+fn format_row(name_fmt: &str, level: &str, area: &str) -> String {
+    // Level: Truncate to 3, Pad to 3
+    // Area: Truncate to 24, Pad to 24
+    let level_fmt = pad1(&truncate(level, 3), 3);
+    let area_fmt = pad1(&truncate(area, 24), 24);
+    format!("{}{}{}", name_fmt, level_fmt, area_fmt)
+}
+
+/// Format a note column: a separator, then the note truncated and padded to
+/// a fixed width, with backticks replaced so they cannot close the code block.
+fn format_note(note: &str) -> String {
+    let note = note.replace('`', "'");
+    format!("{}{}", pad1("", 1), pad1(&truncate(&note, NOTE_WIDTH), NOTE_WIDTH))
+}
+
+/// Compare two members by the configured (column, descending) sort columns.
+fn compare_members(a: &GuildMember, b: &GuildMember, columns: &[(String, bool)]) -> Ordering {
+    for (column, descending) in columns {
+        let ordering = match column.as_str() {
+            "name" => a.name.cmp(&b.name),
+            "level" => a.level.cmp(&b.level),
+            "rank" => a.rank.cmp(&b.rank),
+            "class" => a.class.map(|c| c.name()).cmp(&b.class.map(|c| c.name())),
+            "zone" => get_zone_name(a.zone_id).cmp(get_zone_name(b.zone_id)),
+            // Online members first, then most recently seen
+            "last_seen" => (!a.online).cmp(&!b.online)
+                .then(a.last_logoff.total_cmp(&b.last_logoff)),
+            _ => Ordering::Equal,
+        };
+        let ordering = if *descending { ordering.reverse() } else { ordering };
+        if ordering != Ordering::Equal {
+            return ordering;
         }
+    }
+    Ordering::Equal
+}
 
-        embeds
+/// Format time since logoff (in days, from the roster) as "seen 3d ago".
+fn format_last_seen(days: f32) -> String {
+    let minutes = (days * 24.0 * 60.0).round() as u64;
+    match minutes {
+        0 => "seen just now".to_string(),
+        m if m < 60 => format!("seen {}m ago", m),
+        m if m < 24 * 60 => format!("seen {}h ago", m / 60),
+        m => format!("seen {}d ago", m / (24 * 60)),
     }
 }
 
-// Helpers
+/// Closest of Discord's ANSI colours to each class colour.
+fn class_color(class: Class) -> &'static str {
+    match class {
+        Class::Warrior => "33",
+        Class::Paladin => "35",
+        Class::Hunter => "32",
+        Class::Rogue => "1;33",
+        Class::Priest => "37",
+        Class::DeathKnight => "31",
+        Class::Shaman => "34",
+        Class::Mage => "36",
+        Class::Warlock => "1;35",
+        Class::Monk => "1;32",
+        Class::Druid => "1;31",
+    }
+}
+
+fn class_color_pad(value: &str, width: usize, class: Option<Class>) -> String {
+    match class {
+        Some(class) => ansi_pad(value, width, class_color(class)),
+        None => pad1(value, width),
+    }
+}
+
+fn ansi_pad(value: &str, width: usize, color: &str) -> String {
+    let value_len = value.chars().count();
+    let padding = if width > value_len {
+        "\u{00a0}".repeat(width - value_len)
+    } else {
+        String::new()
+    };
+
+    format!("\u{001b}[{}m{}\u{001b}[0m{}", color, value, padding)
+}
 
 fn pad1(value: &str, width: usize) -> String {
     let len = value.chars().count();
@@ -275,14 +507,169 @@ fn color_pad(value: &str, width: usize) -> String {
     // We want character count to match logic better, though Scala logic is actually code units
     let len = value.chars().count();
     let idx = (first + last + len) % colors.len();
-    let color = colors[idx];
+    ansi_pad(value, width, colors[idx])
+}
 
-    let value_len = value.chars().count();
-    let padding = if width > value_len {
-        "\u{00a0}".repeat(width - value_len)
-    } else {
-        String::new()
-    };
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, level: u8, rank: u8, class: Option<Class>, last_logoff: Option<f32>) -> GuildMember {
+        GuildMember {
+            guid: 0,
+            name: name.to_string(),
+            level,
+            class,
+            rank,
+            rank_name: format!("Rank {}", rank),
+            zone_id: 0,
+            online: last_logoff.is_none(),
+            last_logoff: last_logoff.unwrap_or(0.0),
+            note: String::new(),
+            officer_note: String::new(),
+        }
+    }
 
-    format!("\u{001b}[{}m{}\u{001b}[0m{}", color, value, padding)
+    fn roster_renderer(group_by: &str, sort: Option<Vec<&str>>) -> DashboardRenderer {
+        DashboardRenderer::new(GuildDashboardConfig {
+            id: "roster".to_string(),
+            enabled: true,
+            channel: "roster".to_string(),
+            mode: "roster".to_string(),
+            group_by: Some(group_by.to_string()),
+            sort: sort.map(|s| s.into_iter().map(String::from).collect()),
+            ..GuildDashboardConfig::default()
+        })
+    }
+
+    /// Leading text of each row with ANSI codes removed.
+    fn names(rows: &[String]) -> Vec<String> {
+        rows.iter()
+            .map(|r| {
+                let mut text = String::new();
+                let mut chars = r.chars();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\u{001b}' => { chars.by_ref().find(|&c| c == 'm'); }
+                        '\u{00a0}' => break,
+                        c => text.push(c),
+                    }
+                }
+                text
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_roster_rows_grouped_by_rank() {
+        let members = vec![
+            member("Thrall", 80, 1, Some(Class::Shaman), Some(2.0)),
+            member("Jaina", 80, 1, Some(Class::Mage), None),
+            member("Varian", 80, 0, Some(Class::Warrior), None),
+        ];
+        let rows = roster_renderer("rank", None).detailed_rows(&members);
+
+        // Online members first within each rank, offline ones show when they were last seen
+        assert_eq!(names(&rows), vec!["Rank 0 (1)", "Varian", "Rank 1 (2)", "Jaina", "Thrall"]);
+        assert!(rows[1].starts_with("\u{001b}[33mVarian"));
+        assert!(rows[4].contains("seen 2d ago"));
+    }
+
+    #[test]
+    fn test_roster_rows_sorted_by_level() {
+        let members = vec![
+            member("Thrall", 70, 1, None, None),
+            member("Jaina", 80, 1, Some(Class::Mage), Some(0.01)),
+            member("Varian", 75, 0, Some(Class::Warrior), None),
+        ];
+        let rows = roster_renderer("none", Some(vec!["-level"])).detailed_rows(&members);
+        assert_eq!(names(&rows), vec!["Jaina", "Varian", "Thrall"]);
+
+        let rows = roster_renderer("class", None).detailed_rows(&members);
+        assert_eq!(names(&rows), vec!["Mage (1)", "Jaina", "Warrior (1)", "Varian", "Unknown (1)", "Thrall"]);
+    }
+
+    #[test]
+    fn test_dashboard_views() {
+        let mut thrall = member("Thrall", 80, 1, Some(Class::Shaman), None);
+        thrall.zone_id = 1637;
+        thrall.officer_note = "Raid lead".to_string();
+        let data = GuildDashboardData {
+            guild_name: "Horde".to_string(),
+            realm: "Test".to_string(),
+            members: vec![thrall, member("Jaina", 80, 1, Some(Class::Mage), Some(3.0))],
+            online: true,
+        };
+
+        // The online board only shows online members
+        let online = DashboardRenderer::new(GuildDashboardConfig {
+            id: "online".to_string(),
+            enabled: true,
+            ..GuildDashboardConfig::default()
+        });
+        let view = online.build_view(&data);
+        assert_eq!(view.title, "Horde — Test");
+        assert_eq!((view.online_count, view.member_count), (1, 1));
+
+        // Offline members changing does not change the online board
+        let mut later = data.clone();
+        later.members[1].last_logoff = 3.5;
+        assert_eq!(online.build_view(&later), view);
+
+        let officers = DashboardRenderer::new(GuildDashboardConfig {
+            id: "officers".to_string(),
+            enabled: true,
+            mode: "roster".to_string(),
+            group_by: Some("zone".to_string()),
+            note: "officer".to_string(),
+            title: Some("Officers".to_string()),
+            ..GuildDashboardConfig::default()
+        });
+        let view = officers.build_view(&data);
+        assert_eq!(view.title, "Horde — Officers");
+        assert_eq!((view.online_count, view.member_count), (1, 2));
+        assert_eq!(names(&view.rows), vec!["Orgrimmar (1)", "Thrall", "Offline (1)", "Jaina"]);
+        assert!(view.rows[1].ends_with(&format_note("Raid lead")));
+    }
+
+    #[test]
+    fn test_dashboard_pages_fit_with_notes() {
+        let mut members = Vec::new();
+        for i in 0..130 {
+            let mut m = member(&format!("Longname{:04}", i), 80, 1, Some(Class::DeathKnight), Some(12.0));
+            m.officer_note = format!("{:`<31}", i);
+            members.push(m);
+        }
+        let data = GuildDashboardData {
+            guild_name: "Horde".to_string(),
+            realm: "Test".to_string(),
+            members,
+            online: true,
+        };
+        let officers = DashboardRenderer::new(GuildDashboardConfig {
+            id: "officers".to_string(),
+            enabled: true,
+            mode: "roster".to_string(),
+            note: "officer".to_string(),
+            ..GuildDashboardConfig::default()
+        });
+        let view = officers.build_view(&data);
+        assert!(view.rows.iter().all(|r| !r.contains('`')));
+
+        let pages = officers.format_pages(&view);
+        assert!(pages.len() > 2);
+        for page in &pages {
+            assert!(page.chars().count() <= EMBED_DESCRIPTION_LIMIT);
+        }
+        let rows: usize = pages.iter().map(|p| p.matches("Longname").count()).sum();
+        assert_eq!(rows, 130);
+    }
+
+    #[test]
+    fn test_format_last_seen() {
+        assert_eq!(format_last_seen(0.0), "seen just now");
+        assert_eq!(format_last_seen(0.5 / 24.0), "seen 30m ago");
+        assert_eq!(format_last_seen(0.5), "seen 12h ago");
+        assert_eq!(format_last_seen(3.2), "seen 3d ago");
+    }
 }
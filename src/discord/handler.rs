@@ -3,8 +3,10 @@
 //! Provides the event handler for Discord messages and manages
 //! the message flow between Discord and WoW.
 
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+use chrono::Utc;
 use tokio::sync::{mpsc, oneshot};
 use tracing::{debug, error, info, warn};
 
@@ -12,16 +14,21 @@ use serenity::prelude::*;
 use serenity::model::channel::Message;
 use serenity::model::gateway::Ready;
 use serenity::model::guild::Guild;
+use serenity::model::id::ChannelId;
+use serenity::all::{CreateAttachment, CreateMessage};
 
 use crate::bridge::{
-    Bridge, PendingBridgeState, ResolvedBridgeState,
+    export, Bridge, DiscordTarget, PendingBridgeState, ResolvedBridgeState,
 };
-use crate::common::messages::{split_message_preserving_newlines, DashboardEvent};
-use crate::common::{ActivityStatus, BridgeMessage, DiscordMessage};
+use crate::common::messages::{
+    split_message_preserving_newlines, ChannelEvent, ChannelStatus, DashboardEvent, SocialNotice,
+};
+use crate::common::{ActivityStatus, BridgeMessage, CommandResponseData, DiscordMessage};
 use crate::config::types::GuildDashboardConfig;
 use crate::protocol::game::chat::chat_events;
 use crate::discord::commands::{CommandHandler, CommandResponse, WowCommand};
 use crate::discord::dashboard::DashboardRenderer;
+use crate::discord::report::InactivityReportState;
 
 /// Channels bundle for background tasks.
 /// These are consumed when tasks are spawned (moved into the tasks).
@@ -30,6 +37,8 @@ pub struct TaskChannels {
     pub cmd_response_rx: mpsc::UnboundedReceiver<CommandResponse>,
     pub status_rx: mpsc::UnboundedReceiver<ActivityStatus>,
     pub dashboard_rx: mpsc::UnboundedReceiver<DashboardEvent>,
+    pub channel_event_rx: mpsc::UnboundedReceiver<ChannelEvent>,
+    pub social_notice_rx: mpsc::UnboundedReceiver<SocialNotice>,
 }
 
 /// Discord event handler.
@@ -40,12 +49,22 @@ pub struct BridgeHandler {
     pending_state: Option<PendingBridgeState>,
     /// Command handler for Discord commands.
     command_handler: CommandHandler,
-    /// Dashboard renderer for updating dashboard messages.
-    dashboard_renderer: DashboardRenderer,
+    /// Dashboard renderers for updating dashboard messages, keyed by dashboard id.
+    dashboard_renderers: HashMap<String, DashboardRenderer>,
     /// Resolved state (available after guild_create, for message() handler and channel processing).
     resolved_state: Option<Arc<ResolvedBridgeState>>,
     /// Signal sent to main after guild_create() completes initialization.
     init_complete_tx: Option<oneshot::Sender<()>>,
+    /// Configured admin channel (name or ID, empty = disabled).
+    admin_channel: String,
+    /// Resolved admin channel ID (available after guild_create).
+    admin_channel_id: Option<ChannelId>,
+    /// Lowercase WoW channel names the bot is currently not in.
+    degraded_channels: HashSet<String>,
+    /// Inactivity report schedule (loaded once the state is resolved).
+    inactivity_report: Option<InactivityReportState>,
+    /// Whether the game client has sent a guild roster since it last connected.
+    guild_roster_received: bool,
 }
 
 impl BridgeHandler {
@@ -56,17 +75,27 @@ impl BridgeHandler {
         bridge: Arc<Bridge>,
         pending_state: PendingBridgeState,
         command_tx: mpsc::UnboundedSender<WowCommand>,
-        dashboard_config: GuildDashboardConfig,
+        dashboard_configs: Vec<GuildDashboardConfig>,
+        admin_roles: Option<Vec<String>>,
+        admin_channel: String,
         init_complete_tx: oneshot::Sender<()>,
     ) -> Self {
-        let dashboard_renderer = DashboardRenderer::new(dashboard_config);
+        let dashboard_renderers = dashboard_configs
+            .into_iter()
+            .map(|config| (config.id.clone(), DashboardRenderer::new(config)))
+            .collect();
         Self {
             bridge,
             pending_state: Some(pending_state),
-            command_handler: CommandHandler::new(command_tx),
-            dashboard_renderer,
+            command_handler: CommandHandler::new(command_tx, admin_roles),
+            dashboard_renderers,
             resolved_state: None,
             init_complete_tx: Some(init_complete_tx),
+            admin_channel,
+            admin_channel_id: None,
+            degraded_channels: HashSet::new(),
+            inactivity_report: None,
+            guild_roster_received: false,
         }
     }
 
@@ -87,76 +116,81 @@ impl BridgeHandler {
 
         // Process and filter message through Bridge
         let results = self.bridge.handle_wow_to_discord(
-            msg.chat_type,
-            msg.channel_name.as_deref(),
-            msg.sender.as_deref(),
+            &msg,
             &processed_content,
-            msg.format.as_deref(),
-            msg.guild_event.as_ref(),
+            &resolved.guild_event_channels,
         );
 
         // Send filtered messages to appropriate Discord channels
-        for (discord_channel_name, formatted) in results {
-            let key = (
-                msg.chat_type,
-                msg.channel_name.as_ref().map(|s| s.to_lowercase()),
-            );
-            if let Some(channel_configs) = resolved.wow_to_discord.get(&key) {
-                for config in channel_configs {
-                    if config.discord_channel_name == discord_channel_name {
-                        if let Some(channel_id) = config.discord_channel_id {
-                            // Apply post-bridge processing (emojis, tags, markdown escape)
-                            let (final_message, tag_errors) = if msg.sender.is_some() {
-                                let result = resolved.resolver.process_post_bridge(
-                                    &cache,
-                                    channel_id,
-                                    &formatted,
-                                    resolved.self_user_id,
-                                );
-                                (result.message, result.errors)
-                            } else {
-                                (formatted.clone(), Vec::new())
-                            };
+        let key = (
+            msg.chat_type,
+            msg.channel_name.as_ref().map(|s| s.to_lowercase()),
+        );
 
-                            // Send the message to Discord
-                            match channel_id.say(context.http.clone(), &final_message).await {
-                                Ok(_) => {
-                                    debug!(
-                                        "Sent to Discord #{}: {}",
-                                        discord_channel_name, final_message
-                                    );
-                                }
-                                Err(e) => {
-                                    error!(
-                                        "Failed to send to Discord channel {}: {}",
-                                        discord_channel_name, e
-                                    );
-                                }
-                            }
+        for (discord_target, formatted) in results {
+            let channel_ids: Vec<ChannelId> = match &discord_target {
+                DiscordTarget::Channel(channel_id) => vec![*channel_id],
+                DiscordTarget::Mapped(discord_channel_name) => resolved
+                    .wow_to_discord
+                    .get(&key)
+                    .map_or(&[][..], |c| c.as_slice())
+                    .iter()
+                    .filter(|c| &c.discord_channel_name == discord_channel_name)
+                    .filter_map(|c| c.discord_channel_id)
+                    .collect(),
+            };
+            let discord_channel_name = discord_target.to_string();
+
+            for channel_id in channel_ids {
+                // Apply post-bridge processing (emojis, tags, markdown escape)
+                let (final_message, tag_errors) = if msg.sender.is_some() {
+                    let result = resolved.resolver.process_post_bridge(
+                        &cache,
+                        channel_id,
+                        &formatted,
+                        resolved.self_user_id,
+                    );
+                    (result.message, result.errors)
+                } else {
+                    (formatted.clone(), Vec::new())
+                };
+
+                // Send the message to Discord
+                match channel_id.say(context.http.clone(), &final_message).await {
+                    Ok(_) => {
+                        debug!(
+                            "Sent to Discord #{}: {}",
+                            discord_channel_name, final_message
+                        );
+                    }
+                    Err(e) => {
+                        error!(
+                            "Failed to send to Discord channel {}: {}",
+                            discord_channel_name, e
+                        );
+                    }
+                }
+
+                // Handle tag resolution errors
+                if resolved.enable_tag_failed_notifications && !tag_errors.is_empty() {
+                    for error_msg in &tag_errors {
+                        // Send error to Discord channel
+                        if let Err(e) = channel_id.say(context.http.clone(), error_msg).await {
+                            warn!("Failed to send tag error to Discord: {}", e);
+                        }
 
-                            // Handle tag resolution errors
-                            if resolved.enable_tag_failed_notifications && !tag_errors.is_empty() {
-                                for error_msg in &tag_errors {
-                                    // Send error to Discord channel
-                                    if let Err(e) = channel_id.say(context.http.clone(), error_msg).await {
-                                        warn!("Failed to send tag error to Discord: {}", e);
-                                    }
-
-                                    // Send whisper back to WoW sender
-                                    if let Some(ref sender) = msg.sender {
-                                        let whisper_msg = BridgeMessage {
-                                            sender: None,
-                                            content: error_msg.clone(),
-                                            chat_type: chat_events::CHAT_MSG_WHISPER,
-                                            channel_name: Some(sender.clone()),
-                                            format: None,
-                                            guild_event: None,
-                                        };
-                                        if let Err(e) = resolved.wow_tx.send(whisper_msg) {
-                                            warn!("Failed to send tag error whisper to WoW: {}", e);
-                                        }
-                                    }
-                                }
+                        // Send whisper back to WoW sender
+                        if let Some(ref sender) = msg.sender {
+                            let whisper_msg = BridgeMessage {
+                                sender: None,
+                                content: error_msg.clone(),
+                                chat_type: chat_events::CHAT_MSG_WHISPER,
+                                channel_name: Some(sender.clone()),
+                                format: None,
+                                guild_event: None,
+                            };
+                            if let Err(e) = resolved.wow_tx.send(whisper_msg) {
+                                warn!("Failed to send tag error whisper to WoW: {}", e);
                             }
                         }
                     }
@@ -169,6 +203,9 @@ impl BridgeHandler {
     pub async fn handle_status_update(&mut self, context: &Context, status: ActivityStatus) {
         use serenity::gateway::ActivityData;
 
+        // Guild stats are sent with each guild roster
+        self.guild_roster_received = matches!(status, ActivityStatus::GuildStats { .. });
+
         match status {
             ActivityStatus::Connecting => {
                 let activity = ActivityData::custom("Connecting...");
@@ -222,11 +259,27 @@ impl BridgeHandler {
         let channel = serenity::model::id::ChannelId::new(response.channel_id);
         const MAX_MESSAGE_LENGTH: usize = 1900; // Leave some buffer under Discord's 2000 limit
 
+        // Roster exports go out as a file attachment
+        if let CommandResponseData::RosterExport(members, format) = &response.content {
+            let now = Utc::now();
+            let attachment = CreateAttachment::bytes(
+                export::export_roster(members, *format, now),
+                export::roster_filename(*format, now),
+            );
+            let builder = CreateMessage::new().content(&result.message).add_file(attachment);
+            if let Err(e) = channel.send_message(&context.http, builder).await {
+                error!("Failed to upload roster export to Discord: {}", e);
+            }
+            return;
+        }
+
         let message = &result.message;
+        let mut sent = true;
         if message.len() <= MAX_MESSAGE_LENGTH {
             // Single message - send directly
             if let Err(e) = channel.say(context.http.clone(), message).await {
                 error!("Failed to send command response to Discord: {}", e);
+                sent = false;
             }
         } else {
             // Large message - split into chunks at newline boundaries
@@ -234,6 +287,7 @@ impl BridgeHandler {
             for (i, chunk) in chunks.iter().enumerate() {
                 if let Err(e) = channel.say(context.http.clone(), chunk).await {
                     error!("Failed to send command response chunk {} to Discord: {}", i + 1, e);
+                    sent = false;
                     break;
                 }
                 // Small delay between chunks to avoid rate limits
@@ -242,6 +296,17 @@ impl BridgeHandler {
                 }
             }
         }
+
+        // A requested inactivity report only counts as posted once its list went out
+        if resolved.inactivity_report_channel_id == Some(channel) {
+            if let Some(report) = &mut self.inactivity_report {
+                match &response.content {
+                    CommandResponseData::Inactive(..) => report.finish(sent),
+                    CommandResponseData::Error(_) => report.finish(false),
+                    _ => {}
+                }
+            }
+        }
     }
 
     /// Process a dashboard event and update the dashboard message.
@@ -254,13 +319,96 @@ impl BridgeHandler {
             }
         };
 
-        match event {
-            DashboardEvent::Update(data) => {
-                self.dashboard_renderer.update(context, resolved.dashboard_channel_id, data).await;
+        for (id, renderer) in self.dashboard_renderers.iter_mut() {
+            let channel_id = resolved.dashboard_channel_ids.get(id).copied();
+            match &event {
+                DashboardEvent::Update(data) => {
+                    renderer.update(context, channel_id, data.clone()).await;
+                }
+                DashboardEvent::SetOffline => {
+                    renderer.set_offline(context, channel_id).await;
+                }
             }
-            DashboardEvent::SetOffline => {
-                self.dashboard_renderer.set_offline(context, resolved.dashboard_channel_id).await;
+        }
+    }
+
+    /// Process a custom channel event: track degraded routes and notify the admin channel.
+    pub async fn handle_channel_event(&mut self, context: &Context, event: ChannelEvent) {
+        let key = event.channel_name.to_lowercase();
+        let notify = match event.status {
+            // Only worth a notice when recovering from a degraded state
+            ChannelStatus::Joined => self.degraded_channels.remove(&key),
+            ChannelStatus::Rejoining | ChannelStatus::Degraded => {
+                self.degraded_channels.insert(key);
+                true
             }
+            ChannelStatus::Notice => true,
+        };
+
+        if !notify {
+            return;
+        }
+        let Some(channel_id) = self.admin_channel_id else {
+            debug!("No admin channel configured for: {}", event.message);
+            return;
+        };
+
+        let icon = match event.status {
+            ChannelStatus::Joined => "✅",
+            ChannelStatus::Rejoining => "🔄",
+            ChannelStatus::Degraded => "⛔",
+            ChannelStatus::Notice => "⚠️",
+        };
+        let content = format!("{} {}", icon, event.message);
+        if let Err(e) = channel_id.say(&context.http, content).await {
+            error!("Failed to send channel event to admin channel: {}", e);
+        }
+    }
+
+    /// Post an answered duel/trade/group/guild/petition request to the admin channel.
+    pub async fn handle_social_notice(&self, context: &Context, notice: SocialNotice) {
+        let Some(channel_id) = self.admin_channel_id else {
+            debug!("No admin channel configured for: {}", notice.message());
+            return;
+        };
+
+        let content = format!("📨 {}", notice.message());
+        if let Err(e) = channel_id.say(&context.http, content).await {
+            error!("Failed to send social notice to admin channel: {}", e);
+        }
+    }
+
+    /// Request the weekly inactivity report when it is due.
+    /// The report comes back as an `!inactive` response in the report channel.
+    pub fn handle_report_tick(&mut self) {
+        let Some(resolved) = &self.resolved_state else {
+            return;
+        };
+        let Some(channel_id) = resolved.inactivity_report_channel_id else {
+            return;
+        };
+        // Wait for the roster so the report is not run against an empty guild
+        if !self.guild_roster_received {
+            return;
+        }
+        let report = self
+            .inactivity_report
+            .get_or_insert_with(|| InactivityReportState::load(&resolved.inactivity_report.file));
+        let now = Utc::now();
+        let at = std::time::Instant::now();
+        if !report.is_due(&resolved.inactivity_report, now, at) {
+            return;
+        }
+        report.mark_requested(now, at);
+
+        info!("Requesting weekly inactivity report for channel {}", channel_id);
+        let command = WowCommand::Inactive {
+            days: resolved.inactivity_report.days,
+            rank: None,
+            reply_channel: channel_id.get(),
+        };
+        if let Err(e) = self.command_handler.command_tx.send(command) {
+            warn!("Failed to request inactivity report: {}", e);
         }
     }
 
@@ -296,10 +444,16 @@ impl BridgeHandler {
         };
 
         // Check for !commands first
-        if content.len() <= 100 && (content.starts_with('!') || content.starts_with('?')) {
+        // Same limit as CommandHandler, long enough for `!ginfo set <text>`
+        if content.chars().count() <= 600 && (content.starts_with('!') || content.starts_with('?')) {
             let channel_name = msg.channel_id.name(&context).await.unwrap_or_default();
             if resolved.command_allowed_in_channel(&channel_name, msg.channel_id.get()) {
-                match self.command_handler.handle_command(&context, &msg, content).await {
+                let officer_channel = resolved.is_officer_channel(msg.channel_id);
+                match self
+                    .command_handler
+                    .handle_command(&context, &msg, content, officer_channel)
+                    .await
+                {
                     Ok(true) => return, // Command was handled
                     Ok(false) => {}     // Not a known command, continue
                     Err(e) => {
@@ -368,6 +522,16 @@ impl BridgeHandler {
 
         let outgoing = self.bridge.handle_discord_to_wow(&discord_msg);
         for wow_msg in outgoing {
+            // Route is degraded: the bot is not in the WoW channel
+            if let Some(channel_name) = wow_msg.channel_name.as_ref().filter(|_| {
+                wow_msg.chat_type == chat_events::CHAT_MSG_CHANNEL
+            }) {
+                if self.degraded_channels.contains(&channel_name.to_lowercase()) {
+                    debug!("Dropping message to degraded WoW channel: {}", channel_name);
+                    msg.react(&context.http, '⚠').await.ok();
+                    continue;
+                }
+            }
             if let Err(e) = resolved.wow_tx.send(wow_msg) {
                 error!("Failed to send message to WoW: {}", e);
             }
@@ -404,6 +568,21 @@ impl BridgeHandler {
         // Resolve state
         let resolved = pending.resolve(&guild_channels, ready.user.id.into());
 
+        // Resolve admin channel if configured
+        if !self.admin_channel.is_empty() {
+            self.admin_channel_id = guild_channels
+                .iter()
+                .find(|ch| {
+                    ch.id.get().to_string() == self.admin_channel
+                        || ch.name().eq_ignore_ascii_case(&self.admin_channel)
+                })
+                .map(|ch| ch.id);
+            match self.admin_channel_id {
+                Some(id) => info!("Resolved admin channel '{}' -> ID {}", self.admin_channel, id),
+                None => warn!("Could not resolve admin channel: {}", self.admin_channel),
+            }
+        }
+
         // Store resolved state for message handler
         self.resolved_state = Some(Arc::new(resolved));
 
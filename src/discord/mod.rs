@@ -7,6 +7,7 @@ pub mod client;
 pub mod commands;
 pub mod dashboard;
 pub mod handler;
+pub mod report;
 pub mod resolver;
 
 // Re-export main types for external use
@@ -0,0 +1,175 @@
+//! Weekly inactivity report scheduling.
+//!
+//! Tracks whether this week's report went out. The date of the last posted
+//! report is kept in a small JSON file so a restart later on the report day
+//! does not post it a second time.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::config::types::InactivityReportConfig;
+
+/// A requested report with no response after this long is requested again.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Report state as stored on disk.
+#[derive(Debug, Serialize, Deserialize)]
+struct SavedReport {
+    /// UTC date of the last posted report, as YYYY-MM-DD.
+    last_posted: String,
+}
+
+/// Inactivity report schedule state.
+#[derive(Debug)]
+pub struct InactivityReportState {
+    /// File the last posted date is kept in (None = not kept).
+    path: Option<PathBuf>,
+    /// UTC date the report was last posted.
+    last_posted: Option<NaiveDate>,
+    /// Report date and request time of a request waiting for its response.
+    requested: Option<(NaiveDate, Instant)>,
+}
+
+impl InactivityReportState {
+    /// Load the state from `file`. An empty path keeps it in memory only.
+    pub fn load(file: &str) -> Self {
+        let path = (!file.is_empty()).then(|| PathBuf::from(file));
+        let last_posted = path.as_ref().and_then(|path| {
+            let text = match std::fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+                Err(e) => {
+                    warn!(
+                        "Failed to read inactivity report state {}: {}",
+                        path.display(),
+                        e
+                    );
+                    return None;
+                }
+            };
+            let date = serde_json::from_str::<SavedReport>(&text)
+                .map_err(|e| e.to_string())
+                .and_then(|saved| {
+                    NaiveDate::parse_from_str(&saved.last_posted, "%Y-%m-%d")
+                        .map_err(|e| e.to_string())
+                });
+            match date {
+                Ok(date) => Some(date),
+                Err(e) => {
+                    warn!(
+                        "Ignoring unreadable inactivity report state {}: {}",
+                        path.display(),
+                        e
+                    );
+                    None
+                }
+            }
+        });
+
+        Self {
+            path,
+            last_posted,
+            requested: None,
+        }
+    }
+
+    /// Check if the report should be requested now.
+    ///
+    /// False while an earlier request is still waiting for its response.
+    pub fn is_due(&self, config: &InactivityReportConfig, now: DateTime<Utc>, at: Instant) -> bool {
+        let waiting = self
+            .requested
+            .is_some_and(|(_, requested)| at.duration_since(requested) < RESPONSE_TIMEOUT);
+        !waiting && config.is_due(now, self.last_posted)
+    }
+
+    /// Remember that the report for `now`'s date was requested.
+    pub fn mark_requested(&mut self, now: DateTime<Utc>, at: Instant) {
+        self.requested = Some((now.date_naive(), at));
+    }
+
+    /// Record the outcome of the requested report. A failed report is
+    /// requested again on the next tick.
+    pub fn finish(&mut self, posted: bool) {
+        let Some((date, _)) = self.requested.take() else {
+            return;
+        };
+        if !posted {
+            return;
+        }
+        self.last_posted = Some(date);
+        self.save(date);
+    }
+
+    fn save(&self, date: NaiveDate) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let saved = SavedReport {
+            last_posted: date.format("%Y-%m-%d").to_string(),
+        };
+        let result = serde_json::to_string(&saved)
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                // Write to a temporary file first so a crash never leaves a truncated file
+                let tmp = path.with_extension("tmp");
+                std::fs::write(&tmp, json)?;
+                std::fs::rename(&tmp, path)
+            });
+        match result {
+            Ok(()) => debug!("Saved inactivity report date {}", date),
+            Err(e) => warn!(
+                "Failed to save inactivity report state {}: {}",
+                path.display(),
+                e
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_report_state() {
+        let path =
+            std::env::temp_dir().join(format!("innkeeper-report-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let file = path.to_str().unwrap();
+        let config = InactivityReportConfig {
+            enabled: true,
+            channel: "officers".to_string(),
+            ..InactivityReportConfig::default()
+        };
+        // 2024-06-03 is a Monday
+        let now = Utc.with_ymd_and_hms(2024, 6, 3, 18, 30, 0).unwrap();
+        let at = Instant::now();
+
+        let mut state = InactivityReportState::load(file);
+        assert!(state.is_due(&config, now, at));
+
+        // A failed report is requested again, a pending one only after the timeout
+        state.mark_requested(now, at);
+        assert!(!state.is_due(&config, now, at));
+        assert!(state.is_due(&config, now, at + RESPONSE_TIMEOUT));
+        state.finish(false);
+        assert!(state.is_due(&config, now, at));
+
+        state.mark_requested(now, at);
+        state.finish(true);
+        assert!(!state.is_due(&config, now, at));
+
+        // The posted date survives a restart
+        let state = InactivityReportState::load(file);
+        assert!(!state.is_due(&config, now, at));
+        assert!(state.is_due(&config, now + chrono::Duration::days(7), at));
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
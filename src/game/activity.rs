@@ -0,0 +1,448 @@
+//! Guild member activity tracking.
+//!
+//! Records play sessions per member from online/offline guild events and
+//! roster refreshes, and keeps them in a JSON file so the history survives
+//! restarts. Backs the `!activity` and `!top playtime` commands.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::common::types::{ActivitySummary, GuildMember};
+
+const DAY: u64 = 24 * 60 * 60;
+const WEEK: u64 = 7 * DAY;
+
+/// History the `!activity` averages are taken over.
+const SUMMARY_WINDOW: u64 = 4 * WEEK;
+
+/// Days covered by `!top playtime`.
+pub const TOP_PLAYTIME_DAYS: u32 = 7;
+
+/// Members listed by `!top playtime`.
+pub const TOP_PLAYTIME_LIMIT: usize = 10;
+
+/// Hours played at least this share of the busiest hour count as usual play hours.
+const USUAL_HOUR_SHARE: f32 = 0.5;
+
+/// On-disk activity data.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ActivityData {
+    /// When tracking started (unix seconds).
+    since: u64,
+    /// Activity per member, keyed by lowercase name.
+    members: HashMap<String, MemberActivity>,
+}
+
+/// Sessions of one guild member.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MemberActivity {
+    name: String,
+    /// Start of the current session, if online.
+    #[serde(default)]
+    online_since: Option<u64>,
+    /// Finished sessions as (start, end) unix seconds, oldest first.
+    #[serde(default)]
+    sessions: Vec<(u64, u64)>,
+}
+
+impl MemberActivity {
+    /// All sessions overlapping `[from, now)`, clipped to it; the open session ends at `now`.
+    fn sessions_since(&self, from: u64, now: u64) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.sessions
+            .iter()
+            .copied()
+            .chain(self.online_since.map(|start| (start, now)))
+            .filter(move |&(_, end)| end > from)
+            .map(move |(start, end)| (start.max(from), end.min(now)))
+            .filter(|&(start, end)| end > start)
+    }
+
+    fn seconds_since(&self, from: u64, now: u64) -> u64 {
+        self.sessions_since(from, now)
+            .map(|(start, end)| end - start)
+            .sum()
+    }
+}
+
+/// Play session history backed by a JSON file.
+#[derive(Debug)]
+pub struct ActivityStore {
+    path: PathBuf,
+    retention: u64,
+    data: ActivityData,
+    dirty: bool,
+}
+
+/// Serialised store waiting to be written to disk.
+#[derive(Debug)]
+pub struct ActivitySnapshot {
+    path: PathBuf,
+    json: String,
+    members: usize,
+}
+
+impl ActivitySnapshot {
+    /// Write the snapshot to its file. Returns false (after logging) on failure.
+    pub fn write(self) -> bool {
+        // Write to a temporary file first so a crash never leaves a truncated store
+        let tmp = self.path.with_extension("tmp");
+        let result =
+            std::fs::write(&tmp, self.json).and_then(|()| std::fs::rename(&tmp, &self.path));
+        match result {
+            Ok(()) => {
+                debug!("Saved activity for {} members", self.members);
+                true
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to save activity file {}: {}",
+                    self.path.display(),
+                    e
+                );
+                false
+            }
+        }
+    }
+}
+
+impl ActivityStore {
+    /// Load the store from `path`, starting empty when the file is missing or unreadable.
+    pub fn load(path: impl AsRef<Path>, retention_days: u32, now: u64) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let data = match std::fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring unreadable activity file {}: {}",
+                    path.display(),
+                    e
+                );
+                ActivityData::default()
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => ActivityData::default(),
+            Err(e) => {
+                warn!("Failed to read activity file {}: {}", path.display(), e);
+                ActivityData::default()
+            }
+        };
+        let mut store = Self {
+            path,
+            retention: retention_days as u64 * DAY,
+            data,
+            dirty: false,
+        };
+        if store.data.since == 0 {
+            store.data.since = now;
+            store.dirty = true;
+        }
+        store
+    }
+
+    fn member(&mut self, name: &str) -> &mut MemberActivity {
+        self.data
+            .members
+            .entry(name.to_lowercase())
+            .or_insert_with(|| MemberActivity {
+                name: name.to_string(),
+                ..Default::default()
+            })
+    }
+
+    /// Record a member coming online.
+    pub fn sign_on(&mut self, name: &str, now: u64) {
+        let member = self.member(name);
+        if member.online_since.is_none() {
+            member.online_since = Some(now);
+            self.dirty = true;
+        }
+    }
+
+    /// Record a member going offline at `at`.
+    pub fn sign_off(&mut self, name: &str, at: u64) {
+        let member = self.member(name);
+        if let Some(start) = member.online_since.take() {
+            member.sessions.push((start, at.max(start)));
+            self.dirty = true;
+        }
+    }
+
+    /// Bring online states in line with a roster refresh.
+    ///
+    /// Sessions left open by a missed event or a restart are closed at the
+    /// member's reported logoff time.
+    pub fn sync_roster(&mut self, members: &[GuildMember], now: u64) {
+        for m in members {
+            if m.online {
+                self.sign_on(&m.name, now);
+            } else if self
+                .data
+                .members
+                .get(&m.name.to_lowercase())
+                .is_some_and(|a| a.online_since.is_some())
+            {
+                let logoff = now.saturating_sub((m.last_logoff as f64 * DAY as f64) as u64);
+                self.sign_off(&m.name, logoff);
+            }
+        }
+
+        // Members that left the guild while online
+        let open: Vec<String> = self
+            .data
+            .members
+            .iter()
+            .filter(|(key, a)| {
+                a.online_since.is_some() && !members.iter().any(|m| m.name.to_lowercase() == **key)
+            })
+            .map(|(_, a)| a.name.clone())
+            .collect();
+        for name in open {
+            self.sign_off(&name, now);
+        }
+    }
+
+    /// Serialise the store if anything changed since the last snapshot,
+    /// dropping sessions past retention. The snapshot is written separately
+    /// so the file I/O can happen off the async runtime.
+    pub fn take_snapshot(&mut self, now: u64) -> Option<ActivitySnapshot> {
+        if !self.dirty {
+            return None;
+        }
+        let cutoff = now.saturating_sub(self.retention);
+        for member in self.data.members.values_mut() {
+            member.sessions.retain(|&(_, end)| end >= cutoff);
+        }
+        self.data
+            .members
+            .retain(|_, m| m.online_since.is_some() || !m.sessions.is_empty());
+
+        match serde_json::to_string(&self.data) {
+            Ok(json) => {
+                self.dirty = false;
+                Some(ActivitySnapshot {
+                    path: self.path.clone(),
+                    json,
+                    members: self.data.members.len(),
+                })
+            }
+            Err(e) => {
+                warn!("Failed to serialise activity: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Mark the store as changed again after a snapshot failed to write.
+    pub fn mark_unsaved(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Summarise a member's activity over the last weeks.
+    pub fn summary(&self, name: &str, now: u64) -> Option<ActivitySummary> {
+        let member = self.data.members.get(&name.to_lowercase())?;
+        let from = now.saturating_sub(SUMMARY_WINDOW).max(self.data.since);
+        // Average over at least a week so a fresh store does not extrapolate
+        let weeks = now.saturating_sub(from).max(WEEK) as f32 / WEEK as f32;
+
+        let mut sessions = 0;
+        let mut seconds = 0;
+        let mut hours = [0u64; 24];
+        for (start, end) in member.sessions_since(from, now) {
+            sessions += 1;
+            seconds += end - start;
+            add_to_hours(&mut hours, start, end);
+        }
+
+        Some(ActivitySummary {
+            name: member.name.clone(),
+            online: member.online_since.is_some(),
+            tracked_days: (now.saturating_sub(from) / DAY) as u32,
+            sessions_per_week: sessions as f32 / weeks,
+            hours_per_week: seconds as f32 / 3600.0 / weeks,
+            usual_hours: usual_hours(&hours),
+        })
+    }
+
+    /// Members with the most play time over the last `days`, as (name, hours).
+    pub fn top_playtime(&self, now: u64, days: u32, limit: usize) -> Vec<(String, f32)> {
+        let from = now.saturating_sub(days as u64 * DAY);
+        let mut top: Vec<(String, u64)> = self
+            .data
+            .members
+            .values()
+            .map(|m| (m.name.clone(), m.seconds_since(from, now)))
+            .filter(|&(_, seconds)| seconds > 0)
+            .collect();
+        top.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top.truncate(limit);
+        top.into_iter()
+            .map(|(name, seconds)| (name, seconds as f32 / 3600.0))
+            .collect()
+    }
+}
+
+/// Add the seconds of a session to per-hour-of-day (UTC) buckets.
+fn add_to_hours(hours: &mut [u64; 24], start: u64, end: u64) {
+    let mut t = start;
+    while t < end {
+        let next = ((t / 3600) + 1) * 3600;
+        hours[((t / 3600) % 24) as usize] += next.min(end) - t;
+        t = next;
+    }
+}
+
+/// Hours of the day played at least `USUAL_HOUR_SHARE` of the busiest hour,
+/// merged into (start, end) ranges with an exclusive end. Ranges may wrap past midnight.
+fn usual_hours(hours: &[u64; 24]) -> Vec<(u8, u8)> {
+    let peak = hours.iter().copied().max().unwrap_or(0);
+    if peak == 0 {
+        return Vec::new();
+    }
+    let busy: Vec<bool> = hours
+        .iter()
+        .map(|&s| s as f32 >= peak as f32 * USUAL_HOUR_SHARE)
+        .collect();
+
+    // Start scanning after a quiet hour so a range crossing midnight stays whole
+    let Some(offset) = busy.iter().position(|&b| !b) else {
+        return vec![(0, 24)];
+    };
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for i in 0..=24 {
+        let hour = (offset + i) % 24;
+        match (busy[hour] && i < 24, start) {
+            (true, None) => start = Some(hour as u8),
+            (false, Some(s)) => {
+                ranges.push((s, hour as u8));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    ranges.sort();
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 3600;
+    /// 2024-06-03 00:00 UTC, a Monday.
+    const START: u64 = 1_717_372_800;
+
+    fn member(name: &str, online: bool, last_logoff: f32) -> GuildMember {
+        GuildMember {
+            guid: 0,
+            name: name.to_string(),
+            level: 80,
+            class: None,
+            rank: 1,
+            rank_name: "Member".to_string(),
+            zone_id: 0,
+            online,
+            last_logoff,
+            note: String::new(),
+            officer_note: String::new(),
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("innkeeper-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_sessions_and_summary() {
+        let path = temp_path("activity-summary");
+        let mut store = ActivityStore::load(&path, 90, START);
+
+        // Two weeks of evenings, 20:00 to 22:00
+        for day in 0..14 {
+            let evening = START + day * DAY + 20 * HOUR;
+            store.sign_on("Thrall", evening);
+            store.sign_off("thrall", evening + 2 * HOUR);
+        }
+        let now = START + 14 * DAY;
+
+        let summary = store.summary("THRALL", now).unwrap();
+        assert_eq!(summary.name, "Thrall");
+        assert!(!summary.online);
+        assert_eq!(summary.tracked_days, 14);
+        assert_eq!(summary.sessions_per_week, 7.0);
+        assert_eq!(summary.hours_per_week, 14.0);
+        assert_eq!(summary.usual_hours, vec![(20, 22)]);
+        assert!(store.summary("Jaina", now).is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_roster_sync_and_persistence() {
+        let path = temp_path("activity-roster");
+        let _ = std::fs::remove_file(&path);
+        let mut store = ActivityStore::load(&path, 90, START);
+
+        store.sync_roster(
+            &[member("Thrall", true, 0.0), member("Jaina", true, 0.0)],
+            START,
+        );
+        assert!(store.take_snapshot(START).unwrap().write());
+        assert!(store.take_snapshot(START).is_none());
+
+        // After a restart, Thrall turns out to have logged off half an hour ago
+        // and Jaina left the guild
+        let now = START + 3 * HOUR;
+        let mut store = ActivityStore::load(&path, 90, now);
+        store.sync_roster(&[member("Thrall", false, 0.5 / 24.0)], now);
+
+        assert_eq!(
+            store.top_playtime(now, 7, 10),
+            vec![("Jaina".to_string(), 3.0), ("Thrall".to_string(), 2.5)]
+        );
+        assert!(!store.summary("Jaina", now).unwrap().online);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_retention_and_top_playtime() {
+        let path = temp_path("activity-retention");
+        let _ = std::fs::remove_file(&path);
+        let mut store = ActivityStore::load(&path, 30, START);
+
+        store.sign_on("Arthas", START);
+        store.sign_off("Arthas", START + HOUR);
+        store.sign_on("Sylvanas", START + 40 * DAY);
+        store.sign_off("Sylvanas", START + 40 * DAY + 4 * HOUR);
+        store.sign_on("Jaina", START + 41 * DAY);
+
+        let now = START + 41 * DAY + HOUR;
+        assert_eq!(
+            store.top_playtime(now, 7, 1),
+            vec![("Sylvanas".to_string(), 4.0)]
+        );
+
+        assert!(store.take_snapshot(now).unwrap().write());
+        let store = ActivityStore::load(&path, 30, now);
+        assert!(store.summary("Arthas", now).is_none());
+        assert!(store.summary("Jaina", now).unwrap().online);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_usual_hours() {
+        let mut hours = [0u64; 24];
+        assert!(usual_hours(&hours).is_empty());
+
+        // Late nights crossing midnight, plus a short lunch break
+        add_to_hours(&mut hours, START - 2 * HOUR, START + 2 * HOUR);
+        add_to_hours(&mut hours, START + 12 * HOUR, START + 12 * HOUR + 10 * 60);
+        assert_eq!(usual_hours(&hours), vec![(22, 2)]);
+
+        add_to_hours(&mut hours, START + 12 * HOUR, START + 13 * HOUR);
+        assert_eq!(usual_hours(&hours), vec![(12, 13), (22, 2)]);
+    }
+}
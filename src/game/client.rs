@@ -6,12 +6,18 @@ use tokio::net::TcpStream;
 
 use tracing::{debug, info, warn};
 
-use crate::common::messages::{DashboardEvent, GuildDashboardData};
+use crate::common::messages::{DashboardEvent, GuildDashboardData, GuildEventInfo, SocialNotice};
 use crate::bridge::GameChannels;
+use crate::common::types::{CustomChannel, SocialAction, SocialRequest};
 use crate::common::{ActivityStatus, BridgeCommand, BridgeMessage, CommandResponseData};
 use crate::config::types::Config;
 use crate::discord::commands::CommandResponse;
+use crate::game::activity::{ActivityStore, TOP_PLAYTIME_DAYS, TOP_PLAYTIME_LIMIT};
+use crate::game::name_cache::NameCacheFile;
+use crate::game::presence::PresenceAggregator;
+use crate::game::roster_diff::diff_roster;
 
+use crate::protocol::game::handler::{unix_time, GuildLogSeen, PendingMessageStats, SocialRequestInfo};
 use crate::protocol::game::packets::{AuthChallenge, AuthResponse, CharEnum, InitWorldStates, LoginVerifyWorld, Pong, TimeSyncReq};
 use crate::protocol::game::{new_game_connection, ChatProcessingResult, GameConnection, GameHandler};
 use crate::protocol::packets::opcodes::*;
@@ -30,15 +36,47 @@ enum HandlePacketResult {
 pub struct GameClient {
     config: Config,
     pub channels: GameChannels,
-    custom_channels: Vec<String>,
+    /// Custom channels joined on login, updated by !channel join/leave.
+    pub custom_channels: Vec<CustomChannel>,
+    /// Guild event log as last seen, kept across reconnects to find missed events.
+    pub guild_log: Option<GuildLogSeen>,
+    /// Online/offline events held back for aggregation.
+    presence: PresenceAggregator,
+    /// Recorded play sessions (None when activity tracking is disabled).
+    activity: Option<ActivityStore>,
+    /// Saved player name cache (None when not configured).
+    name_cache: Option<NameCacheFile>,
+    /// Chat relayed late or dropped while waiting for a sender name, kept across reconnects.
+    pub pending_message_stats: PendingMessageStats,
 }
 
 impl GameClient {
-    pub fn new(config: Config, channels: GameChannels, custom_channels: Vec<String>) -> Self {
+    pub fn new(
+        config: Config,
+        channels: GameChannels,
+        custom_channels: Vec<CustomChannel>,
+        guild_log: Option<GuildLogSeen>,
+        pending_message_stats: PendingMessageStats,
+    ) -> Self {
+        let presence = PresenceAggregator::new(config.guild.presence_window());
+        let activity = config.activity.enabled.then(|| {
+            ActivityStore::load(
+                &config.activity.file,
+                config.activity.retention_days,
+                unix_time(),
+            )
+        });
+        let name_cache = (!config.wow.name_cache.is_empty())
+            .then(|| NameCacheFile::new(&config.wow.name_cache));
         Self {
             config,
             channels,
             custom_channels,
+            guild_log,
+            presence,
+            activity,
+            name_cache,
+            pending_message_stats,
         }
     }
 
@@ -49,7 +87,20 @@ impl GameClient {
             .ok_or_else(|| anyhow!("Invalid realm address"))?;
         info!("Connecting to game server at {}:{}", host, port);
         let stream = TcpStream::connect((host, port)).await?;
-        self.handle_connection(stream, session).await
+        let result = self.handle_connection(stream, session).await;
+
+        // Write play sessions recorded since the last periodic save
+        self.save_activity().await;
+
+        // Send online/offline events still held back
+        let events = self.presence.flush();
+        self.send_presence_events(events);
+
+        // Guild events after this point were not seen live
+        if let Some(guild_log) = &mut self.guild_log {
+            guild_log.last_online = unix_time();
+        }
+        result
     }
 
     pub async fn handle_connection<S>(
@@ -67,6 +118,25 @@ impl GameClient {
             session.realm.id as u32,
             &self.config.wow.character,
         );
+        self.load_player_names(&mut handler).await;
+        let result = self.run_connection(&mut handler, &mut connection, &session).await;
+
+        // Save names resolved since the last periodic save
+        self.save_player_names(&mut handler).await;
+        self.pending_message_stats += handler.take_pending_message_stats();
+        result
+    }
+
+    /// Run the connection until it closes: packets, timers, shutdown and commands.
+    async fn run_connection<S>(
+        &mut self,
+        handler: &mut GameHandler,
+        connection: &mut GameConnection<S>,
+        session: &RealmSession,
+    ) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
         let mut shutdown_rx = self.channels.shutdown_rx.clone();
 
         let now = tokio::time::Instant::now();
@@ -85,6 +155,34 @@ impl GameClient {
         );
         keepalive_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
+        // Aggregated online/offline events are checked every second
+        let mut presence_interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+        presence_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        // Newly resolved or invalidated names are saved every 10 seconds
+        let mut name_cache_interval = tokio::time::interval(tokio::time::Duration::from_secs(10));
+        name_cache_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        // Finished and unanswered requests are replied to every second
+        let mut reply_interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+        reply_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        // Chat relayed without a sender name is summarised every hour
+        let mut stats_interval = tokio::time::interval_at(
+            now + tokio::time::Duration::from_secs(3600),
+            tokio::time::Duration::from_secs(3600),
+        );
+        stats_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut logged_stats = self.pending_message_stats;
+
+        // Recorded play sessions are saved every minute
+        let mut activity_interval = tokio::time::interval(tokio::time::Duration::from_secs(60));
+        activity_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        // Chat waiting on unanswered name queries is retried or relayed every second
+        let mut name_timeout_interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+        name_timeout_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
         info!("Game connection established");
 
         let mut logout_timeout: Option<std::pin::Pin<Box<tokio::time::Sleep>>> = None;
@@ -95,8 +193,8 @@ impl GameClient {
                     match packet {
                         Some(Ok(packet)) => {
                             let action = self.handle_packet(
-                                &mut handler,
-                                &mut connection,
+                                handler,
+                                connection,
                                 packet.opcode,
                                 packet.payload,
                                 &session.session_key,
@@ -117,7 +215,7 @@ impl GameClient {
                 // Shutdown signal received - send logout request, start timer
                 _ = shutdown_rx.changed() => {
                     if *shutdown_rx.borrow() {
-                        self.handle_shutdown(&mut handler, &mut connection).await?;
+                        self.handle_shutdown(handler, connection).await?;
                         logout_timeout = Some(Box::pin(tokio::time::sleep(
                             tokio::time::Duration::from_secs(21),
                         )));
@@ -132,22 +230,55 @@ impl GameClient {
 
                 // Ping keepalive every 30 seconds
                 _ = ping_interval.tick() => {
-                    self.handle_ping_tick(&mut handler, &mut connection).await?;
+                    self.handle_ping_tick(handler, connection).await?;
                 }
 
                 // KeepAlive packet every 30 seconds (TBC/WotLK specific)
                 _ = keepalive_interval.tick() => {
-                    self.handle_keepalive_tick(&mut handler, &mut connection).await?;
+                    self.handle_keepalive_tick(handler, connection).await?;
+                }
+
+                // Send aggregated online/offline events once things quiet down
+                _ = presence_interval.tick() => {
+                    let events = self.presence.take_due(std::time::Instant::now());
+                    self.send_presence_events(events);
+                }
+
+                _ = name_timeout_interval.tick() => {
+                    self.handle_name_timeout_tick(handler, connection).await?;
+                }
+
+                _ = reply_interval.tick() => {
+                    self.handle_reply_tick(handler);
+                }
+
+                _ = stats_interval.tick() => {
+                    if self.pending_message_stats != logged_stats {
+                        logged_stats = self.pending_message_stats;
+                        info!(
+                            late = logged_stats.late,
+                            dropped = logged_stats.dropped,
+                            "Chat messages without a resolved sender name since startup"
+                        );
+                    }
+                }
+
+                _ = activity_interval.tick() => {
+                    self.save_activity().await;
+                }
+
+                _ = name_cache_interval.tick() => {
+                    self.save_player_names(handler).await;
                 }
 
                 // Outgoing messages from bridge (Discord -> WoW)
                 Some(outgoing) = self.channels.outgoing_wow_rx.recv() => {
-                    self.handle_outgoing_message(&mut handler, &mut connection, outgoing).await?;
+                    self.handle_outgoing_message(handler, connection, outgoing).await?;
                 }
 
                 // Commands from Discord (!who, !gmotd)
                 Some(command) = self.channels.command_rx.recv() => {
-                    self.handle_command(&mut handler, command);
+                    self.handle_command(handler, connection, command).await;
                 }
             }
         }
@@ -155,7 +286,7 @@ impl GameClient {
 
     /// Handle incoming packet dispatch.
     async fn handle_packet<S>(
-        &self,
+        &mut self,
         handler: &mut GameHandler,
         connection: &mut GameConnection<S>,
         opcode: u16,
@@ -192,16 +323,28 @@ impl GameClient {
                 self.on_name_query(handler, payload)?;
             }
             SMSG_CHANNEL_NOTIFY => {
-                handler.handle_channel_notify(payload)?;
+                self.on_channel_notify(handler, payload)?;
+            }
+            SMSG_CHANNEL_LIST => {
+                self.on_channel_list(handler, connection, payload).await?;
             }
             SMSG_GUILD_QUERY => {
                 handler.handle_guild_query(payload)?;
             }
             SMSG_GUILD_ROSTER => {
                 self.on_guild_roster(handler, payload)?;
+                self.send_guild_command_replies(handler);
             }
             SMSG_GUILD_EVENT => {
                 self.on_guild_event(handler, connection, payload).await?;
+                self.send_guild_command_replies(handler);
+            }
+            MSG_GUILD_EVENT_LOG_QUERY => {
+                self.on_guild_event_log(handler, connection, payload).await?;
+            }
+            SMSG_GUILD_COMMAND_RESULT => {
+                handler.handle_guild_command_result(payload)?;
+                self.send_guild_command_replies(handler);
             }
             SMSG_NOTIFICATION => {
                 self.on_notification(handler, payload);
@@ -232,12 +375,46 @@ impl GameClient {
                 let _ = InitWorldStates::decode(&mut payload)?;
                 handler.handle_init_world_states();
             }
-            SMSG_UPDATE_OBJECT => {
-                self.on_update_object(handler, connection, payload).await?;
+            SMSG_UPDATE_OBJECT | SMSG_COMPRESSED_UPDATE_OBJECT => {
+                self.on_update_object(handler, connection, opcode, payload).await?;
+            }
+            SMSG_DESTROY_OBJECT => {
+                handler.handle_destroy_object(payload)?;
             }
             SMSG_INVALIDATE_PLAYER => {
                 handler.handle_invalidate_player(payload)?;
             }
+            SMSG_WHO => {
+                self.on_who(handler, payload)?;
+            }
+            SMSG_GROUP_INVITE => {
+                if let Some(info) = handler.handle_group_invite(payload)? {
+                    self.on_social_request(handler, connection, info).await?;
+                }
+            }
+            SMSG_GROUP_LIST => {
+                handler.handle_group_list(payload)?;
+            }
+            SMSG_GROUP_DESTROYED | SMSG_GROUP_UNINVITE => {
+                handler.handle_group_removed();
+            }
+            SMSG_DUEL_REQUESTED => {
+                let info = handler.handle_duel_requested(payload)?;
+                self.on_social_request(handler, connection, info).await?;
+            }
+            SMSG_TRADE_STATUS => {
+                if let Some(info) = handler.handle_trade_status(payload)? {
+                    self.on_social_request(handler, connection, info).await?;
+                }
+            }
+            SMSG_GUILD_INVITE => {
+                let info = handler.handle_guild_invite(payload)?;
+                self.on_social_request(handler, connection, info).await?;
+            }
+            SMSG_PETITION_SHOW_SIGNATURES => {
+                let info = handler.handle_petition_offer(payload)?;
+                self.on_social_request(handler, connection, info).await?;
+            }
             _ => {
                 // Ignore unknown packets
             }
@@ -335,13 +512,17 @@ impl GameClient {
 
             let roster_req = handler.request_guild_roster();
             connection.send(roster_req.into()).await?;
+
+            // Catch up on guild events missed while offline
+            let log_query = handler.request_guild_event_log(None);
+            connection.send(log_query.into()).await?;
         }
 
         // Join custom channels
-        for channel_name in &self.custom_channels {
-            let join = handler.build_join_channel(channel_name);
+        for channel in &self.custom_channels {
+            let join = handler.build_join_channel(&channel.name, channel.password.as_deref());
             connection.send(join.into()).await?;
-            info!("Joining channel: {}", channel_name);
+            info!("Joining channel: {}", channel.name);
         }
 
         Ok(())
@@ -411,6 +592,9 @@ impl GameClient {
                 warn!("Failed to send message to bridge: {}", e);
             }
         }
+        self.send_ready_member_lists(handler);
+        self.send_ready_guild_logs(handler);
+        self.send_ready_social_notices(handler);
         Ok(())
     }
 
@@ -418,10 +602,32 @@ impl GameClient {
     // Guild handlers
     // ========================================================================
 
-    fn on_guild_roster(&self, handler: &mut GameHandler, payload: Bytes) -> Result<()> {
+    fn on_guild_roster(&mut self, handler: &mut GameHandler, payload: Bytes) -> Result<()> {
+        let previous = std::mem::take(&mut handler.guild_roster);
         handler.handle_guild_roster(payload)?;
         info!("Guild roster received: {} members", handler.guild_roster.len());
 
+        let announced = handler.take_guild_event_members();
+        // The first roster after login has nothing to compare against
+        if !previous.is_empty() {
+            for (event_data, content) in diff_roster(&previous, &handler.guild_roster, &announced) {
+                debug!(
+                    guild_event = %event_data.event_name,
+                    player = %event_data.player_name,
+                    "Guild roster change"
+                );
+                let wow_msg = BridgeMessage::guild_event(event_data, content);
+                if let Err(e) = self.channels.wow_tx.send(wow_msg) {
+                    warn!("Failed to send roster change to bridge: {}", e);
+                }
+            }
+        }
+
+        if let Some(activity) = &mut self.activity {
+            let now = unix_time();
+            activity.sync_roster(&handler.get_guildies(), now);
+        }
+
         // Send guild stats update
         let online_count = handler.get_online_guildies_count();
         if let Err(e) = self.channels.status_tx.send(ActivityStatus::GuildStats { online_count }) {
@@ -433,7 +639,7 @@ impl GameClient {
             let dashboard_data = GuildDashboardData {
                 guild_name: guild_info.name.clone(),
                 realm: self.config.wow.realm.clone(),
-                members: handler.get_online_guildies(),
+                members: handler.get_guildies(),
                 online: true,
             };
 
@@ -445,7 +651,7 @@ impl GameClient {
     }
 
     async fn on_guild_event<S>(
-        &self,
+        &mut self,
         handler: &mut GameHandler,
         connection: &mut GameConnection<S>,
         payload: Bytes,
@@ -476,10 +682,27 @@ impl GameClient {
                 .contains(&event_data.event_name.as_str());
             let event_name = event_data.event_name.clone();
 
-            // Send guild event as a BridgeMessage to Discord
-            let wow_msg = BridgeMessage::guild_event(event_data, content);
-            if let Err(e) = self.channels.wow_tx.send(wow_msg) {
-                warn!("Failed to send guild event to bridge: {}", e);
+            if let Some(activity) = &mut self.activity {
+                let now = unix_time();
+                match event_data.event_name.as_str() {
+                    "online" => activity.sign_on(&event_data.player_name, now),
+                    "offline" => activity.sign_off(&event_data.player_name, now),
+                    _ => {}
+                }
+            }
+
+            // Send guild event as a BridgeMessage to Discord, holding online/offline
+            // events back for aggregation
+            let event_data = if PresenceAggregator::handles(&event_data) {
+                self.presence.push(event_data, std::time::Instant::now())
+            } else {
+                Some(event_data)
+            };
+            if let Some(event_data) = event_data {
+                let wow_msg = BridgeMessage::guild_event(event_data, content);
+                if let Err(e) = self.channels.wow_tx.send(wow_msg) {
+                    warn!("Failed to send guild event to bridge: {}", e);
+                }
             }
 
             // Update roster on online/offline/join/left events
@@ -495,6 +718,165 @@ impl GameClient {
         Ok(())
     }
 
+    async fn on_guild_event_log<S>(
+        &mut self,
+        handler: &mut GameHandler,
+        connection: &mut GameConnection<S>,
+        payload: Bytes,
+    ) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let result = handler.handle_guild_event_log(payload)?;
+        for guid in result.unnamed {
+            let name_query = handler.build_name_query(guid);
+            connection.send(name_query.into()).await?;
+        }
+
+        if result.first_since_login {
+            if let Some(seen) = &self.guild_log {
+                handler.queue_missed_guild_events(seen.missed(&result.records));
+            }
+        }
+        self.guild_log = Some(GuildLogSeen {
+            records: result.records,
+            last_online: unix_time(),
+        });
+
+        self.send_ready_guild_logs(handler);
+        Ok(())
+    }
+
+    /// Send !guildlog replies and missed guild events whose names have resolved.
+    fn send_ready_guild_logs(&self, handler: &mut GameHandler) {
+        for log in handler.take_ready_guild_logs() {
+            let Some(reply_channel) = log.reply_channel else {
+                for entry in log.entries {
+                    // Invites have no guild event format
+                    if entry.event.event_name == "invited" {
+                        continue;
+                    }
+                    let wow_msg = BridgeMessage::guild_event(entry.event, String::new());
+                    if let Err(e) = self.channels.wow_tx.send(wow_msg) {
+                        warn!("Failed to send missed guild event to bridge: {}", e);
+                    }
+                }
+                continue;
+            };
+
+            info!("Processed !guildlog command for channel {}", reply_channel);
+
+            let cmd_response = CommandResponse {
+                channel_id: reply_channel,
+                content: CommandResponseData::GuildLog(log.entries),
+            };
+            if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                warn!("Failed to send !guildlog response to bridge: {}", e);
+            }
+        }
+    }
+
+    /// Read the saved player names into the handler on a blocking thread.
+    async fn load_player_names(&mut self, handler: &mut GameHandler) {
+        let Some(mut name_cache) = self.name_cache.take() else {
+            return;
+        };
+        let now = unix_time();
+        let loaded = tokio::task::spawn_blocking(move || {
+            let players = name_cache.load(now);
+            (name_cache, players)
+        })
+        .await;
+        match loaded {
+            Ok((name_cache, players)) => {
+                handler.load_player_names(players);
+                self.name_cache = Some(name_cache);
+            }
+            Err(e) => warn!("Failed to load name cache: {}", e),
+        }
+    }
+
+    /// Save names resolved or invalidated since the last save on a blocking thread.
+    async fn save_player_names(&mut self, handler: &mut GameHandler) {
+        if self.name_cache.is_none() {
+            return;
+        }
+        let Some(players) = handler.take_changed_player_names() else {
+            return;
+        };
+        let Some(mut name_cache) = self.name_cache.take() else {
+            return;
+        };
+        let now = unix_time();
+        match tokio::task::spawn_blocking(move || {
+            name_cache.save(&players, now);
+            name_cache
+        })
+        .await
+        {
+            Ok(name_cache) => self.name_cache = Some(name_cache),
+            Err(e) => warn!("Failed to save name cache: {}", e),
+        }
+    }
+
+    /// Write changed play sessions to disk on a blocking thread.
+    async fn save_activity(&mut self) {
+        let Some(activity) = &mut self.activity else {
+            return;
+        };
+        let Some(snapshot) = activity.take_snapshot(unix_time()) else {
+            return;
+        };
+        let written = tokio::task::spawn_blocking(move || snapshot.write())
+            .await
+            .unwrap_or(false);
+        if !written {
+            activity.mark_unsaved();
+        }
+    }
+
+    /// Send aggregated online/offline events to the bridge.
+    fn send_presence_events(&self, events: Vec<(GuildEventInfo, usize)>) {
+        for (event, count) in events {
+            debug!(
+                guild_event = %event.event_name,
+                players = %event.player_name,
+                "Sending aggregated guild event"
+            );
+            // Several players need the plural wording
+            let format = if count > 1 {
+                self.config.guild.get_event_batch_format(&event.event_name)
+            } else {
+                None
+            };
+            let mut wow_msg = BridgeMessage::guild_event(event, String::new());
+            wow_msg.format = format;
+            if let Err(e) = self.channels.wow_tx.send(wow_msg) {
+                warn!("Failed to send guild event to bridge: {}", e);
+            }
+        }
+    }
+
+    /// Send !guild replies resolved by command results, guild events or timeouts.
+    fn send_guild_command_replies(&self, handler: &mut GameHandler) {
+        for reply in handler.take_guild_command_replies() {
+            info!("Processed !guild command for channel {}", reply.reply_channel);
+
+            let content = if reply.success {
+                CommandResponseData::String(reply.message)
+            } else {
+                CommandResponseData::Error(reply.message)
+            };
+            let cmd_response = CommandResponse {
+                channel_id: reply.reply_channel,
+                content,
+            };
+            if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                warn!("Failed to send !guild response to bridge: {}", e);
+            }
+        }
+    }
+
     // ========================================================================
     // System message handlers
     // ========================================================================
@@ -538,6 +920,225 @@ impl GameClient {
         }
     }
 
+    fn on_channel_notify(&mut self, handler: &mut GameHandler, payload: Bytes) -> Result<()> {
+        let notify = handler.handle_channel_notify(payload)?;
+
+        for (reply_channel, message) in notify.list_errors {
+            let cmd_response = CommandResponse {
+                channel_id: reply_channel,
+                content: CommandResponseData::Error(message),
+            };
+            if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                warn!("Failed to send !members response to bridge: {}", e);
+            }
+        }
+
+        if let Some(event) = notify.event {
+            if let Err(e) = self.channels.channel_event_tx.send(event) {
+                warn!("Failed to send channel event to bridge: {}", e);
+            }
+        }
+
+        let Some(result) = notify.command else {
+            return Ok(());
+        };
+
+        if let Some(channel) = result.joined {
+            if !self
+                .custom_channels
+                .iter()
+                .any(|c| c.name.eq_ignore_ascii_case(&channel.name))
+            {
+                self.custom_channels.push(channel);
+            }
+        }
+        if let Some(name) = result.left {
+            self.custom_channels
+                .retain(|c| !c.name.eq_ignore_ascii_case(&name));
+        }
+        // Rejoins after a kick or reconnect must use the new password
+        if let Some(changed) = result.password_changed {
+            if let Some(channel) = self
+                .custom_channels
+                .iter_mut()
+                .find(|c| c.name.eq_ignore_ascii_case(&changed.name))
+            {
+                channel.password = changed.password;
+            }
+        }
+
+        info!("Processed !channel command for channel {}", result.reply_channel);
+
+        let cmd_response = CommandResponse {
+            channel_id: result.reply_channel,
+            content: CommandResponseData::String(result.message),
+        };
+        if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+            warn!("Failed to send !channel response to bridge: {}", e);
+        }
+        Ok(())
+    }
+
+    async fn on_channel_list<S>(
+        &self,
+        handler: &mut GameHandler,
+        connection: &mut GameConnection<S>,
+        payload: Bytes,
+    ) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        for guid in handler.handle_channel_list(payload)? {
+            let name_query = handler.build_name_query(guid);
+            connection.send(name_query.into()).await?;
+        }
+        self.send_ready_member_lists(handler);
+        Ok(())
+    }
+
+    /// Send !members replies whose names have resolved.
+    fn send_ready_member_lists(&self, handler: &mut GameHandler) {
+        for (reply_channel, channel_name, members) in handler.take_ready_member_lists() {
+            info!("Processed !members command for channel {}", reply_channel);
+
+            let cmd_response = CommandResponse {
+                channel_id: reply_channel,
+                content: CommandResponseData::ChannelMembers(channel_name, members),
+            };
+            if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                warn!("Failed to send !members response to bridge: {}", e);
+            }
+        }
+    }
+
+    /// Send replies that are finished or that the server did not answer in time.
+    fn handle_reply_tick(&self, handler: &mut GameHandler) {
+        // Member lists and guild logs stuck on unanswered name queries go out without those names
+        self.send_ready_member_lists(handler);
+        self.send_ready_guild_logs(handler);
+
+        for reply_channel in handler.take_expired_guild_log_requests() {
+            warn!("Guild event log request timed out");
+            let cmd_response = CommandResponse {
+                channel_id: reply_channel,
+                content: CommandResponseData::Error(
+                    "No guild event log from the server.".to_string(),
+                ),
+            };
+            if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                warn!("Failed to send !guildlog response to bridge: {}", e);
+            }
+        }
+
+        for (reply_channel, search_name) in handler.take_expired_who_requests() {
+            warn!("Server-wide WHO for '{}' timed out", search_name);
+            let cmd_response = CommandResponse {
+                channel_id: reply_channel,
+                content: CommandResponseData::Error(format!(
+                    "No WHO answer from the server for '{}'.",
+                    search_name
+                )),
+            };
+            if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                warn!("Failed to send !who response to bridge: {}", e);
+            }
+        }
+    }
+
+    fn on_who(&self, handler: &mut GameHandler, payload: Bytes) -> Result<()> {
+        if let Some((reply_channel, search_name, players)) = handler.handle_who(payload)? {
+            info!("Processed server-wide !who for channel {}", reply_channel);
+
+            let cmd_response = CommandResponse {
+                channel_id: reply_channel,
+                content: CommandResponseData::WhoServer(search_name, players),
+            };
+            if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                warn!("Failed to send !who response to bridge: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Answer a duel, trade, group, guild or petition request per the social policy.
+    async fn on_social_request<S>(
+        &self,
+        handler: &mut GameHandler,
+        connection: &mut GameConnection<S>,
+        info: SocialRequestInfo,
+    ) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let social = &self.config.social;
+        let name = info.request.config_name();
+        let action = if info.request == SocialRequest::Group
+            && self.accepts_group_invite(handler, &info.player)
+        {
+            SocialAction::Accept
+        } else {
+            SocialAction::from_name(social.get_action(name)).unwrap_or(SocialAction::Decline)
+        };
+
+        match action {
+            SocialAction::Accept => {
+                info!("Accepting {} request from {}", name, info.player);
+                connection.send(handler.build_social_response(&info, true)).await?;
+            }
+            SocialAction::Decline => {
+                info!("Declining {} request from {}", name, info.player);
+                connection.send(handler.build_social_response(&info, false)).await?;
+            }
+            SocialAction::Ignore => {
+                debug!("Ignoring {} request from {}", name, info.player);
+            }
+        }
+
+        if social.is_notify_enabled(name) {
+            let notice = SocialNotice {
+                request: info.request,
+                player: info.player,
+                action,
+            };
+            match info.unnamed_guid {
+                // Hold the notice until the requester's name is known
+                Some(guid) => {
+                    handler.hold_social_notice(guid, notice);
+                    if handler.pending_name_queries.insert(guid) {
+                        let name_query = handler.build_name_query(guid);
+                        connection.send(name_query.into()).await?;
+                    }
+                }
+                None => self.send_social_notice(notice),
+            }
+        }
+        Ok(())
+    }
+
+    /// Send held social notices whose requester name resolved or timed out.
+    fn send_ready_social_notices(&self, handler: &mut GameHandler) {
+        for notice in handler.take_ready_social_notices() {
+            self.send_social_notice(notice);
+        }
+    }
+
+    fn send_social_notice(&self, notice: SocialNotice) {
+        if let Err(e) = self.channels.social_notice_tx.send(notice) {
+            warn!("Failed to send social notice to bridge: {}", e);
+        }
+    }
+
+    /// Check the configured group invite policy (guild members and/or an allowlist).
+    fn accepts_group_invite(&self, handler: &GameHandler, inviter: &str) -> bool {
+        let group = &self.config.group;
+        let allowlisted = group
+            .invite_allowlist
+            .as_ref()
+            .is_some_and(|names| names.iter().any(|n| n.eq_ignore_ascii_case(inviter)));
+
+        allowlisted || (group.accept_guild_invites && handler.search_guild_member(inviter).is_some())
+    }
+
     // ========================================================================
     // World handlers
     // ========================================================================
@@ -546,22 +1147,35 @@ impl GameClient {
         &self,
         handler: &mut GameHandler,
         connection: &mut GameConnection<S>,
+        opcode: u16,
         payload: Bytes,
     ) -> Result<()>
     where
         S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
     {
-        match handler.handle_update_object(payload, self.config.quirks.sit) {
+        let result = if opcode == SMSG_COMPRESSED_UPDATE_OBJECT {
+            handler.handle_compressed_update_object(payload, self.config.quirks.sit)
+        } else {
+            handler.handle_update_object(payload, self.config.quirks.sit)
+        };
+
+        match result {
             Ok(Some(guid)) => {
                 info!("Found a chair! Sitting on it...");
                 let interact = handler.build_gameobj_use(guid);
                 connection.send(interact.into()).await?;
             }
             Err(e) => {
-                warn!("Failed to parse SMSG_UPDATE_OBJECT: {}", e);
+                warn!("Failed to parse {}: {}", opcode_name(opcode), e);
             }
             _ => {}
         }
+
+        // Resolve names of players that just came into view (for !nearby)
+        for guid in handler.take_unnamed_nearby_players() {
+            let name_query = handler.build_name_query(guid);
+            connection.send(name_query.into()).await?;
+        }
         Ok(())
     }
 
@@ -589,6 +1203,42 @@ impl GameClient {
         Ok(())
     }
 
+    /// Retry name queries for chat stuck waiting on a sender name, then relay
+    /// it with a fallback sender label once the retry times out as well.
+    async fn handle_name_timeout_tick<S>(
+        &mut self,
+        handler: &mut GameHandler,
+        connection: &mut GameConnection<S>,
+    ) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let (retries, expired) = handler.take_expired_pending_messages(std::time::Instant::now());
+        self.pending_message_stats += handler.take_pending_message_stats();
+        for guid in retries {
+            debug!("Retrying name query for GUID {}", guid);
+            let name_query = handler.build_name_query(guid);
+            connection.send(name_query.into()).await?;
+        }
+
+        if !expired.is_empty() {
+            warn!(
+                late = self.pending_message_stats.late,
+                dropped = self.pending_message_stats.dropped,
+                "Relaying {} messages without a resolved sender name",
+                expired.len()
+            );
+        }
+        for chat_msg in expired {
+            let wow_msg = BridgeMessage::from(chat_msg);
+            if let Err(e) = self.channels.wow_tx.send(wow_msg) {
+                warn!("Failed to send message to bridge: {}", e);
+            }
+        }
+        self.send_ready_social_notices(handler);
+        Ok(())
+    }
+
     async fn handle_ping_tick<S>(
         &self,
         handler: &mut GameHandler,
@@ -612,6 +1262,25 @@ impl GameClient {
                     debug!("Requested guild roster update");
                 }
             }
+
+
+            // Time out !guild commands the server never answered
+            self.send_guild_command_replies(handler);
+
+            // Rejoin channels we were kicked from once their backoff elapsed
+            for channel_name in handler.take_due_channel_rejoins() {
+                let password = self
+                    .custom_channels
+                    .iter()
+                    .find(|c| c.name.eq_ignore_ascii_case(&channel_name))
+                    .and_then(|c| c.password.as_deref());
+                let join = handler.build_join_channel(&channel_name, password);
+                if let Err(e) = connection.send(join.into()).await {
+                    warn!("Failed to send channel rejoin: {}", e);
+                } else {
+                    info!("Rejoining channel: {}", channel_name);
+                }
+            }
         }
         Ok(())
     }
@@ -656,12 +1325,32 @@ impl GameClient {
         Ok(())
     }
 
-    fn handle_command(&self, handler: &mut GameHandler, command: BridgeCommand) {
+    async fn handle_command<S>(
+        &self,
+        handler: &mut GameHandler,
+        connection: &mut GameConnection<S>,
+        command: BridgeCommand,
+    ) where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
         match command {
             BridgeCommand::Who { args, reply_channel } => {
                 let content = if let Some(search_name) = args {
                     let member = handler.search_guild_member(&search_name);
                     let guild_name = handler.guild_info.as_ref().map(|g| g.name.clone());
+
+                    // Not a guildie: ask the server, the answer arrives as SMSG_WHO
+                    if member.is_none() && handler.in_world {
+                        let who = handler.request_who(&search_name, reply_channel);
+                        match connection.send(who.into()).await {
+                            Ok(()) => {
+                                debug!("Sent server-wide WHO for '{}'", search_name);
+                                return;
+                            }
+                            Err(e) => warn!("Failed to send WHO request to WoW: {}", e),
+                        }
+                    }
+
                     CommandResponseData::WhoSearch(search_name, member, guild_name)
                 } else {
                     let members = handler.get_online_guildies();
@@ -680,6 +1369,383 @@ impl GameClient {
                     warn!("Failed to send !who response to bridge: {}", e);
                 }
             }
+            BridgeCommand::Inactive {
+                days,
+                rank,
+                reply_channel,
+            } => {
+                let members = handler.get_inactive_guildies(days, rank.as_deref());
+
+                info!("Processed !inactive command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content: CommandResponseData::Inactive(members, days, rank),
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !inactive response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::ExportRoster {
+                format,
+                reply_channel,
+            } => {
+                let content = if handler.guild_id == 0 {
+                    CommandResponseData::Error("The bot is not in a guild.".to_string())
+                } else {
+                    CommandResponseData::RosterExport(handler.get_roster(), format)
+                };
+
+                info!("Processed !export command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !export response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::Activity {
+                name,
+                reply_channel,
+            } => {
+                let content = match &self.activity {
+                    Some(activity) => {
+                        let summary = activity.summary(&name, unix_time());
+                        CommandResponseData::Activity(name, summary)
+                    }
+                    None => CommandResponseData::Error("Activity tracking is disabled.".to_string()),
+                };
+
+                info!("Processed !activity command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !activity response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::TopPlaytime { reply_channel } => {
+                let content = match &self.activity {
+                    Some(activity) => CommandResponseData::TopPlaytime(
+                        activity.top_playtime(unix_time(), TOP_PLAYTIME_DAYS, TOP_PLAYTIME_LIMIT),
+                        TOP_PLAYTIME_DAYS,
+                    ),
+                    None => CommandResponseData::Error("Activity tracking is disabled.".to_string()),
+                };
+
+                info!("Processed !top command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !top response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::Nearby { reply_channel } => {
+                let content = CommandResponseData::Nearby(handler.get_nearby_entities());
+
+                info!("Processed !nearby command for channel {}", reply_channel);
+
+                // Send response back to Discord
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !nearby response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::Status { reply_channel } => {
+                let content = CommandResponseData::String(format!(
+                    "Connected to {} as {}.\n{}",
+                    self.config.wow.realm,
+                    self.config.wow.character,
+                    self.pending_message_stats.summary()
+                ));
+
+                info!("Processed !status command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !status response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::ChannelJoin {
+                name,
+                password,
+                reply_channel,
+            } => {
+                let join = handler.request_channel_join(CustomChannel { name, password }, reply_channel);
+                let channel_name = join.channel_name.clone();
+                if let Err(e) = connection.send(join.into()).await {
+                    warn!("Failed to send channel join to WoW: {}", e);
+                } else {
+                    info!("Joining channel {} for !channel command", channel_name);
+                }
+            }
+            BridgeCommand::ChannelLeave { name, reply_channel } => {
+                let leave = handler.request_channel_leave(&name, reply_channel);
+                if let Err(e) = connection.send(leave.into()).await {
+                    warn!("Failed to send channel leave to WoW: {}", e);
+                } else {
+                    info!("Leaving channel {} for !channel command", name);
+                }
+            }
+            BridgeCommand::ChannelModerate {
+                name,
+                action,
+                reply_channel,
+            } => {
+                let moderate = handler.request_channel_moderation(&name, action, reply_channel);
+                if let Err(e) = connection.send(moderate.into()).await {
+                    warn!("Failed to send channel moderation to WoW: {}", e);
+                } else {
+                    info!("Sent moderation for channel {} for !channel command", name);
+                }
+            }
+            BridgeCommand::Members {
+                channel_name,
+                reply_channel,
+            } => {
+                if !handler.in_world {
+                    let cmd_response = CommandResponse {
+                        channel_id: reply_channel,
+                        content: CommandResponseData::Error(
+                            "The bot is not in the world yet.".to_string(),
+                        ),
+                    };
+                    if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                        warn!("Failed to send !members response to bridge: {}", e);
+                    }
+                    return;
+                }
+                let request = handler.request_channel_list(&channel_name, reply_channel);
+                if let Err(e) = connection.send(request.into()).await {
+                    warn!("Failed to send channel list request to WoW: {}", e);
+                } else {
+                    debug!("Requested member list for channel {}", channel_name);
+                }
+            }
+            BridgeCommand::GroupLeave { reply_channel } => {
+                let content = match handler.build_group_leave() {
+                    Ok(leave) => match connection.send(leave.into()).await {
+                        Ok(()) => CommandResponseData::String("Left the group.".to_string()),
+                        Err(e) => {
+                            warn!("Failed to send group leave to WoW: {}", e);
+                            return;
+                        }
+                    },
+                    Err(e) => CommandResponseData::Error(e.to_string()),
+                };
+
+                info!("Processed !group leave command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !group response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::GroupDisband { reply_channel } => {
+                let content = match handler.build_group_disband() {
+                    Ok(uninvites) => {
+                        let count = uninvites.len();
+                        for uninvite in uninvites {
+                            if let Err(e) = connection.send(uninvite.into()).await {
+                                warn!("Failed to send group uninvite to WoW: {}", e);
+                                return;
+                            }
+                        }
+                        CommandResponseData::String(format!(
+                            "Disbanded the group ({} members removed).",
+                            count
+                        ))
+                    }
+                    Err(e) => CommandResponseData::Error(e.to_string()),
+                };
+
+                info!("Processed !group disband command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !group response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::GuildMember {
+                action,
+                name,
+                reply_channel,
+            } => {
+                match handler.request_guild_member_command(action, &name, reply_channel) {
+                    Ok(command) => {
+                        if let Err(e) = connection.send(command.into()).await {
+                            warn!("Failed to send guild command to WoW: {}", e);
+                        } else {
+                            info!("Sent guild {:?} for {} for !guild command", action, name);
+                        }
+                    }
+                    Err(e) => {
+                        let cmd_response = CommandResponse {
+                            channel_id: reply_channel,
+                            content: CommandResponseData::Error(e.to_string()),
+                        };
+                        if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                            warn!("Failed to send !guild response to bridge: {}", e);
+                        }
+                    }
+                }
+            }
+            BridgeCommand::SetGuildMotd {
+                text,
+                reply_channel,
+            } => match handler.request_set_guild_motd(&text, reply_channel) {
+                Ok(Some(request)) => {
+                    if let Err(e) = connection.send(request.into()).await {
+                        warn!("Failed to send guild MOTD to WoW: {}", e);
+                    } else {
+                        info!("Sent guild MOTD change for !gmotd set");
+                    }
+                }
+                // Unchanged MOTD, already answered
+                Ok(None) => self.send_guild_command_replies(handler),
+                Err(e) => {
+                    let cmd_response = CommandResponse {
+                        channel_id: reply_channel,
+                        content: CommandResponseData::Error(e.to_string()),
+                    };
+                    if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                        warn!("Failed to send !gmotd response to bridge: {}", e);
+                    }
+                }
+            },
+            BridgeCommand::GuildInfo { reply_channel } => {
+                let info = handler.guild_info_text.clone();
+                let guild_name = handler.guild_info.as_ref().map(|g| g.name.clone());
+                let content = CommandResponseData::GuildInfoText(info, guild_name);
+
+                info!("Processed !ginfo command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !ginfo response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::SetGuildInfo {
+                text,
+                reply_channel,
+            } => match handler.request_set_guild_info(&text, reply_channel) {
+                Ok(Some(request)) => {
+                    if let Err(e) = connection.send(request.into()).await {
+                        warn!("Failed to send guild information to WoW: {}", e);
+                    } else {
+                        info!("Sent guild information change for !ginfo set");
+                        // The change is not broadcast; read it back from the roster
+                        let roster_req = handler.request_guild_roster();
+                        if let Err(e) = connection.send(roster_req.into()).await {
+                            warn!("Failed to send guild roster request: {}", e);
+                        }
+                    }
+                }
+                // Unchanged information, already answered
+                Ok(None) => self.send_guild_command_replies(handler),
+                Err(e) => {
+                    let cmd_response = CommandResponse {
+                        channel_id: reply_channel,
+                        content: CommandResponseData::Error(e.to_string()),
+                    };
+                    if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                        warn!("Failed to send !ginfo response to bridge: {}", e);
+                    }
+                }
+            },
+            BridgeCommand::GuildLog {
+                count,
+                reply_channel,
+            } => {
+                if handler.guild_id == 0 {
+                    let cmd_response = CommandResponse {
+                        channel_id: reply_channel,
+                        content: CommandResponseData::Error("The bot is not in a guild.".to_string()),
+                    };
+                    if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                        warn!("Failed to send !guildlog response to bridge: {}", e);
+                    }
+                    return;
+                }
+                let query = handler.request_guild_event_log(Some((reply_channel, count)));
+                if let Err(e) = connection.send(query.into()).await {
+                    warn!("Failed to send guild event log query to WoW: {}", e);
+                }
+            }
+            BridgeCommand::GuildNote {
+                name,
+                officer,
+                reply_channel,
+            } => {
+                let content = match handler.search_guild_member(&name) {
+                    Some(member) => CommandResponseData::GuildNote(
+                        member.name,
+                        member.note,
+                        officer.then_some(member.officer_note),
+                    ),
+                    None => CommandResponseData::Error(format!("{} is not in the guild.", name)),
+                };
+
+                info!("Processed !note command for channel {}", reply_channel);
+
+                let cmd_response = CommandResponse {
+                    channel_id: reply_channel,
+                    content,
+                };
+                if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                    warn!("Failed to send !note response to bridge: {}", e);
+                }
+            }
+            BridgeCommand::SetGuildNote {
+                name,
+                note,
+                officer,
+                reply_channel,
+            } => match handler.request_set_guild_note(&name, &note, officer, reply_channel) {
+                Ok(request) => {
+                    if let Err(e) = connection.send(request.into()).await {
+                        warn!("Failed to send guild note to WoW: {}", e);
+                    } else {
+                        info!("Sent note change for {} for !note set", name);
+                        // Not every server sends the roster back on its own
+                        let roster_req = handler.request_guild_roster();
+                        if let Err(e) = connection.send(roster_req.into()).await {
+                            warn!("Failed to send guild roster request: {}", e);
+                        }
+                    }
+                }
+                Err(e) => {
+                    let cmd_response = CommandResponse {
+                        channel_id: reply_channel,
+                        content: CommandResponseData::Error(e.to_string()),
+                    };
+                    if let Err(e) = self.channels.command_response_tx.send(cmd_response) {
+                        warn!("Failed to send !note response to bridge: {}", e);
+                    }
+                }
+            },
             BridgeCommand::Gmotd { reply_channel } => {
                 let motd = handler.get_guild_motd().map(|s| s.to_string());
                 let guild_name = handler.guild_info.as_ref().map(|g| g.name.clone());
@@ -750,7 +1816,7 @@ mod tests {
         let config = make_test_config();
         let session = make_test_session();
         let channels = ChannelBundle::new();
-        let mut client = GameClient::new(config, channels.game, Vec::new());
+        let mut client = GameClient::new(config, channels.game, Vec::new(), None, PendingMessageStats::default());
 
         let (client_stream, mut server_stream) = tokio::io::duplex(4096);
 
@@ -3,9 +3,17 @@
 //! This module contains:
 //! - Message formatting with placeholder substitution
 //! - Game client implementation
+//! - Guild roster diffing
+//! - Online/offline notification aggregation
+//! - Guild member activity tracking
+//! - Persistent player name cache
 
+pub mod activity;
 pub mod client;
 pub mod formatter;
+pub mod name_cache;
+pub mod presence;
+pub mod roster_diff;
 
 // Re-export commonly used types
 pub use client::GameClient;
@@ -0,0 +1,172 @@
+//! Persistent player name cache.
+//!
+//! Saves the GUID -> name cache to a JSON file so names resolved in earlier
+//! sessions are known right after a reconnect or restart, without a
+//! CMSG_NAME_QUERY round trip for every first message.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::common::resources::{Class, Race};
+use crate::common::types::Player;
+
+/// Entries saved longer ago than this are dropped on load (and queried again),
+/// so renamed characters do not keep their old name forever.
+const MAX_AGE: u64 = 30 * 24 * 60 * 60;
+
+/// One cached player as stored on disk.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedName {
+    guid: u64,
+    name: String,
+    #[serde(default)]
+    race: u8,
+    #[serde(default)]
+    class: u8,
+    /// Last known level, 0 if never seen.
+    #[serde(default)]
+    level: u8,
+    /// When the entry was first saved (unix seconds).
+    seen: u64,
+}
+
+/// Name cache file.
+#[derive(Debug)]
+pub struct NameCacheFile {
+    path: PathBuf,
+    /// When each saved GUID was first seen.
+    seen: HashMap<u64, u64>,
+}
+
+impl NameCacheFile {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Read the saved players, oldest first. A missing or unreadable file gives none.
+    pub fn load(&mut self, now: u64) -> Vec<Player> {
+        let entries: Vec<CachedName> = match std::fs::read_to_string(&self.path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring unreadable name cache {}: {}",
+                    self.path.display(),
+                    e
+                );
+                Vec::new()
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => {
+                warn!("Failed to read name cache {}: {}", self.path.display(), e);
+                Vec::new()
+            }
+        };
+
+        self.seen.clear();
+        let players: Vec<Player> = entries
+            .into_iter()
+            .filter(|e| now.saturating_sub(e.seen) < MAX_AGE)
+            .map(|e| {
+                self.seen.insert(e.guid, e.seen);
+                Player {
+                    guid: e.guid,
+                    name: e.name,
+                    level: e.level,
+                    class: Class::from_id(e.class),
+                    race: Race::from_id(e.race),
+                    zone_id: 0,
+                }
+            })
+            .collect();
+        debug!("Loaded {} cached player names", players.len());
+        players
+    }
+
+    /// Write the cached players, oldest first.
+    pub fn save(&mut self, players: &[Player], now: u64) {
+        let seen: HashMap<u64, u64> = players
+            .iter()
+            .map(|p| (p.guid, self.seen.get(&p.guid).copied().unwrap_or(now)))
+            .collect();
+        let entries: Vec<CachedName> = players
+            .iter()
+            .map(|p| CachedName {
+                guid: p.guid,
+                name: p.name.clone(),
+                race: p.race.map_or(0, |r| r as u8),
+                class: p.class.map_or(0, |c| c as u8),
+                level: p.level,
+                seen: seen[&p.guid],
+            })
+            .collect();
+
+        let result = serde_json::to_string(&entries)
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                // Write to a temporary file first so a crash never leaves a truncated cache
+                let tmp = self.path.with_extension("tmp");
+                std::fs::write(&tmp, json)?;
+                std::fs::rename(&tmp, &self.path)
+            });
+        match result {
+            Ok(()) => {
+                debug!("Saved {} cached player names", entries.len());
+                self.seen = seen;
+            }
+            Err(e) => warn!("Failed to save name cache {}: {}", self.path.display(), e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(guid: u64, name: &str) -> Player {
+        Player {
+            guid,
+            name: name.to_string(),
+            level: 80,
+            class: Some(Class::Shaman),
+            race: Some(Race::Orc),
+            zone_id: 0,
+        }
+    }
+
+    fn names(players: &[Player]) -> Vec<&str> {
+        players.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_name_cache_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("innkeeper-names-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let start = 1_717_372_800;
+
+        let mut cache = NameCacheFile::new(&path);
+        assert!(cache.load(start).is_empty());
+        cache.save(&[player(1, "Thrall")], start);
+
+        // Jaina is saved later, so she outlives Thrall
+        let later = start + MAX_AGE - 10;
+        let mut cache = NameCacheFile::new(&path);
+        let loaded = cache.load(later);
+        assert_eq!(names(&loaded), vec!["Thrall"]);
+        assert_eq!(loaded[0].class, Some(Class::Shaman));
+        assert_eq!(loaded[0].race, Some(Race::Orc));
+        assert_eq!(loaded[0].level, 80);
+        cache.save(&[player(1, "Thrall"), player(2, "Jaina")], later);
+
+        let mut cache = NameCacheFile::new(&path);
+        let loaded = cache.load(start + MAX_AGE);
+        assert_eq!(names(&loaded), vec!["Jaina"]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
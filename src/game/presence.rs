@@ -0,0 +1,231 @@
+//! Online/offline notification aggregation.
+//!
+//! Holds guild online/offline events briefly so that a quick relog cancels out
+//! and a burst of logins (e.g. after a server restart) goes out as one message.
+
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
+use crate::common::messages::GuildEventInfo;
+
+/// Players named in a batched event before the rest are counted.
+const MAX_NAMED_PLAYERS: usize = 2;
+
+/// Events are sent after this many windows even if new ones keep arriving.
+const MAX_DELAY_WINDOWS: u32 = 4;
+
+/// Online/offline events waiting for a quiet period.
+#[derive(Debug)]
+pub struct PresenceAggregator {
+    window: Duration,
+    /// Events in arrival order, at most one per player.
+    pending: Vec<GuildEventInfo>,
+    /// When the oldest pending event arrived.
+    first: Option<Instant>,
+    /// When the newest pending event arrived.
+    last: Option<Instant>,
+}
+
+impl PresenceAggregator {
+    /// Create an aggregator. A zero window disables aggregation.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: Vec::new(),
+            first: None,
+            last: None,
+        }
+    }
+
+    /// Check if an event is an online/offline event.
+    pub fn handles(event: &GuildEventInfo) -> bool {
+        event.event_name == "online" || event.event_name == "offline"
+    }
+
+    /// Add an online/offline event.
+    ///
+    /// Returns the event back when aggregation is disabled.
+    pub fn push(&mut self, event: GuildEventInfo, now: Instant) -> Option<GuildEventInfo> {
+        if self.window.is_zero() {
+            return Some(event);
+        }
+
+        match self
+            .pending
+            .iter()
+            .position(|e| e.player_name == event.player_name)
+        {
+            // Going offline and back online (or the reverse) within the window cancels out
+            Some(i) if self.pending[i].event_name != event.event_name => {
+                debug!(player = %event.player_name, "Suppressed online/offline flap");
+                self.pending.remove(i);
+            }
+            Some(_) => {}
+            None => self.pending.push(event),
+        }
+
+        self.first.get_or_insert(now);
+        self.last = Some(now);
+        None
+    }
+
+    /// Take the batched events once no event arrived for a full window.
+    /// Returns (event, number of players it names) pairs.
+    pub fn take_due(&mut self, now: Instant) -> Vec<(GuildEventInfo, usize)> {
+        let (Some(first), Some(last)) = (self.first, self.last) else {
+            return Vec::new();
+        };
+
+        let quiet = now.duration_since(last) >= self.window;
+        let overdue = now.duration_since(first) >= self.window * MAX_DELAY_WINDOWS;
+        if quiet || overdue {
+            self.flush()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Take all pending events, batched into one event per kind.
+    /// Returns (event, number of players it names) pairs.
+    pub fn flush(&mut self) -> Vec<(GuildEventInfo, usize)> {
+        self.first = None;
+        self.last = None;
+        let pending = std::mem::take(&mut self.pending);
+
+        ["online", "offline"]
+            .into_iter()
+            .filter_map(|kind| {
+                let mut events: Vec<GuildEventInfo> = pending
+                    .iter()
+                    .filter(|e| e.event_name == kind)
+                    .cloned()
+                    .collect();
+                match events.len() {
+                    0 => None,
+                    1 => events.pop().map(|e| (e, 1)),
+                    count => {
+                        let names: Vec<&str> =
+                            events.iter().map(|e| e.player_name.as_str()).collect();
+                        let event = GuildEventInfo {
+                            event_name: kind.to_string(),
+                            player_name: join_names(&names),
+                            target_name: None,
+                            rank_name: None,
+                            achievement_id: None,
+                        };
+                        Some((event, count))
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Join player names as "Alice, Bob and Carol" or "Alice, Bob and 12 others".
+fn join_names(names: &[&str]) -> String {
+    match names {
+        [] => String::new(),
+        [name] => name.to_string(),
+        _ if names.len() <= MAX_NAMED_PLAYERS + 1 => {
+            let (last, rest) = names.split_last().unwrap();
+            format!("{} and {}", rest.join(", "), last)
+        }
+        _ => format!(
+            "{} and {} others",
+            names[..MAX_NAMED_PLAYERS].join(", "),
+            names.len() - MAX_NAMED_PLAYERS
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event_name: &str, player_name: &str) -> GuildEventInfo {
+        GuildEventInfo {
+            event_name: event_name.to_string(),
+            player_name: player_name.to_string(),
+            target_name: None,
+            rank_name: None,
+            achievement_id: None,
+        }
+    }
+
+    fn summary(events: &[(GuildEventInfo, usize)]) -> Vec<(&str, &str, usize)> {
+        events
+            .iter()
+            .map(|(e, count)| (e.event_name.as_str(), e.player_name.as_str(), *count))
+            .collect()
+    }
+
+    #[test]
+    fn test_join_names() {
+        assert_eq!(join_names(&["Alice"]), "Alice");
+        assert_eq!(join_names(&["Alice", "Bob"]), "Alice and Bob");
+        assert_eq!(
+            join_names(&["Alice", "Bob", "Carol"]),
+            "Alice, Bob and Carol"
+        );
+        assert_eq!(
+            join_names(&["Alice", "Bob", "Carol", "Dave"]),
+            "Alice, Bob and 2 others"
+        );
+    }
+
+    #[test]
+    fn test_presence_batching() {
+        let window = Duration::from_secs(30);
+        let start = Instant::now();
+        let mut presence = PresenceAggregator::new(window);
+
+        assert!(presence.push(event("online", "Alice"), start).is_none());
+        assert!(presence.push(event("online", "Bob"), start).is_none());
+        assert!(presence.push(event("online", "Carol"), start).is_none());
+        assert!(presence.push(event("offline", "Dave"), start).is_none());
+
+        assert!(presence
+            .take_due(start + Duration::from_secs(10))
+            .is_empty());
+        assert_eq!(
+            summary(&presence.take_due(start + window)),
+            vec![
+                ("online", "Alice, Bob and Carol", 3),
+                ("offline", "Dave", 1)
+            ]
+        );
+        assert!(presence.take_due(start + window * 2).is_empty());
+    }
+
+    #[test]
+    fn test_presence_flap_suppressed() {
+        let window = Duration::from_secs(30);
+        let start = Instant::now();
+        let mut presence = PresenceAggregator::new(window);
+
+        presence.push(event("offline", "Alice"), start);
+        presence.push(event("online", "Alice"), start + Duration::from_secs(20));
+        assert!(presence.take_due(start + window * 2).is_empty());
+    }
+
+    #[test]
+    fn test_presence_overdue_and_disabled() {
+        let window = Duration::from_secs(30);
+        let start = Instant::now();
+        let mut presence = PresenceAggregator::new(window);
+
+        // A steady trickle is still sent after MAX_DELAY_WINDOWS
+        for i in 0..5 {
+            let name = format!("Player{}", i);
+            presence.push(event("online", &name), start + Duration::from_secs(25 * i));
+        }
+        assert_eq!(
+            summary(&presence.take_due(start + window * MAX_DELAY_WINDOWS)),
+            vec![("online", "Player0, Player1 and 3 others", 5)]
+        );
+
+        let mut presence = PresenceAggregator::new(Duration::ZERO);
+        assert!(presence.push(event("online", "Alice"), start).is_some());
+    }
+}
@@ -0,0 +1,165 @@
+//! Guild roster diffing.
+//!
+//! Compares successive guild roster snapshots and turns the differences into
+//! guild events: level-ups, rank, zone and note changes, and members that
+//! appear in or disappear from the roster.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::common::messages::GuildEventInfo;
+use crate::common::resources::get_zone_name;
+use crate::common::types::{Guid, GuildMember};
+
+/// Compare two roster snapshots.
+///
+/// `announced` holds the lowercase names of members whose join, departure or
+/// rank change a server guild event already reported; they get no `first_seen`,
+/// `gone` or `rank_changed` event.
+///
+/// Returns (event, %message content) pairs, sorted by member name.
+pub fn diff_roster(
+    old: &HashMap<Guid, GuildMember>,
+    new: &HashMap<Guid, GuildMember>,
+    announced: &HashSet<String>,
+) -> Vec<(GuildEventInfo, String)> {
+    let mut changes = Vec::new();
+    let is_announced = |member: &GuildMember| announced.contains(&member.name.to_lowercase());
+
+    for (guid, member) in new {
+        let Some(previous) = old.get(guid) else {
+            if !is_announced(member) {
+                changes.push(event("first_seen", member, String::new()));
+            }
+            continue;
+        };
+
+        if member.level > previous.level {
+            changes.push(event("level_up", member, member.level.to_string()));
+        }
+        if member.rank != previous.rank && !is_announced(member) {
+            changes.push(event("rank_changed", member, String::new()));
+        }
+        // Offline members report the zone they logged out in
+        if member.online && previous.online && member.zone_id != previous.zone_id {
+            changes.push(event(
+                "zone_changed",
+                member,
+                get_zone_name(member.zone_id).to_string(),
+            ));
+        }
+        if member.note != previous.note {
+            changes.push(event("note_changed", member, member.note.clone()));
+        }
+    }
+
+    for (guid, member) in old {
+        if !new.contains_key(guid) && !is_announced(member) {
+            changes.push(event("gone", member, String::new()));
+        }
+    }
+
+    changes.sort_by(|(a, _), (b, _)| a.player_name.cmp(&b.player_name));
+    changes
+}
+
+fn event(event_name: &str, member: &GuildMember, content: String) -> (GuildEventInfo, String) {
+    let info = GuildEventInfo {
+        event_name: event_name.to_string(),
+        player_name: member.name.clone(),
+        target_name: None,
+        rank_name: Some(member.rank_name.clone()),
+        achievement_id: None,
+    };
+    (info, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(guid: Guid, name: &str, level: u8, rank: u8) -> GuildMember {
+        GuildMember {
+            guid,
+            name: name.to_string(),
+            level,
+            class: None,
+            rank,
+            rank_name: format!("Rank {}", rank),
+            zone_id: 0,
+            online: false,
+            last_logoff: 0.0,
+            note: String::new(),
+            officer_note: String::new(),
+        }
+    }
+
+    fn names(changes: &[(GuildEventInfo, String)]) -> Vec<(&str, &str)> {
+        changes
+            .iter()
+            .map(|(e, _)| (e.event_name.as_str(), e.player_name.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_roster() {
+        let old = HashMap::from([
+            (1, member(1, "Thrall", 79, 2)),
+            (2, member(2, "Jaina", 80, 1)),
+            (3, member(3, "Arthas", 80, 3)),
+        ]);
+        let mut thrall = member(1, "Thrall", 80, 1);
+        thrall.note = "Main".to_string();
+        let new = HashMap::from([
+            (1, thrall),
+            (2, member(2, "Jaina", 80, 1)),
+            (4, member(4, "Sylvanas", 12, 4)),
+        ]);
+
+        let changes = diff_roster(&old, &new, &HashSet::new());
+        assert_eq!(
+            names(&changes),
+            vec![
+                ("gone", "Arthas"),
+                ("first_seen", "Sylvanas"),
+                ("level_up", "Thrall"),
+                ("rank_changed", "Thrall"),
+                ("note_changed", "Thrall"),
+            ]
+        );
+        assert_eq!(changes[2].1, "80");
+        assert_eq!(changes[3].0.rank_name.as_deref(), Some("Rank 1"));
+        assert_eq!(changes[4].1, "Main");
+
+        // Changes a server guild event already announced are not repeated
+        let announced = HashSet::from(["arthas".to_string(), "thrall".to_string()]);
+        assert_eq!(
+            names(&diff_roster(&old, &new, &announced)),
+            vec![
+                ("first_seen", "Sylvanas"),
+                ("level_up", "Thrall"),
+                ("note_changed", "Thrall"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_roster_zone_only_when_online() {
+        let mut before = member(1, "Thrall", 80, 1);
+        before.zone_id = 1637;
+        let mut after = before.clone();
+        after.zone_id = 1519;
+
+        let old = HashMap::from([(1, before.clone())]);
+        let new = HashMap::from([(1, after.clone())]);
+        assert!(diff_roster(&old, &new, &HashSet::new()).is_empty());
+
+        before.online = true;
+        after.online = true;
+        let old = HashMap::from([(1, before)]);
+        let new = HashMap::from([(1, after)]);
+        assert_eq!(
+            names(&diff_roster(&old, &new, &HashSet::new())),
+            vec![("zone_changed", "Thrall")]
+        );
+    }
+}
@@ -23,6 +23,7 @@ use discord::{
     DiscordBotBuilder, DiscordChannels, WowCommand,
 };
 use game::GameClient;
+use protocol::game::handler::PendingMessageStats;
 use protocol::realm::connector::connect_and_authenticate;
 
 #[tokio::main]
@@ -70,6 +71,8 @@ async fn main() -> Result<()> {
         cmd_response_rx: channels.discord.cmd_response_rx,
         status_rx: channels.discord.status_rx,
         dashboard_rx: channels.discord.dashboard_rx,
+        channel_event_rx: channels.discord.channel_event_rx,
+        social_notice_rx: channels.discord.social_notice_rx,
         shutdown_rx: channels.game.shutdown_rx.clone(),
     };
 
@@ -96,6 +99,7 @@ async fn main() -> Result<()> {
     // Task 2: Discord commands -> Bridge commands converter
     let command_converter = {
         let cmd_tx = channels.discord.command_tx;
+        let router = bridge.router();
         tokio::spawn(async move {
             while let Some(cmd) = discord_command_rx.recv().await {
                 let bridge_cmd = match cmd {
@@ -105,6 +109,65 @@ async fn main() -> Result<()> {
                     WowCommand::GuildMotd { reply_channel } => {
                         BridgeCommand::Gmotd { reply_channel }
                     }
+                    WowCommand::SetGuildMotd { text, reply_channel } => {
+                        BridgeCommand::SetGuildMotd { text, reply_channel }
+                    }
+                    WowCommand::GuildInfo { reply_channel } => {
+                        BridgeCommand::GuildInfo { reply_channel }
+                    }
+                    WowCommand::SetGuildInfo { text, reply_channel } => {
+                        BridgeCommand::SetGuildInfo { text, reply_channel }
+                    }
+                    WowCommand::GuildLog { count, reply_channel } => {
+                        BridgeCommand::GuildLog { count, reply_channel }
+                    }
+                    WowCommand::Inactive { days, rank, reply_channel } => {
+                        BridgeCommand::Inactive { days, rank, reply_channel }
+                    }
+                    WowCommand::ExportRoster { format, reply_channel } => {
+                        BridgeCommand::ExportRoster { format, reply_channel }
+                    }
+                    WowCommand::Activity { name, reply_channel } => {
+                        BridgeCommand::Activity { name, reply_channel }
+                    }
+                    WowCommand::TopPlaytime { reply_channel } => {
+                        BridgeCommand::TopPlaytime { reply_channel }
+                    }
+                    WowCommand::GuildNote { name, officer, reply_channel } => {
+                        BridgeCommand::GuildNote { name, officer, reply_channel }
+                    }
+                    WowCommand::SetGuildNote { name, note, officer, reply_channel } => {
+                        BridgeCommand::SetGuildNote { name, note, officer, reply_channel }
+                    }
+                    WowCommand::Nearby { reply_channel } => {
+                        BridgeCommand::Nearby { reply_channel }
+                    }
+                    WowCommand::Status { reply_channel } => {
+                        BridgeCommand::Status { reply_channel }
+                    }
+                    WowCommand::ChannelJoin { name, password, reply_channel } => {
+                        // Fall back to the password configured for this channel, if any
+                        let password = password.or_else(|| router.get_channel_password(&name));
+                        BridgeCommand::ChannelJoin { name, password, reply_channel }
+                    }
+                    WowCommand::ChannelLeave { name, reply_channel } => {
+                        BridgeCommand::ChannelLeave { name, reply_channel }
+                    }
+                    WowCommand::ChannelModerate { name, action, reply_channel } => {
+                        BridgeCommand::ChannelModerate { name, action, reply_channel }
+                    }
+                    WowCommand::Members { channel_name, reply_channel } => {
+                        BridgeCommand::Members { channel_name, reply_channel }
+                    }
+                    WowCommand::GroupLeave { reply_channel } => {
+                        BridgeCommand::GroupLeave { reply_channel }
+                    }
+                    WowCommand::GroupDisband { reply_channel } => {
+                        BridgeCommand::GroupDisband { reply_channel }
+                    }
+                    WowCommand::GuildMember { action, name, reply_channel } => {
+                        BridgeCommand::GuildMember { action, name, reply_channel }
+                    }
                 };
 
                 if let Err(e) = cmd_tx.send(bridge_cmd) {
@@ -143,7 +206,11 @@ async fn main() -> Result<()> {
     }
 
     // Game client task
-    let channels_to_join = bridge.channels_to_join();
+    let mut channels_to_join = bridge.channels_to_join();
+    // Guild event log as last seen, to post events missed while reconnecting
+    let mut guild_log = None;
+    // Chat relayed late or dropped while waiting for sender names, across reconnects
+    let mut pending_message_stats = PendingMessageStats::default();
     let realm_host = realm_host.to_string();
     let config_clone = config.clone();
     let shutdown_tx = channels.control.shutdown_tx;
@@ -206,8 +273,12 @@ async fn main() -> Result<()> {
                             shutdown_rx: game_channels.shutdown_rx.clone(),
                             status_tx: game_channels.status_tx.clone(),
                             dashboard_tx: game_channels.dashboard_tx.clone(),
+                            channel_event_tx: game_channels.channel_event_tx.clone(),
+                            social_notice_tx: game_channels.social_notice_tx.clone(),
                         },
                         channels_to_join.clone(),
+                        guild_log.take(),
+                        pending_message_stats,
                     );
 
                     match game_client.run(session).await {
@@ -218,6 +289,10 @@ async fn main() -> Result<()> {
                     // After disconnect, extract receivers back
                     outgoing_rx = game_client.channels.outgoing_wow_rx;
                     command_rx = game_client.channels.command_rx;
+                    // Keep channels joined/left via !channel for the next login
+                    channels_to_join = game_client.custom_channels;
+                    guild_log = game_client.guild_log;
+                    pending_message_stats = game_client.pending_message_stats;
                 }
                 Err(e) => {
                     error!("Realm authentication failed: {}", e);
@@ -252,8 +327,38 @@ async fn main() -> Result<()> {
                     // Drain commands with error response while waiting
                     cmd = command_rx.recv() => {
                         match cmd {
+                            Some(BridgeCommand::Status { reply_channel }) => {
+                                let status_response = discord::commands::CommandResponse {
+                                    channel_id: reply_channel,
+                                    content: common::messages::CommandResponseData::String(format!(
+                                        "Not connected to WoW, reconnecting.\n{}",
+                                        pending_message_stats.summary()
+                                    )),
+                                };
+                                if let Err(e) = cmd_response_tx.send(status_response) {
+                                    warn!("Failed to send command response: {}", e);
+                                }
+                            }
                             Some(BridgeCommand::Who { reply_channel, .. }) |
-                            Some(BridgeCommand::Gmotd { reply_channel }) => {
+                            Some(BridgeCommand::Gmotd { reply_channel }) |
+                            Some(BridgeCommand::SetGuildMotd { reply_channel, .. }) |
+                            Some(BridgeCommand::GuildInfo { reply_channel }) |
+                            Some(BridgeCommand::SetGuildInfo { reply_channel, .. }) |
+                            Some(BridgeCommand::GuildLog { reply_channel, .. }) |
+                            Some(BridgeCommand::Inactive { reply_channel, .. }) |
+                            Some(BridgeCommand::ExportRoster { reply_channel, .. }) |
+                            Some(BridgeCommand::Activity { reply_channel, .. }) |
+                            Some(BridgeCommand::TopPlaytime { reply_channel }) |
+                            Some(BridgeCommand::GuildNote { reply_channel, .. }) |
+                            Some(BridgeCommand::SetGuildNote { reply_channel, .. }) |
+                            Some(BridgeCommand::Nearby { reply_channel }) |
+                            Some(BridgeCommand::ChannelJoin { reply_channel, .. }) |
+                            Some(BridgeCommand::ChannelLeave { reply_channel, .. }) |
+                            Some(BridgeCommand::ChannelModerate { reply_channel, .. }) |
+                            Some(BridgeCommand::Members { reply_channel, .. }) |
+                            Some(BridgeCommand::GroupLeave { reply_channel }) |
+                            Some(BridgeCommand::GroupDisband { reply_channel }) |
+                            Some(BridgeCommand::GuildMember { reply_channel, .. }) => {
                                 let error_response = discord::commands::CommandResponse {
                                     channel_id: reply_channel,
                                     content: common::messages::CommandResponseData::Error(
@@ -4,7 +4,7 @@
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 
-use crate::common::types::{ChatMessage, ChatType, Guid};
+use crate::common::types::{ChannelModeration, ChatMessage, ChatType, Guid};
 use crate::protocol::packets::{
     read_cstring, read_packed_guid, PacketDecode, PacketEncode, MAX_CSTRING_LONG, MAX_CSTRING_SHORT,
 };
@@ -73,6 +73,17 @@ pub mod chat_notify {
     pub const CHAT_VOICE_OFF_NOTICE: u8 = 0x23;
 }
 
+/// Channel member flags (SMSG_CHANNEL_LIST).
+#[allow(dead_code)]
+pub mod channel_member_flags {
+    pub const MEMBER_FLAG_OWNER: u8 = 0x01;
+    pub const MEMBER_FLAG_MODERATOR: u8 = 0x02;
+    pub const MEMBER_FLAG_VOICED: u8 = 0x04;
+    pub const MEMBER_FLAG_MUTED: u8 = 0x08;
+    pub const MEMBER_FLAG_CUSTOM: u8 = 0x10;
+    pub const MEMBER_FLAG_MIC_MUTED: u8 = 0x20;
+}
+
 /// Language IDs for chat messages.
 #[allow(dead_code)]
 pub mod languages {
@@ -136,7 +147,7 @@ pub struct MessageChat {
     pub message_length: u32,
     pub message: String,
     pub chat_tag: u8,
-    /// Achievement ID for guild achievement messages (read after chat tag).
+    /// Achievement ID for achievement messages (read after chat tag).
     pub achievement_id: Option<u32>,
 }
 
@@ -306,13 +317,15 @@ impl MessageChat {
             buf.advance(1);
         }
 
-        // For guild achievement messages, read the achievement ID (4 bytes) after chat tag
-        let achievement_id =
-            if chat_type == chat_events::CHAT_MSG_GUILD_ACHIEVEMENT && buf.remaining() >= 4 {
-                Some(buf.get_u32_le())
-            } else {
-                None
-            };
+        // For achievement messages, read the achievement ID (4 bytes) after chat tag
+        let achievement_id = if (chat_type == chat_events::CHAT_MSG_ACHIEVEMENT
+            || chat_type == chat_events::CHAT_MSG_GUILD_ACHIEVEMENT)
+            && buf.remaining() >= 4
+        {
+            Some(buf.get_u32_le())
+        } else {
+            None
+        };
 
         Ok(MessageChat {
             chat_type,
@@ -373,6 +386,7 @@ impl From<SendChatMessage> for crate::protocol::packets::Packet {
 pub struct JoinChannelWotLK {
     pub channel_id: u32,
     pub channel_name: String,
+    pub password: String,
 }
 
 impl PacketEncode for JoinChannelWotLK {
@@ -385,7 +399,8 @@ impl PacketEncode for JoinChannelWotLK {
         buf.put_slice(self.channel_name.as_bytes());
         buf.put_u8(0);
 
-        // Password (null-terminated, empty)
+        // Password (null-terminated, may be empty)
+        buf.put_slice(self.password.as_bytes());
         buf.put_u8(0);
     }
 }
@@ -402,11 +417,178 @@ impl From<JoinChannelWotLK> for crate::protocol::packets::Packet {
     }
 }
 
+/// CMSG_LEAVE_CHANNEL packet.
+///
+/// Packet layout: [unk: u32le] [name: cstring]
+#[derive(Debug, Clone)]
+pub struct LeaveChannel {
+    pub channel_name: String,
+}
+
+impl PacketEncode for LeaveChannel {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(0); // unknown, always 0
+        buf.put_slice(self.channel_name.as_bytes());
+        buf.put_u8(0);
+    }
+}
+
+impl From<LeaveChannel> for crate::protocol::packets::Packet {
+    fn from(leave: LeaveChannel) -> Self {
+        let mut buf = BytesMut::new();
+        leave.encode(&mut buf);
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_LEAVE_CHANNEL,
+            buf.freeze(),
+        )
+    }
+}
+
+/// CMSG_CHANNEL_LIST packet.
+///
+/// Packet layout: [name: cstring]
+#[derive(Debug, Clone)]
+pub struct ChannelListRequest {
+    pub channel_name: String,
+}
+
+impl PacketEncode for ChannelListRequest {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_slice(self.channel_name.as_bytes());
+        buf.put_u8(0);
+    }
+}
+
+impl From<ChannelListRequest> for crate::protocol::packets::Packet {
+    fn from(req: ChannelListRequest) -> Self {
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_CHANNEL_LIST,
+            buf.freeze(),
+        )
+    }
+}
+
+/// CMSG_CHANNEL_KICK/BAN/UNBAN/PASSWORD/SET_OWNER/MUTE/UNMUTE/MODERATE/ANNOUNCEMENTS packet.
+///
+/// Packet layout: [name: cstring] [argument: cstring] (argument omitted for toggles)
+#[derive(Debug, Clone)]
+pub struct ChannelModerate {
+    pub channel_name: String,
+    pub action: ChannelModeration,
+}
+
+impl ChannelModerate {
+    /// Opcode for the moderation action.
+    pub fn opcode(&self) -> u16 {
+        use crate::protocol::packets::opcodes::*;
+        match self.action {
+            ChannelModeration::Kick(_) => CMSG_CHANNEL_KICK,
+            ChannelModeration::Ban(_) => CMSG_CHANNEL_BAN,
+            ChannelModeration::Unban(_) => CMSG_CHANNEL_UNBAN,
+            ChannelModeration::Password(_) => CMSG_CHANNEL_PASSWORD,
+            ChannelModeration::SetOwner(_) => CMSG_CHANNEL_SET_OWNER,
+            ChannelModeration::Mute(_) => CMSG_CHANNEL_MUTE,
+            ChannelModeration::Unmute(_) => CMSG_CHANNEL_UNMUTE,
+            ChannelModeration::Moderate => CMSG_CHANNEL_MODERATE,
+            ChannelModeration::Announcements => CMSG_CHANNEL_ANNOUNCEMENTS,
+        }
+    }
+}
+
+impl PacketEncode for ChannelModerate {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_slice(self.channel_name.as_bytes());
+        buf.put_u8(0);
+
+        match &self.action {
+            ChannelModeration::Kick(arg)
+            | ChannelModeration::Ban(arg)
+            | ChannelModeration::Unban(arg)
+            | ChannelModeration::Password(arg)
+            | ChannelModeration::SetOwner(arg)
+            | ChannelModeration::Mute(arg)
+            | ChannelModeration::Unmute(arg) => {
+                buf.put_slice(arg.as_bytes());
+                buf.put_u8(0);
+            }
+            ChannelModeration::Moderate | ChannelModeration::Announcements => {}
+        }
+    }
+}
+
+impl From<ChannelModerate> for crate::protocol::packets::Packet {
+    fn from(moderate: ChannelModerate) -> Self {
+        let mut buf = BytesMut::new();
+        moderate.encode(&mut buf);
+        crate::protocol::packets::Packet::new(moderate.opcode(), buf.freeze())
+    }
+}
+
+/// SMSG_CHANNEL_LIST packet data.
+///
+/// Packet layout: [type: u8] [name: cstring] [channel_flags: u8] [count: u32le]
+/// followed by `count` x [guid: u64le] [member_flags: u8]
+#[derive(Debug, Clone)]
+pub struct ChannelList {
+    pub channel_name: String,
+    /// (guid, member flags) pairs.
+    pub members: Vec<(Guid, u8)>,
+}
+
+impl PacketDecode for ChannelList {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 1 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                1,
+                buf.remaining()
+            ));
+        }
+
+        let _list_type = buf.get_u8();
+        let channel_name = read_cstring(buf, MAX_CSTRING_SHORT)?;
+
+        if buf.remaining() < 5 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                5,
+                buf.remaining()
+            ));
+        }
+        buf.advance(1); // Channel flags
+        let count = buf.get_u32_le() as usize;
+
+        if buf.remaining() < count * 9 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                count * 9,
+                buf.remaining()
+            ));
+        }
+        let members = (0..count)
+            .map(|_| (buf.get_u64_le(), buf.get_u8()))
+            .collect();
+
+        Ok(ChannelList {
+            channel_name,
+            members,
+        })
+    }
+}
+
 /// SMSG_CHANNEL_NOTIFY packet data.
 #[derive(Debug, Clone)]
 pub struct ChannelNotify {
     pub notify_type: u8,
     pub channel_name: String,
+    /// Affected player for kick/ban notices.
+    pub player_guid: Option<Guid>,
+    /// Player name for not-found/not-banned/owner notices.
+    pub player_name: Option<String>,
 }
 
 impl ChannelNotify {
@@ -422,6 +604,9 @@ impl ChannelNotify {
             chat_notify::CHAT_WRONG_PASSWORD_NOTICE => {
                 format!("Wrong password for channel: {}", self.channel_name)
             }
+            chat_notify::CHAT_NOT_MEMBER_NOTICE => {
+                format!("Not on channel: {}", self.channel_name)
+            }
             chat_notify::CHAT_MUTED_NOTICE => {
                 format!(
                     "[{}] You do not have permission to speak",
@@ -431,6 +616,57 @@ impl ChannelNotify {
             chat_notify::CHAT_BANNED_NOTICE => {
                 format!("[{}] You are banned from that channel", self.channel_name)
             }
+            chat_notify::CHAT_PLAYER_KICKED_NOTICE => {
+                format!("[{}] Player kicked from channel", self.channel_name)
+            }
+            chat_notify::CHAT_PLAYER_BANNED_NOTICE => {
+                format!("[{}] Player banned from channel", self.channel_name)
+            }
+            chat_notify::CHAT_PLAYER_UNBANNED_NOTICE => {
+                format!("[{}] Player unbanned from channel", self.channel_name)
+            }
+            chat_notify::CHAT_PLAYER_NOT_BANNED_NOTICE => format!(
+                "[{}] {} is not banned",
+                self.channel_name,
+                self.player_name.as_deref().unwrap_or("Player")
+            ),
+            chat_notify::CHAT_PLAYER_NOT_FOUND_NOTICE => format!(
+                "[{}] Player {} not found",
+                self.channel_name,
+                self.player_name.as_deref().unwrap_or_default()
+            ),
+            chat_notify::CHAT_CHANNEL_OWNER_NOTICE => format!(
+                "[{}] Channel owner is {}",
+                self.channel_name,
+                self.player_name.as_deref().unwrap_or("nobody")
+            ),
+            chat_notify::CHAT_PASSWORD_CHANGED_NOTICE => {
+                format!("[{}] Password changed", self.channel_name)
+            }
+            chat_notify::CHAT_OWNER_CHANGED_NOTICE => {
+                format!("[{}] Owner changed", self.channel_name)
+            }
+            chat_notify::CHAT_NOT_MODERATOR_NOTICE => {
+                format!("[{}] You are not a moderator", self.channel_name)
+            }
+            chat_notify::CHAT_NOT_OWNER_NOTICE => {
+                format!("[{}] You are not the channel owner", self.channel_name)
+            }
+            chat_notify::CHAT_MODE_CHANGE_NOTICE => {
+                format!("[{}] Player mode changed", self.channel_name)
+            }
+            chat_notify::CHAT_ANNOUNCEMENTS_ON_NOTICE => {
+                format!("[{}] Channel announcements enabled", self.channel_name)
+            }
+            chat_notify::CHAT_ANNOUNCEMENTS_OFF_NOTICE => {
+                format!("[{}] Channel announcements disabled", self.channel_name)
+            }
+            chat_notify::CHAT_MODERATION_ON_NOTICE => {
+                format!("[{}] Channel moderation enabled", self.channel_name)
+            }
+            chat_notify::CHAT_MODERATION_OFF_NOTICE => {
+                format!("[{}] Channel moderation disabled", self.channel_name)
+            }
             chat_notify::CHAT_PLAYER_ALREADY_MEMBER_NOTICE => {
                 format!("[{}] already joined!", self.channel_name)
             }
@@ -474,9 +710,32 @@ impl PacketDecode for ChannelNotify {
         let notify_type = buf.get_u8();
         let channel_name = read_cstring(buf, MAX_CSTRING_SHORT)?;
 
+        // Kick/ban notices carry the victim GUID followed by the moderator GUID
+        let player_guid = match notify_type {
+            chat_notify::CHAT_PLAYER_KICKED_NOTICE | chat_notify::CHAT_PLAYER_BANNED_NOTICE
+                if buf.remaining() >= 8 =>
+            {
+                Some(buf.get_u64_le())
+            }
+            _ => None,
+        };
+
+        let player_name = match notify_type {
+            chat_notify::CHAT_PLAYER_NOT_FOUND_NOTICE
+            | chat_notify::CHAT_PLAYER_NOT_BANNED_NOTICE
+            | chat_notify::CHAT_CHANNEL_OWNER_NOTICE
+                if buf.has_remaining() =>
+            {
+                Some(read_cstring(buf, MAX_CSTRING_SHORT)?)
+            }
+            _ => None,
+        };
+
         Ok(ChannelNotify {
             notify_type,
             channel_name,
+            player_guid,
+            player_name,
         })
     }
 }
@@ -744,6 +1003,7 @@ mod tests {
         let join = JoinChannelWotLK {
             channel_id: 0,
             channel_name: "World".to_string(),
+            password: String::new(),
         };
 
         let mut buf = BytesMut::new();
@@ -752,4 +1012,95 @@ mod tests {
         // 4 bytes channel_id + 1 has_voice + 1 unknown + 5 bytes "World" + 1 null + 1 null = 13
         assert_eq!(buf.len(), 13);
     }
+
+    #[test]
+    fn test_leave_channel_encode() {
+        let leave = LeaveChannel {
+            channel_name: "World".to_string(),
+        };
+
+        let mut buf = BytesMut::new();
+        leave.encode(&mut buf);
+
+        // 4 bytes unknown + 5 bytes "World" + 1 null = 10
+        assert_eq!(buf.len(), 10);
+        assert_eq!(&buf[4..], b"World\0");
+    }
+
+    #[test]
+    fn test_channel_list_decode() {
+        let mut data = BytesMut::new();
+        data.put_u8(1);
+        data.put_slice(b"RaidTeam\0");
+        data.put_u8(0x01);
+        data.put_u32_le(2);
+        data.put_u64_le(42);
+        data.put_u8(channel_member_flags::MEMBER_FLAG_OWNER);
+        data.put_u64_le(43);
+        data.put_u8(channel_member_flags::MEMBER_FLAG_MUTED);
+
+        let list = ChannelList::decode(&mut data.freeze()).unwrap();
+        assert_eq!(list.channel_name, "RaidTeam");
+        assert_eq!(
+            list.members,
+            vec![
+                (42, channel_member_flags::MEMBER_FLAG_OWNER),
+                (43, channel_member_flags::MEMBER_FLAG_MUTED)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_channel_notify_kicked_decode() {
+        let mut data = BytesMut::new();
+        data.put_u8(chat_notify::CHAT_PLAYER_KICKED_NOTICE);
+        data.put_slice(b"World\0");
+        data.put_u64_le(42); // victim
+        data.put_u64_le(7); // moderator
+
+        let notify = ChannelNotify::decode(&mut data.freeze()).unwrap();
+        assert_eq!(notify.channel_name, "World");
+        assert_eq!(notify.player_guid, Some(42));
+
+        let mut data = BytesMut::new();
+        data.put_u8(chat_notify::CHAT_YOU_JOINED_NOTICE);
+        data.put_slice(b"World\0");
+
+        let notify = ChannelNotify::decode(&mut data.freeze()).unwrap();
+        assert_eq!(notify.player_guid, None);
+    }
+
+    #[test]
+    fn test_channel_moderate_encode() {
+        let kick = ChannelModerate {
+            channel_name: "World".to_string(),
+            action: ChannelModeration::Kick("Thrall".to_string()),
+        };
+        let mut buf = BytesMut::new();
+        kick.encode(&mut buf);
+        assert_eq!(
+            kick.opcode(),
+            crate::protocol::packets::opcodes::CMSG_CHANNEL_KICK
+        );
+        assert_eq!(&buf[..], b"World\0Thrall\0");
+
+        let moderate = ChannelModerate {
+            channel_name: "World".to_string(),
+            action: ChannelModeration::Moderate,
+        };
+        let mut buf = BytesMut::new();
+        moderate.encode(&mut buf);
+        assert_eq!(&buf[..], b"World\0");
+    }
+
+    #[test]
+    fn test_channel_notify_player_name_decode() {
+        let mut data = BytesMut::new();
+        data.put_u8(chat_notify::CHAT_PLAYER_NOT_FOUND_NOTICE);
+        data.put_slice(b"World\0Arthas\0");
+
+        let notify = ChannelNotify::decode(&mut data.freeze()).unwrap();
+        assert_eq!(notify.player_name.as_deref(), Some("Arthas"));
+        assert_eq!(notify.description(), "[World] Player Arthas not found");
+    }
 }
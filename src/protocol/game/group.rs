@@ -0,0 +1,277 @@
+//! Party and raid groups.
+//!
+//! Handles SMSG_GROUP_INVITE and SMSG_GROUP_LIST parsing, and the accept,
+//! decline, leave and uninvite requests.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::common::types::Guid;
+use crate::protocol::packets::{read_cstring, PacketDecode, PacketEncode, MAX_CSTRING_SHORT};
+use anyhow::{anyhow, Result};
+
+/// Group type flags from SMSG_GROUP_LIST.
+#[allow(dead_code)]
+pub mod group_types {
+    pub const GROUPTYPE_NORMAL: u8 = 0x00;
+    pub const GROUPTYPE_BG: u8 = 0x01;
+    pub const GROUPTYPE_RAID: u8 = 0x02;
+    pub const GROUPTYPE_LFG_RESTRICTED: u8 = 0x04;
+    pub const GROUPTYPE_LFG: u8 = 0x08;
+}
+
+/// SMSG_GROUP_INVITE packet.
+#[derive(Debug, Clone)]
+pub struct GroupInvite {
+    /// False when the bot is already in a group (the server has declined for us).
+    pub can_accept: bool,
+    pub inviter: String,
+}
+
+impl PacketDecode for GroupInvite {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 1 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                1,
+                buf.remaining()
+            ));
+        }
+
+        let can_accept = buf.get_u8() != 0;
+        let inviter = read_cstring(buf, MAX_CSTRING_SHORT)?;
+        // Trailing unknown u32, u8 count and u32 are not needed
+
+        Ok(GroupInvite {
+            can_accept,
+            inviter,
+        })
+    }
+}
+
+/// CMSG_GROUP_ACCEPT packet.
+#[derive(Debug, Clone, Default)]
+pub struct GroupAccept;
+
+impl PacketEncode for GroupAccept {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(0); // Roles
+    }
+}
+
+impl From<GroupAccept> for crate::protocol::packets::Packet {
+    fn from(req: GroupAccept) -> Self {
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_GROUP_ACCEPT,
+            buf.freeze(),
+        )
+    }
+}
+
+/// CMSG_GROUP_DECLINE packet (empty payload).
+#[derive(Debug, Clone, Default)]
+pub struct GroupDecline;
+
+impl From<GroupDecline> for crate::protocol::packets::Packet {
+    fn from(_req: GroupDecline) -> Self {
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_GROUP_DECLINE,
+            Bytes::new(),
+        )
+    }
+}
+
+/// CMSG_GROUP_DISBAND packet (empty payload).
+/// Despite the name, this makes the bot leave its current group.
+#[derive(Debug, Clone, Default)]
+pub struct GroupLeave;
+
+impl From<GroupLeave> for crate::protocol::packets::Packet {
+    fn from(_req: GroupLeave) -> Self {
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_GROUP_DISBAND,
+            Bytes::new(),
+        )
+    }
+}
+
+/// CMSG_GROUP_UNINVITE_GUID packet.
+#[derive(Debug, Clone)]
+pub struct GroupUninvite {
+    pub guid: Guid,
+    pub reason: String,
+}
+
+impl PacketEncode for GroupUninvite {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.guid);
+        buf.put_slice(self.reason.as_bytes());
+        buf.put_u8(0);
+    }
+}
+
+impl From<GroupUninvite> for crate::protocol::packets::Packet {
+    fn from(req: GroupUninvite) -> Self {
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_GROUP_UNINVITE_GUID,
+            buf.freeze(),
+        )
+    }
+}
+
+/// A single member entry from SMSG_GROUP_LIST (the bot itself is not listed).
+#[derive(Debug, Clone)]
+pub struct GroupListMember {
+    pub name: String,
+    pub guid: Guid,
+}
+
+/// SMSG_GROUP_LIST packet.
+/// An empty member list means the bot is no longer in a group.
+#[derive(Debug, Clone, Default)]
+pub struct GroupList {
+    pub group_type: u8,
+    pub members: Vec<GroupListMember>,
+    pub leader_guid: Guid,
+}
+
+impl GroupList {
+    pub fn is_raid(&self) -> bool {
+        self.group_type & group_types::GROUPTYPE_RAID != 0
+    }
+}
+
+impl PacketDecode for GroupList {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 4 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                4,
+                buf.remaining()
+            ));
+        }
+
+        let group_type = buf.get_u8();
+        buf.advance(3); // Subgroup, flags, roles
+
+        if group_type & group_types::GROUPTYPE_LFG != 0 {
+            if buf.remaining() < 5 {
+                return Err(anyhow!(
+                    "Packet too short: need {} bytes, got {}",
+                    5,
+                    buf.remaining()
+                ));
+            }
+            buf.advance(5); // LFG state, dungeon entry
+        }
+
+        if buf.remaining() < 16 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                16,
+                buf.remaining()
+            ));
+        }
+        buf.advance(12); // Group GUID, update counter
+        let member_count = buf.get_u32_le();
+
+        let mut members = Vec::with_capacity(member_count.min(40) as usize);
+        for _ in 0..member_count {
+            let name = read_cstring(buf, MAX_CSTRING_SHORT)?;
+
+            if buf.remaining() < 12 {
+                return Err(anyhow!(
+                    "Packet too short: need {} bytes, got {}",
+                    12,
+                    buf.remaining()
+                ));
+            }
+            let guid = buf.get_u64_le();
+            buf.advance(4); // Online status, subgroup, flags, roles
+
+            members.push(GroupListMember { name, guid });
+        }
+
+        if buf.remaining() < 8 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                8,
+                buf.remaining()
+            ));
+        }
+        let leader_guid = buf.get_u64_le();
+        // Loot settings and difficulty follow when the group is not empty
+
+        Ok(GroupList {
+            group_type,
+            members,
+            leader_guid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_invite_decode() {
+        let mut data = BytesMut::new();
+        data.put_u8(1);
+        data.put_slice(b"Thrall\0");
+        data.put_u32_le(0);
+        data.put_u8(0);
+        data.put_u32_le(0);
+
+        let invite = GroupInvite::decode(&mut data.freeze()).unwrap();
+        assert!(invite.can_accept);
+        assert_eq!(invite.inviter, "Thrall");
+    }
+
+    #[test]
+    fn test_group_list_decode() {
+        let mut data = BytesMut::new();
+        data.put_u8(group_types::GROUPTYPE_RAID);
+        data.put_slice(&[0, 0, 0]);
+        data.put_u64_le(0x1F50000000000001);
+        data.put_u32_le(7);
+        data.put_u32_le(2);
+        data.put_slice(b"Thrall\0");
+        data.put_u64_le(42);
+        data.put_slice(&[1, 0, 0, 0]);
+        data.put_slice(b"Jaina\0");
+        data.put_u64_le(43);
+        data.put_slice(&[0, 1, 0, 0]);
+        data.put_u64_le(42);
+        data.put_slice(&[0; 13]);
+
+        let list = GroupList::decode(&mut data.freeze()).unwrap();
+        assert!(list.is_raid());
+        assert_eq!(list.leader_guid, 42);
+        assert_eq!(list.members.len(), 2);
+        assert_eq!(list.members[0].name, "Thrall");
+        assert_eq!(list.members[1].name, "Jaina");
+        assert_eq!(list.members[1].guid, 43);
+    }
+
+    #[test]
+    fn test_group_uninvite_encode() {
+        let req = GroupUninvite {
+            guid: 42,
+            reason: String::new(),
+        };
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+
+        assert_eq!(buf.len(), 9);
+        assert_eq!(buf[0..8], 42u64.to_le_bytes());
+        assert_eq!(buf[8], 0);
+    }
+}
@@ -4,7 +4,7 @@
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 
-use crate::common::types::{Guid, GuildEvent, GuildMember};
+use crate::common::types::{Guid, GuildEvent, GuildMember, GuildMemberAction};
 use crate::protocol::packets::{
     read_cstring, PacketDecode, PacketEncode, MAX_CSTRING_LONG, MAX_CSTRING_SHORT,
 };
@@ -24,6 +24,92 @@ pub mod guild_events {
     pub const GE_SIGNED_OFF: u8 = 0x0D;
 }
 
+/// Event types from MSG_GUILD_EVENT_LOG_QUERY.
+pub mod guild_log_events {
+    pub const GUILD_EVENT_LOG_INVITE_PLAYER: u8 = 1;
+    pub const GUILD_EVENT_LOG_JOIN_GUILD: u8 = 2;
+    pub const GUILD_EVENT_LOG_PROMOTE_PLAYER: u8 = 3;
+    pub const GUILD_EVENT_LOG_DEMOTE_PLAYER: u8 = 4;
+    pub const GUILD_EVENT_LOG_UNINVITE_PLAYER: u8 = 5;
+    pub const GUILD_EVENT_LOG_LEAVE_GUILD: u8 = 6;
+}
+
+/// Guild rank right flags from SMSG_GUILD_ROSTER (every right includes GR_RIGHT_EMPTY).
+#[allow(dead_code)]
+pub mod guild_rights {
+    pub const GR_RIGHT_EMPTY: u32 = 0x00000040;
+    pub const GR_RIGHT_GCHATLISTEN: u32 = 0x00000041;
+    pub const GR_RIGHT_GCHATSPEAK: u32 = 0x00000042;
+    pub const GR_RIGHT_OFFCHATLISTEN: u32 = 0x00000044;
+    pub const GR_RIGHT_OFFCHATSPEAK: u32 = 0x00000048;
+    pub const GR_RIGHT_INVITE: u32 = 0x00000050;
+    pub const GR_RIGHT_REMOVE: u32 = 0x00000060;
+    pub const GR_RIGHT_PROMOTE: u32 = 0x000000C0;
+    pub const GR_RIGHT_DEMOTE: u32 = 0x00000140;
+    pub const GR_RIGHT_SETMOTD: u32 = 0x00001040;
+    pub const GR_RIGHT_EPNOTE: u32 = 0x00002040;
+    pub const GR_RIGHT_VIEWOFFNOTE: u32 = 0x00004040;
+    pub const GR_RIGHT_EOFFNOTE: u32 = 0x00008040;
+    pub const GR_RIGHT_MODIFY_GUILD_INFO: u32 = 0x00010040;
+}
+
+/// Command types from SMSG_GUILD_COMMAND_RESULT.
+#[allow(dead_code)]
+pub mod guild_commands {
+    pub const GUILD_COMMAND_CREATE: u32 = 0x00;
+    pub const GUILD_COMMAND_INVITE: u32 = 0x01;
+    pub const GUILD_COMMAND_QUIT: u32 = 0x03;
+    pub const GUILD_COMMAND_ROSTER: u32 = 0x05;
+    pub const GUILD_COMMAND_PROMOTE: u32 = 0x06;
+    pub const GUILD_COMMAND_DEMOTE: u32 = 0x07;
+    pub const GUILD_COMMAND_REMOVE: u32 = 0x08;
+    pub const GUILD_COMMAND_CHANGE_LEADER: u32 = 0x0A;
+    pub const GUILD_COMMAND_EDIT_MOTD: u32 = 0x0B;
+    pub const GUILD_COMMAND_PUBLIC_NOTE: u32 = 0x13;
+}
+
+/// Result codes from SMSG_GUILD_COMMAND_RESULT.
+#[allow(dead_code)]
+pub mod guild_command_errors {
+    pub const ERR_GUILD_COMMAND_SUCCESS: u32 = 0x00;
+    pub const ERR_GUILD_INTERNAL: u32 = 0x01;
+    pub const ERR_ALREADY_IN_GUILD: u32 = 0x02;
+    pub const ERR_ALREADY_IN_GUILD_S: u32 = 0x03;
+    pub const ERR_INVITED_TO_GUILD: u32 = 0x04;
+    pub const ERR_ALREADY_INVITED_TO_GUILD_S: u32 = 0x05;
+    pub const ERR_GUILD_NAME_INVALID: u32 = 0x06;
+    pub const ERR_GUILD_NAME_EXISTS_S: u32 = 0x07;
+    pub const ERR_GUILD_PERMISSIONS: u32 = 0x08;
+    pub const ERR_GUILD_PLAYER_NOT_IN_GUILD: u32 = 0x09;
+    pub const ERR_GUILD_PLAYER_NOT_IN_GUILD_S: u32 = 0x0A;
+    pub const ERR_GUILD_PLAYER_NOT_FOUND_S: u32 = 0x0B;
+    pub const ERR_GUILD_NOT_ALLIED: u32 = 0x0C;
+    pub const ERR_GUILD_RANK_TOO_HIGH_S: u32 = 0x0D;
+    pub const ERR_GUILD_RANK_TOO_LOW_S: u32 = 0x0E;
+    pub const ERR_GUILD_RANKS_LOCKED: u32 = 0x11;
+    pub const ERR_GUILD_RANK_IN_USE: u32 = 0x12;
+    pub const ERR_GUILD_IGNORING_YOU_S: u32 = 0x13;
+
+    /// Get a human-readable description of a guild command result.
+    pub fn description(result: u32, name: &str) -> String {
+        match result {
+            ERR_GUILD_COMMAND_SUCCESS => "Done.".to_string(),
+            ERR_ALREADY_IN_GUILD_S => format!("{} is already in a guild.", name),
+            ERR_ALREADY_INVITED_TO_GUILD_S => {
+                format!("{} has already been invited to a guild.", name)
+            }
+            ERR_GUILD_PERMISSIONS => "The bot's guild rank does not allow that.".to_string(),
+            ERR_GUILD_PLAYER_NOT_IN_GUILD_S => format!("{} is not in the guild.", name),
+            ERR_GUILD_PLAYER_NOT_FOUND_S => format!("Player \"{}\" not found.", name),
+            ERR_GUILD_NOT_ALLIED => format!("{} is not of the bot's faction.", name),
+            ERR_GUILD_RANK_TOO_HIGH_S => format!("{}'s rank is too high.", name),
+            ERR_GUILD_RANK_TOO_LOW_S => format!("{} is already at the lowest rank.", name),
+            ERR_GUILD_IGNORING_YOU_S => format!("{} is ignoring the bot.", name),
+            _ => format!("Guild command failed (error {}).", result),
+        }
+    }
+}
+
 /// Parsed guild information from SMSG_GUILD_QUERY.
 #[derive(Debug, Clone, Default)]
 pub struct GuildQueryResponse {
@@ -184,6 +270,8 @@ pub struct GuildRoster {
     pub member_count: u32,
     pub motd: String,
     pub guild_info: String,
+    /// Rights flags per rank index (see `guild_rights`).
+    pub rank_rights: Vec<u32>,
     pub members: Vec<GuildRosterMember>,
 }
 
@@ -219,7 +307,11 @@ impl PacketDecode for GuildRoster {
             ));
         }
 
-        buf.advance((8 + 48) * (rank_count as usize));
+        let mut rank_rights = Vec::with_capacity(rank_count as usize);
+        for _ in 0..rank_count {
+            rank_rights.push(buf.get_u32_le());
+            buf.advance(4 + 48); // Gold per day, bank tab rights
+        }
 
         // Read members
         let mut members = Vec::with_capacity(member_count as usize);
@@ -243,7 +335,6 @@ impl PacketDecode for GuildRoster {
             }
             let name = read_cstring(buf, MAX_CSTRING_SHORT)?;
 
-            // Skip guild rank (4 bytes) - not stored
             if buf.remaining() < 11 {
                 return Err(anyhow!(
                     "Packet too short: need {} bytes, got {}",
@@ -251,7 +342,7 @@ impl PacketDecode for GuildRoster {
                     buf.remaining()
                 ));
             }
-            buf.advance(4); // Rank
+            let rank = buf.get_u32_le();
             let level = buf.get_u8();
             let class = buf.get_u8();
             buf.advance(1); // Unknown/Padding
@@ -280,7 +371,7 @@ impl PacketDecode for GuildRoster {
                 guid,
                 online,
                 name,
-                rank: 0, // Not stored, skipped
+                rank,
                 level,
                 class,
                 gender: 0, // Not present in packet
@@ -303,6 +394,7 @@ impl PacketDecode for GuildRoster {
             member_count,
             motd,
             guild_info,
+            rank_rights,
             members,
         })
     }
@@ -373,6 +465,300 @@ impl PacketDecode for GuildEventPacket {
     }
 }
 
+/// SMSG_GUILD_INVITE packet.
+#[derive(Debug, Clone)]
+pub struct GuildInvite {
+    pub inviter: String,
+    pub guild_name: String,
+}
+
+impl PacketDecode for GuildInvite {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        let inviter = read_cstring(buf, MAX_CSTRING_SHORT)?;
+        let guild_name = read_cstring(buf, MAX_CSTRING_SHORT)?;
+
+        Ok(GuildInvite {
+            inviter,
+            guild_name,
+        })
+    }
+}
+
+/// CMSG_GUILD_ACCEPT or CMSG_GUILD_DECLINE packet (empty payload).
+#[derive(Debug, Clone)]
+pub struct GuildInviteResponse {
+    pub accept: bool,
+}
+
+impl From<GuildInviteResponse> for crate::protocol::packets::Packet {
+    fn from(resp: GuildInviteResponse) -> Self {
+        let opcode = if resp.accept {
+            crate::protocol::packets::opcodes::CMSG_GUILD_ACCEPT
+        } else {
+            crate::protocol::packets::opcodes::CMSG_GUILD_DECLINE
+        };
+        crate::protocol::packets::Packet::new(opcode, Bytes::new())
+    }
+}
+
+/// CMSG_GUILD_INVITE, CMSG_GUILD_REMOVE, CMSG_GUILD_PROMOTE or CMSG_GUILD_DEMOTE packet.
+#[derive(Debug, Clone)]
+pub struct GuildMemberCommand {
+    pub action: GuildMemberAction,
+    pub name: String,
+}
+
+impl GuildMemberCommand {
+    pub fn opcode(&self) -> u16 {
+        use crate::protocol::packets::opcodes::*;
+        match self.action {
+            GuildMemberAction::Invite => CMSG_GUILD_INVITE,
+            GuildMemberAction::Remove => CMSG_GUILD_REMOVE,
+            GuildMemberAction::Promote => CMSG_GUILD_PROMOTE,
+            GuildMemberAction::Demote => CMSG_GUILD_DEMOTE,
+        }
+    }
+}
+
+impl PacketEncode for GuildMemberCommand {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_slice(self.name.as_bytes());
+        buf.put_u8(0);
+    }
+}
+
+impl From<GuildMemberCommand> for crate::protocol::packets::Packet {
+    fn from(cmd: GuildMemberCommand) -> Self {
+        let mut buf = BytesMut::new();
+        cmd.encode(&mut buf);
+        crate::protocol::packets::Packet::new(cmd.opcode(), buf.freeze())
+    }
+}
+
+/// Longest guild MOTD the server accepts.
+pub const MAX_GUILD_MOTD_LENGTH: usize = 128;
+
+/// Longest guild information text the server accepts.
+pub const MAX_GUILD_INFO_LENGTH: usize = 500;
+
+/// CMSG_GUILD_MOTD packet.
+#[derive(Debug, Clone)]
+pub struct GuildSetMotd {
+    pub motd: String,
+}
+
+impl PacketEncode for GuildSetMotd {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_slice(self.motd.as_bytes());
+        buf.put_u8(0);
+    }
+}
+
+impl From<GuildSetMotd> for crate::protocol::packets::Packet {
+    fn from(req: GuildSetMotd) -> Self {
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_GUILD_MOTD,
+            buf.freeze(),
+        )
+    }
+}
+
+/// CMSG_GUILD_INFO_TEXT packet.
+#[derive(Debug, Clone)]
+pub struct GuildSetInfoText {
+    pub info: String,
+}
+
+impl PacketEncode for GuildSetInfoText {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_slice(self.info.as_bytes());
+        buf.put_u8(0);
+    }
+}
+
+impl From<GuildSetInfoText> for crate::protocol::packets::Packet {
+    fn from(req: GuildSetInfoText) -> Self {
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_GUILD_INFO_TEXT,
+            buf.freeze(),
+        )
+    }
+}
+
+/// Longest public or officer note the server accepts.
+pub const MAX_GUILD_NOTE_LENGTH: usize = 31;
+
+/// CMSG_GUILD_SET_PUBLIC_NOTE or CMSG_GUILD_SET_OFFICER_NOTE packet.
+#[derive(Debug, Clone)]
+pub struct GuildSetNote {
+    pub name: String,
+    pub note: String,
+    pub officer: bool,
+}
+
+impl PacketEncode for GuildSetNote {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_slice(self.name.as_bytes());
+        buf.put_u8(0);
+        buf.put_slice(self.note.as_bytes());
+        buf.put_u8(0);
+    }
+}
+
+impl From<GuildSetNote> for crate::protocol::packets::Packet {
+    fn from(req: GuildSetNote) -> Self {
+        let opcode = if req.officer {
+            crate::protocol::packets::opcodes::CMSG_GUILD_SET_OFFICER_NOTE
+        } else {
+            crate::protocol::packets::opcodes::CMSG_GUILD_SET_PUBLIC_NOTE
+        };
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+        crate::protocol::packets::Packet::new(opcode, buf.freeze())
+    }
+}
+
+/// MSG_GUILD_EVENT_LOG_QUERY request (empty payload).
+#[derive(Debug, Clone, Default)]
+pub struct GuildEventLogQuery;
+
+impl From<GuildEventLogQuery> for crate::protocol::packets::Packet {
+    fn from(_req: GuildEventLogQuery) -> Self {
+        crate::protocol::packets::Packet::empty(
+            crate::protocol::packets::opcodes::MSG_GUILD_EVENT_LOG_QUERY,
+        )
+    }
+}
+
+/// A single entry from the MSG_GUILD_EVENT_LOG_QUERY response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuildEventLogEntry {
+    /// Event type (see `guild_log_events`).
+    pub event_type: u8,
+    /// Player who acted (or joined/left).
+    pub player_guid: Guid,
+    /// Player acted upon (invite, promote, demote, uninvite).
+    pub target_guid: Option<Guid>,
+    /// New rank index (promote, demote).
+    pub rank: Option<u8>,
+    pub seconds_ago: u32,
+}
+
+/// MSG_GUILD_EVENT_LOG_QUERY response, oldest entry first.
+#[derive(Debug, Clone, Default)]
+pub struct GuildEventLog {
+    pub entries: Vec<GuildEventLogEntry>,
+}
+
+impl PacketDecode for GuildEventLog {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        use guild_log_events::*;
+
+        if buf.remaining() < 1 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                1,
+                buf.remaining()
+            ));
+        }
+
+        let count = buf.get_u8();
+        let mut entries = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            if buf.remaining() < 9 {
+                return Err(anyhow!(
+                    "Packet too short: need {} bytes, got {}",
+                    9,
+                    buf.remaining()
+                ));
+            }
+            let event_type = buf.get_u8();
+            let player_guid = buf.get_u64_le();
+
+            let has_target = !matches!(
+                event_type,
+                GUILD_EVENT_LOG_JOIN_GUILD | GUILD_EVENT_LOG_LEAVE_GUILD
+            );
+            let has_rank = matches!(
+                event_type,
+                GUILD_EVENT_LOG_PROMOTE_PLAYER | GUILD_EVENT_LOG_DEMOTE_PLAYER
+            );
+            let need = 4 + if has_target { 8 } else { 0 } + if has_rank { 1 } else { 0 };
+            if buf.remaining() < need {
+                return Err(anyhow!(
+                    "Packet too short: need {} bytes, got {}",
+                    need,
+                    buf.remaining()
+                ));
+            }
+
+            let target_guid = has_target.then(|| buf.get_u64_le());
+            let rank = has_rank.then(|| buf.get_u8());
+            let seconds_ago = buf.get_u32_le();
+
+            entries.push(GuildEventLogEntry {
+                event_type,
+                player_guid,
+                target_guid,
+                rank,
+                seconds_ago,
+            });
+        }
+
+        Ok(GuildEventLog { entries })
+    }
+}
+
+/// SMSG_GUILD_COMMAND_RESULT packet.
+#[derive(Debug, Clone)]
+pub struct GuildCommandResult {
+    /// Command type (see `guild_commands`).
+    pub command: u32,
+    pub name: String,
+    /// Result code (see `guild_command_errors`).
+    pub result: u32,
+}
+
+impl PacketDecode for GuildCommandResult {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 4 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                4,
+                buf.remaining()
+            ));
+        }
+
+        let command = buf.get_u32_le();
+        let name = read_cstring(buf, MAX_CSTRING_SHORT)?;
+
+        if buf.remaining() < 4 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                4,
+                buf.remaining()
+            ));
+        }
+        let result = buf.get_u32_le();
+
+        Ok(GuildCommandResult {
+            command,
+            name,
+            result,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,4 +772,101 @@ mod tests {
         assert_eq!(buf.len(), 4);
         assert_eq!(buf[0..4], 12345u32.to_le_bytes());
     }
+
+    #[test]
+    fn test_guild_roster_decode_ranks() {
+        let mut data = BytesMut::new();
+        data.put_u32_le(1);
+        data.put_slice(b"Welcome\0");
+        data.put_slice(b"\0");
+        data.put_u32_le(2);
+        data.put_u32_le(0x1DFFF);
+        data.put_slice(&[0; 52]);
+        data.put_u32_le(guild_rights::GR_RIGHT_GCHATSPEAK);
+        data.put_slice(&[0; 52]);
+        data.put_u64_le(42);
+        data.put_u8(1);
+        data.put_slice(b"Thrall\0");
+        data.put_u32_le(1);
+        data.put_u8(80);
+        data.put_u8(7);
+        data.put_u8(0);
+        data.put_u32_le(1637);
+        data.put_slice(b"Main\0");
+        data.put_slice(b"\0");
+
+        let roster = GuildRoster::decode(&mut data.freeze()).unwrap();
+        assert_eq!(
+            roster.rank_rights,
+            vec![0x1DFFF, guild_rights::GR_RIGHT_GCHATSPEAK]
+        );
+        assert_eq!(roster.members[0].rank, 1);
+        assert_eq!(roster.members[0].public_note, "Main");
+    }
+
+    #[test]
+    fn test_guild_command_result_decode() {
+        let mut data = BytesMut::new();
+        data.put_u32_le(guild_commands::GUILD_COMMAND_INVITE);
+        data.put_slice(b"Jaina\0");
+        data.put_u32_le(guild_command_errors::ERR_ALREADY_IN_GUILD_S);
+
+        let result = GuildCommandResult::decode(&mut data.freeze()).unwrap();
+        assert_eq!(result.command, guild_commands::GUILD_COMMAND_INVITE);
+        assert_eq!(result.name, "Jaina");
+        assert_eq!(
+            guild_command_errors::description(result.result, &result.name),
+            "Jaina is already in a guild."
+        );
+    }
+
+    #[test]
+    fn test_guild_set_motd_packet() {
+        let packet: crate::protocol::packets::Packet = GuildSetMotd {
+            motd: "Raid at 8".to_string(),
+        }
+        .into();
+        assert_eq!(
+            packet.opcode,
+            crate::protocol::packets::opcodes::CMSG_GUILD_MOTD
+        );
+        assert_eq!(&packet.payload[..], b"Raid at 8\0");
+    }
+
+    #[test]
+    fn test_guild_set_note_packet() {
+        let packet: crate::protocol::packets::Packet = GuildSetNote {
+            name: "Thrall".to_string(),
+            note: "Alt of Jaina".to_string(),
+            officer: true,
+        }
+        .into();
+        assert_eq!(
+            packet.opcode,
+            crate::protocol::packets::opcodes::CMSG_GUILD_SET_OFFICER_NOTE
+        );
+        assert_eq!(&packet.payload[..], b"Thrall\0Alt of Jaina\0");
+    }
+
+    #[test]
+    fn test_guild_event_log_decode() {
+        let mut data = BytesMut::new();
+        data.put_u8(2);
+        data.put_u8(guild_log_events::GUILD_EVENT_LOG_JOIN_GUILD);
+        data.put_u64_le(42);
+        data.put_u32_le(600);
+        data.put_u8(guild_log_events::GUILD_EVENT_LOG_PROMOTE_PLAYER);
+        data.put_u64_le(7);
+        data.put_u64_le(42);
+        data.put_u8(3);
+        data.put_u32_le(60);
+
+        let log = GuildEventLog::decode(&mut data.freeze()).unwrap();
+        assert_eq!(log.entries.len(), 2);
+        assert_eq!(log.entries[0].target_guid, None);
+        assert_eq!(log.entries[0].seconds_ago, 600);
+        assert_eq!(log.entries[1].player_guid, 7);
+        assert_eq!(log.entries[1].target_guid, Some(42));
+        assert_eq!(log.entries[1].rank, Some(3));
+    }
 }
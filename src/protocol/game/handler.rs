@@ -9,8 +9,218 @@ use bytes::Bytes;
 use sha1::{Digest, Sha1};
 use tracing::{debug, error, info, warn};
 
-use crate::common::messages::GuildEventInfo;
-use crate::common::types::{ChatMessage, GuildEvent, GuildInfo, GuildMember, Player};
+use crate::common::messages::{
+    ChannelEvent, ChannelStatus, GuildEventInfo, GuildLogEntryInfo, SocialNotice,
+};
+use crate::common::types::{
+    ChannelMember, ChannelModeration, ChatMessage, CustomChannel, EntityKind, Guid, GuildEvent,
+    GuildInfo, GuildMember, GuildMemberAction, NearbyEntity, Player, SocialRequest, WhoPlayer,
+};
+
+/// Outcome of a runtime channel join/leave, resolved from SMSG_CHANNEL_NOTIFY.
+#[derive(Debug, Clone)]
+pub struct ChannelCommandResult {
+    /// Discord channel that issued the command.
+    pub reply_channel: u64,
+    /// Human-readable result.
+    pub message: String,
+    /// Channel to rejoin on future logins (successful join).
+    pub joined: Option<CustomChannel>,
+    /// Channel to stop rejoining (successful leave).
+    pub left: Option<String>,
+    /// Channel whose rejoin password changed (successful !channel password).
+    pub password_changed: Option<CustomChannel>,
+}
+
+/// Result of processing SMSG_CHANNEL_NOTIFY.
+#[derive(Debug, Clone, Default)]
+pub struct ChannelNotifyResult {
+    /// Answer to a pending !channel command.
+    pub command: Option<ChannelCommandResult>,
+    /// Channel state change to surface on Discord.
+    pub event: Option<ChannelEvent>,
+    /// Failed !members requests: (reply channel, error message).
+    pub list_errors: Vec<(u64, String)>,
+}
+
+/// A !channel command waiting for SMSG_CHANNEL_NOTIFY.
+#[derive(Debug, Clone)]
+enum PendingChannelCommand {
+    Join(CustomChannel),
+    Leave,
+    Moderate,
+    /// Password change; the new password is kept for rejoins once confirmed.
+    Password(String),
+}
+
+/// A !members reply waiting for its member names to resolve.
+#[derive(Debug, Clone)]
+struct PendingMemberList {
+    reply_channel: u64,
+    channel_name: String,
+    /// (guid, member flags) pairs.
+    members: Vec<(u64, u8)>,
+    received: std::time::Instant,
+}
+
+/// When a GUID's pending chat messages give up on their name query.
+#[derive(Debug, Clone, Copy)]
+struct PendingNameDeadline {
+    due: std::time::Instant,
+    /// Whether the name query was already sent a second time.
+    retried: bool,
+}
+
+/// Counts of chat messages that never got their sender name in time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PendingMessageStats {
+    /// Relayed with a fallback sender label after the name query timed out twice.
+    pub late: u64,
+    /// Evicted without being relayed because too many GUIDs were pending.
+    pub dropped: u64,
+}
+
+impl PendingMessageStats {
+    /// One-line summary for the !status reply.
+    pub fn summary(&self) -> String {
+        format!(
+            "Messages from unresolved senders: {} relayed late, {} dropped.",
+            self.late, self.dropped
+        )
+    }
+}
+
+impl std::ops::AddAssign for PendingMessageStats {
+    fn add_assign(&mut self, other: Self) {
+        self.late += other.late;
+        self.dropped += other.dropped;
+    }
+}
+
+/// Auto-rejoin state for a channel the bot was kicked from.
+#[derive(Debug, Clone)]
+struct ChannelRejoin {
+    channel_name: String,
+    /// Consecutive kicks so far.
+    attempts: u32,
+    last_kick: std::time::Instant,
+    /// When the next join should be sent (None while waiting for the server).
+    due: Option<std::time::Instant>,
+}
+
+/// A !guild command waiting for SMSG_GUILD_COMMAND_RESULT or the matching guild event.
+#[derive(Debug, Clone)]
+struct PendingGuildCommand {
+    reply_channel: u64,
+    action: GuildMemberAction,
+    name: String,
+    sent: std::time::Instant,
+}
+
+/// A !gmotd set or !ginfo set waiting for the server to echo the new text back.
+#[derive(Debug, Clone)]
+struct PendingGuildText {
+    reply_channel: u64,
+    text: String,
+    sent: std::time::Instant,
+}
+
+/// A !note set waiting for the roster to show the new note.
+#[derive(Debug, Clone)]
+struct PendingGuildNote {
+    reply_channel: u64,
+    name: String,
+    note: String,
+    officer: bool,
+    sent: std::time::Instant,
+}
+
+/// A guild event log entry with the Unix time it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuildLogRecord {
+    pub time: u64,
+    pub entry: GuildEventLogEntry,
+}
+
+/// The guild event log as last seen before the bot went offline.
+#[derive(Debug, Clone, Default)]
+pub struct GuildLogSeen {
+    pub records: Vec<GuildLogRecord>,
+    /// Unix time until which live guild events were being received.
+    pub last_online: u64,
+}
+
+impl GuildLogSeen {
+    /// Allowed drift between two fetches of the same entry (timestamps are relative).
+    const TIME_TOLERANCE: u64 = 5;
+
+    /// Records that happened after the bot went offline and were not in the last log.
+    pub fn missed(&self, records: &[GuildLogRecord]) -> Vec<GuildLogRecord> {
+        records
+            .iter()
+            .filter(|r| r.time + Self::TIME_TOLERANCE >= self.last_online)
+            .filter(|r| {
+                !self.records.iter().any(|seen| {
+                    seen.entry.event_type == r.entry.event_type
+                        && seen.entry.player_guid == r.entry.player_guid
+                        && seen.entry.target_guid == r.entry.target_guid
+                        && seen.entry.rank == r.entry.rank
+                        && seen.time.abs_diff(r.time) <= Self::TIME_TOLERANCE
+                })
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Decoded MSG_GUILD_EVENT_LOG_QUERY response.
+#[derive(Debug, Clone)]
+pub struct GuildEventLogResult {
+    pub records: Vec<GuildLogRecord>,
+    /// True for the first log after logging in, which is diffed for missed events.
+    pub first_since_login: bool,
+    /// GUIDs that need a name query before the log can be sent.
+    pub unnamed: Vec<u64>,
+}
+
+/// Guild log entries waiting for their player names to resolve.
+#[derive(Debug, Clone)]
+struct PendingGuildLog {
+    /// Discord channel for !guildlog, None for missed events posted through the bridge.
+    reply_channel: Option<u64>,
+    records: Vec<GuildLogRecord>,
+    received: std::time::Instant,
+}
+
+/// Guild log entries with resolved names.
+#[derive(Debug, Clone)]
+pub struct ReadyGuildLog {
+    /// Discord channel for !guildlog, None for missed events.
+    pub reply_channel: Option<u64>,
+    pub entries: Vec<GuildLogEntryInfo>,
+}
+
+/// Outcome of a !guild command.
+#[derive(Debug, Clone)]
+pub struct GuildCommandReply {
+    /// Discord channel that issued the command.
+    pub reply_channel: u64,
+    /// Human-readable result.
+    pub message: String,
+    pub success: bool,
+}
+
+/// A duel, trade, group, guild or petition request waiting for an answer.
+#[derive(Debug, Clone)]
+pub struct SocialRequestInfo {
+    pub request: SocialRequest,
+    /// Player who sent the request.
+    pub player: String,
+    /// Requester whose name is not known yet, if any.
+    pub unnamed_guid: Option<Guid>,
+    /// Object the answer refers to (duel flag or petition), 0 if none.
+    target_guid: Guid,
+}
 
 /// Result of processing a chat message.
 #[derive(Debug, Clone)]
@@ -19,25 +229,70 @@ pub enum ChatProcessingResult {
     GuildEvent(GuildEventInfo),
 }
 use crate::protocol::game::chat::{
-    get_language_for_race, ChannelNotify, ChatPlayerNotFound, JoinChannelWotLK, MessageChat,
-    NameQuery, NameQueryResponse, SendChatMessage,
+    channel_member_flags, chat_notify, get_language_for_race, ChannelList, ChannelListRequest,
+    ChannelModerate, ChannelNotify, ChatPlayerNotFound, JoinChannelWotLK, LeaveChannel,
+    MessageChat, NameQuery, NameQueryResponse, SendChatMessage,
+};
+use crate::protocol::game::group::{
+    GroupAccept, GroupDecline, GroupInvite, GroupLeave, GroupList, GroupUninvite,
 };
 use crate::protocol::game::guild::{
-    GuildEventPacket, GuildQuery, GuildQueryResponse, GuildRoster, GuildRosterRequest,
+    guild_command_errors, guild_commands, guild_log_events, guild_rights, GuildCommandResult,
+    GuildEventLog, GuildEventLogEntry, GuildEventLogQuery, GuildEventPacket, GuildInvite,
+    GuildInviteResponse, GuildMemberCommand, GuildQuery, GuildQueryResponse, GuildRoster,
+    GuildRosterRequest, GuildSetInfoText, GuildSetMotd, GuildSetNote, MAX_GUILD_INFO_LENGTH,
+    MAX_GUILD_MOTD_LENGTH, MAX_GUILD_NOTE_LENGTH,
 };
+use crate::protocol::game::objects::ObjectRegistry;
 use crate::protocol::game::packets::{
-    AuthChallenge, AuthResponse, AuthSession, CharEnum, CharEnumRequest, CharacterInfo, GameObjUse,
-    InitWorldStates, KeepAlive, LoginVerifyWorld, Ping, PlayerLogin, Pong, TimeSyncReq,
-    TimeSyncResp,
+    AuthChallenge, AuthResponse, AuthSession, CharEnum, CharEnumRequest, CharacterInfo,
+    CompressedUpdateObject, DestroyObject, GameObjUse, InitWorldStates, KeepAlive,
+    LoginVerifyWorld, Ping, PlayerLogin, Pong, TimeSyncReq, TimeSyncResp,
 };
-use crate::protocol::packets::{read_packed_guid, PacketDecode};
+use crate::protocol::game::social::{
+    DuelRequested, DuelResponse, PetitionResponse, PetitionShowSignatures, TradeResponse,
+    TradeStatus,
+};
+use crate::protocol::game::who::{WhoRequest, WhoResponse};
+use crate::protocol::packets::{read_packed_guid, Packet, PacketDecode};
 use anyhow::{anyhow, Result};
 use bytes::Buf;
 
 /// Maximum number of distinct GUIDs with pending name resolution.
-/// When exceeded, the oldest entry is evicted (messages silently dropped).
+/// When exceeded, the oldest entry is evicted (messages dropped and counted).
 const MAX_PENDING_GUIDS: usize = 256;
 
+/// How long queued chat waits for SMSG_NAME_QUERY before the query is retried,
+/// and again before the messages are relayed with a fallback sender label.
+const NAME_QUERY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Delay before the first rejoin after a kick; doubles on each consecutive kick.
+const REJOIN_BASE_DELAY: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Consecutive kicks after which the bot stops rejoining a channel.
+const MAX_REJOIN_ATTEMPTS: u32 = 5;
+
+/// How long a !members reply waits for name queries before sending unresolved GUIDs.
+const MEMBER_NAME_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How long a !guild command waits for the server before giving up.
+const GUILD_COMMAND_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How long a server-wide WHO waits for SMSG_WHO before giving up.
+const WHO_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How long a !guildlog request waits for MSG_GUILD_EVENT_LOG_QUERY before giving up.
+const GUILD_LOG_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How long an achievement announcement suppresses repeats of the same earn.
+const ACHIEVEMENT_DEDUPE_WINDOW: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// A kick notice and YOU_LEFT this close together are one kick.
+const KICK_DEDUPE_WINDOW: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Kicks further apart than this reset the backoff.
+const REJOIN_RESET_WINDOW: std::time::Duration = std::time::Duration::from_secs(30 * 60);
+
 /// Game protocol handler state.
 pub struct GameHandler {
     account: String,
@@ -56,22 +311,77 @@ pub struct GameHandler {
     pub guild_roster: HashMap<u64, GuildMember>,
     /// Guild MOTD
     pub guild_motd: Option<String>,
+    /// Guild information text, from the roster
+    pub guild_info_text: Option<String>,
+    /// Rights flags per guild rank index, from the roster
+    pub guild_rank_rights: Vec<u32>,
     /// Last time guild roster was requested (for periodic updates)
     pub last_roster_request: Option<std::time::Instant>,
 
     /// Cache of player names by GUID (LRU-bounded to prevent unbounded growth)
     pub player_names: LruCache<u64, Player>,
+    /// Whether names were added or invalidated since the cache was last taken for saving
+    player_names_changed: bool,
     /// Pending chat messages waiting for name resolution
     pub pending_messages: HashMap<u64, Vec<ChatMessage>>,
     /// GUIDs that already have an in-flight CMSG_NAME_QUERY (avoids redundant queries)
     pub pending_name_queries: HashSet<u64>,
     /// Insertion-order tracking for pending_messages (front = oldest)
     pending_message_order: VecDeque<u64>,
+    /// Name query deadlines for pending_messages
+    pending_message_deadlines: HashMap<u64, PendingNameDeadline>,
+    /// Messages relayed late or dropped while waiting for a name, since last taken
+    pending_message_stats: PendingMessageStats,
+    /// Social request notices waiting for the requester's name, keyed by GUID
+    pending_social_notices: HashMap<u64, Vec<SocialNotice>>,
+    /// Held social notices ready to be sent
+    ready_social_notices: Vec<SocialNotice>,
+
+    /// In-flight CMSG_WHO queries in send order: (reply channel, search input, sent)
+    pending_who_requests: VecDeque<(u64, String, std::time::Instant)>,
+    /// In-flight !channel commands keyed by lowercase channel name: (reply channel, command)
+    pending_channel_commands: HashMap<String, (u64, PendingChannelCommand)>,
+    /// Channels the bot was kicked from, keyed by lowercase channel name.
+    channel_rejoins: HashMap<String, ChannelRejoin>,
+    /// Lowercase names of channels the server confirmed the bot joined.
+    joined_channels: HashSet<String>,
+    /// When the bot was last kicked from each channel, keyed by lowercase name.
+    recent_kicks: HashMap<String, std::time::Instant>,
+    /// In-flight CMSG_CHANNEL_LIST requests: lowercase channel name -> reply channels
+    pending_channel_lists: HashMap<String, Vec<u64>>,
+    /// Member lists waiting for name queries
+    pending_member_lists: Vec<PendingMemberList>,
+    /// In-flight !guild invite/kick/promote/demote commands
+    pending_guild_commands: Vec<PendingGuildCommand>,
+    /// Resolved !guild commands waiting to be sent to Discord
+    guild_command_replies: Vec<GuildCommandReply>,
+    /// In-flight !gmotd set
+    pending_motd_update: Option<PendingGuildText>,
+    /// In-flight !ginfo set
+    pending_info_update: Option<PendingGuildText>,
+    /// In-flight !note set commands
+    pending_note_updates: Vec<PendingGuildNote>,
+    /// Lowercase names the server announced joining, leaving or changing rank
+    /// since the last roster, so the roster diff does not announce them twice
+    guild_event_members: HashSet<String>,
+    /// In-flight !guildlog requests: (reply channel, entry count, sent)
+    pending_guild_log_requests: VecDeque<(u64, usize, std::time::Instant)>,
+    /// Whether the login guild event log has been received
+    guild_log_synced: bool,
+    /// Guild logs waiting for name queries
+    pending_guild_logs: Vec<PendingGuildLog>,
+    /// Recently announced achievements: (player GUID, achievement ID) -> when
+    recent_achievements: HashMap<(u64, u32), std::time::Instant>,
+
+    /// Current party/raid (None when not grouped)
+    pub group: Option<GroupList>,
 
     /// Sit quirk state
     pub tried_to_sit: bool,
     /// Last known world position (x, y, z)
     pub world_position: Option<(f32, f32, f32)>,
+    /// Players, creatures and game objects in visibility range
+    pub nearby_objects: ObjectRegistry,
     /// Timestamp when the handler was created (for SMSG_TIME_SYNC_REQ uptime calculation)
     connect_time: std::time::Instant,
 }
@@ -91,13 +401,39 @@ impl GameHandler {
             guild_info: None,
             guild_roster: HashMap::new(),
             guild_motd: None,
+            guild_info_text: None,
+            guild_rank_rights: Vec::new(),
             last_roster_request: None,
             player_names: LruCache::new(NonZeroUsize::new(1024).unwrap()),
+            player_names_changed: false,
             pending_messages: HashMap::new(),
             pending_name_queries: HashSet::new(),
             pending_message_order: VecDeque::new(),
+            pending_message_deadlines: HashMap::new(),
+            pending_message_stats: PendingMessageStats::default(),
+            pending_social_notices: HashMap::new(),
+            ready_social_notices: Vec::new(),
+            pending_who_requests: VecDeque::new(),
+            pending_channel_commands: HashMap::new(),
+            channel_rejoins: HashMap::new(),
+            joined_channels: HashSet::new(),
+            recent_kicks: HashMap::new(),
+            pending_channel_lists: HashMap::new(),
+            pending_member_lists: Vec::new(),
+            pending_guild_commands: Vec::new(),
+            guild_command_replies: Vec::new(),
+            pending_motd_update: None,
+            pending_info_update: None,
+            pending_note_updates: Vec::new(),
+            guild_event_members: HashSet::new(),
+            pending_guild_log_requests: VecDeque::new(),
+            guild_log_synced: false,
+            pending_guild_logs: Vec::new(),
+            recent_achievements: HashMap::new(),
+            group: None,
             tried_to_sit: false,
             world_position: None,
+            nearby_objects: ObjectRegistry::new(),
             connect_time: std::time::Instant::now(),
         }
     }
@@ -255,6 +591,8 @@ impl GameHandler {
         &mut self,
         mut payload: Bytes,
     ) -> Result<Option<ChatProcessingResult>> {
+        use crate::protocol::game::chat::chat_events;
+
         let msg = match MessageChat::decode(&mut payload) {
             Ok(msg) => msg,
             Err(e) if e.to_string().contains("skip") => {
@@ -263,12 +601,24 @@ impl GameHandler {
             Err(e) => return Err(e),
         };
 
-        // Handle Guild Achievements
-        if msg.chat_type == crate::protocol::game::chat::chat_events::CHAT_MSG_GUILD_ACHIEVEMENT {
-            // Look up player name from guild roster
+        // Guild and personal achievements of guild members become achievement events
+        if msg.chat_type == chat_events::CHAT_MSG_GUILD_ACHIEVEMENT
+            || msg.chat_type == chat_events::CHAT_MSG_ACHIEVEMENT
+        {
             if let Some(member) = self.guild_roster.get(&msg.sender_guid) {
                 let player_name = member.name.clone();
 
+                if let Some(achievement_id) = msg.achievement_id {
+                    if !self.record_achievement(msg.sender_guid, achievement_id) {
+                        debug!(
+                            player = %player_name,
+                            achievement_id,
+                            "Skipping duplicate achievement announcement"
+                        );
+                        return Ok(None);
+                    }
+                }
+
                 return Ok(Some(ChatProcessingResult::GuildEvent(GuildEventInfo {
                     event_name: "achievement".to_string(),
                     player_name,
@@ -276,7 +626,7 @@ impl GameHandler {
                     rank_name: None,
                     achievement_id: msg.achievement_id,
                 })));
-            } else {
+            } else if msg.chat_type == chat_events::CHAT_MSG_GUILD_ACHIEVEMENT {
                 warn!(
                     guid = %msg.sender_guid,
                     "Guild achievement from unknown player (not in roster yet)"
@@ -291,22 +641,93 @@ impl GameHandler {
         }
     }
 
+    /// Fill the name cache with previously saved players, oldest first.
+    pub fn load_player_names(&mut self, players: Vec<Player>) {
+        for player in players {
+            self.player_names.put(player.guid, player);
+        }
+    }
+
+    /// Take the cached players, oldest first, if the cache changed since the last call.
+    pub fn take_changed_player_names(&mut self) -> Option<Vec<Player>> {
+        if !std::mem::take(&mut self.player_names_changed) {
+            return None;
+        }
+        Some(
+            self.player_names
+                .iter()
+                .rev()
+                .map(|(_, p)| p.clone())
+                .collect(),
+        )
+    }
+
+    /// Store a player's level in the name cache if it is cached and the level changed.
+    fn update_cached_level(&mut self, guid: u64, level: u8) {
+        if level == 0 {
+            return;
+        }
+        if let Some(player) = self.player_names.peek_mut(&guid) {
+            if player.level != level {
+                player.level = level;
+                self.player_names_changed = true;
+            }
+        }
+    }
+
+    /// Store the level of a nearby player from its update fields.
+    fn update_nearby_level(&mut self, guid: u64) {
+        if let Some(object) = self.nearby_objects.get(guid) {
+            if object.kind == EntityKind::Player {
+                let level = object.level;
+                self.update_cached_level(guid, level);
+            }
+        }
+    }
+
+    /// Best known level of a player from the roster, nearby objects or the name cache.
+    fn known_level(&self, guid: u64) -> u8 {
+        self.guild_roster
+            .get(&guid)
+            .map(|m| m.level)
+            .or_else(|| self.nearby_objects.get(guid).map(|o| o.level))
+            .filter(|&level| level != 0)
+            .or_else(|| self.player_names.peek(&guid).map(|p| p.level))
+            .unwrap_or(0)
+    }
+
+    /// Remember an achievement announcement.
+    ///
+    /// Returns false if the same player's achievement was already seen recently,
+    /// e.g. when both the guild and the nearby announcement arrive for one earn.
+    fn record_achievement(&mut self, guid: u64, achievement_id: u32) -> bool {
+        let now = std::time::Instant::now();
+        self.recent_achievements
+            .retain(|_, seen| now.duration_since(*seen) < ACHIEVEMENT_DEDUPE_WINDOW);
+        self.recent_achievements
+            .insert((guid, achievement_id), now)
+            .is_none()
+    }
+
     /// Handle SMSG_NAME_QUERY response.
     pub fn handle_name_query(&mut self, mut payload: Bytes) -> Result<Vec<ChatMessage>> {
         let response = NameQueryResponse::decode(&mut payload)?;
 
-        // Add to cache
+        // Add to cache; SMSG_NAME_QUERY carries no level
         let player = Player {
             guid: response.guid,
             name: response.name.clone(),
-            level: 0,
+            level: self.known_level(response.guid),
             class: crate::common::resources::Class::from_id(response.class as u8),
             race: crate::common::resources::Race::from_id(response.race as u8),
             zone_id: 0,
         };
         self.player_names.put(response.guid, player);
+        self.player_names_changed = true;
         self.pending_name_queries.remove(&response.guid);
         self.pending_message_order.retain(|&g| g != response.guid);
+        self.pending_message_deadlines.remove(&response.guid);
+        self.release_social_notices(response.guid, Some(response.name.clone()));
 
         // Process any pending messages for this GUID
         let mut resolved = Vec::new();
@@ -338,14 +759,27 @@ impl GameHandler {
     /// If this is a new GUID and we're at capacity, the oldest GUID's pending
     /// messages are evicted (dropped) to make room.
     fn queue_pending_message(&mut self, guid: u64, msg: ChatMessage) {
-        let is_new_guid = !self.pending_messages.contains_key(&guid);
+        self.track_pending_name(guid);
+        self.pending_messages.entry(guid).or_default().push(msg);
+    }
+
+    /// Start the name query deadline for a GUID unless it is already waiting.
+    /// At capacity the oldest GUID is evicted: its messages are dropped and its
+    /// social notices are released under their fallback name.
+    fn track_pending_name(&mut self, guid: u64) {
+        if self.pending_message_deadlines.contains_key(&guid) {
+            return;
+        }
 
-        if is_new_guid && self.pending_messages.len() >= MAX_PENDING_GUIDS {
+        if self.pending_message_order.len() >= MAX_PENDING_GUIDS {
             // Evict the oldest entry
             if let Some(oldest_guid) = self.pending_message_order.pop_front() {
                 let evicted = self.pending_messages.remove(&oldest_guid);
                 self.pending_name_queries.remove(&oldest_guid);
+                self.pending_message_deadlines.remove(&oldest_guid);
+                self.release_social_notices(oldest_guid, None);
                 if let Some(msgs) = evicted {
+                    self.pending_message_stats.dropped += msgs.len() as u64;
                     warn!(
                         guid = oldest_guid,
                         count = msgs.len(),
@@ -355,15 +789,80 @@ impl GameHandler {
             }
         }
 
-        self.pending_messages.entry(guid).or_default().push(msg);
+        self.pending_message_order.push_back(guid);
+        self.pending_message_deadlines.insert(
+            guid,
+            PendingNameDeadline {
+                due: std::time::Instant::now() + NAME_QUERY_TIMEOUT,
+                retried: false,
+            },
+        );
+    }
 
-        if is_new_guid {
-            self.pending_message_order.push_back(guid);
+    /// Check pending message deadlines.
+    ///
+    /// Returns the GUIDs whose name query should be sent once more, and the
+    /// messages whose retry also timed out. Those are relayed under the guild
+    /// roster name if known, otherwise under their `Unknown-<guid>` label.
+    pub fn take_expired_pending_messages(
+        &mut self,
+        now: std::time::Instant,
+    ) -> (Vec<u64>, Vec<ChatMessage>) {
+        let mut retries = Vec::new();
+        let mut expired = Vec::new();
+        for &guid in &self.pending_message_order {
+            let Some(deadline) = self.pending_message_deadlines.get_mut(&guid) else {
+                continue;
+            };
+            if deadline.due > now {
+                continue;
+            }
+            if deadline.retried {
+                expired.push(guid);
+            } else {
+                deadline.retried = true;
+                deadline.due = now + NAME_QUERY_TIMEOUT;
+                retries.push(guid);
+            }
         }
+
+        let mut relayed = Vec::new();
+        for guid in expired {
+            self.pending_message_order.retain(|&g| g != guid);
+            self.pending_message_deadlines.remove(&guid);
+            self.pending_name_queries.remove(&guid);
+            let fallback = self.member_name(guid);
+            self.release_social_notices(guid, fallback.clone());
+            let Some(messages) = self.pending_messages.remove(&guid) else {
+                continue;
+            };
+            self.pending_message_stats.late += messages.len() as u64;
+            debug!(
+                guid,
+                count = messages.len(),
+                "Name query timed out, relaying pending messages with fallback sender"
+            );
+            for mut msg in messages {
+                if let Some(name) = &fallback {
+                    msg.sender_name = name.clone();
+                }
+                relayed.push(msg);
+            }
+        }
+        (retries, relayed)
+    }
+
+    /// Take the counts of messages relayed late or dropped while waiting for a
+    /// sender name since the last call.
+    pub fn take_pending_message_stats(&mut self) -> PendingMessageStats {
+        std::mem::take(&mut self.pending_message_stats)
     }
 
     /// Handle SMSG_CHANNEL_NOTIFY.
-    pub fn handle_channel_notify(&self, mut payload: Bytes) -> Result<()> {
+    ///
+    /// Resolves pending !channel join/leave commands and turns kicks, bans,
+    /// failed joins and throttling into channel events for the bridge.
+    pub fn handle_channel_notify(&mut self, mut payload: Bytes) -> Result<ChannelNotifyResult> {
         let notify = ChannelNotify::decode(&mut payload)?;
         let desc = notify.description();
 
@@ -373,15 +872,385 @@ impl GameHandler {
             _ => warn!("{}", desc),
         }
 
-        Ok(())
+        // The server answers CMSG_CHANNEL_LIST with this notice when we're not in the channel
+        let list_errors = match notify.notify_type {
+            chat_notify::CHAT_NOT_MEMBER_NOTICE => self
+                .pending_channel_lists
+                .remove(&notify.channel_name.to_lowercase())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|reply_channel| (reply_channel, desc.clone()))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        // Track membership so a YOU_LEFT nobody asked for can be told apart
+        let key = notify.channel_name.to_lowercase();
+        let was_joined = match notify.notify_type {
+            chat_notify::CHAT_YOU_JOINED_NOTICE => self.joined_channels.insert(key),
+            chat_notify::CHAT_YOU_LEFT_NOTICE => self.joined_channels.remove(&key),
+            _ => false,
+        };
+
+        // Answers to !channel commands are reported to the invoking channel only
+        if let Some(command) = self.resolve_channel_command(&notify, &desc) {
+            return Ok(ChannelNotifyResult {
+                command: Some(command),
+                event: None,
+                list_errors,
+            });
+        }
+
+        Ok(ChannelNotifyResult {
+            command: None,
+            event: self.channel_event(&notify, desc, was_joined),
+            list_errors,
+        })
+    }
+
+    /// Build a CMSG_CHANNEL_LIST for a !members command and remember who asked for it.
+    pub fn request_channel_list(
+        &mut self,
+        channel_name: &str,
+        reply_channel: u64,
+    ) -> ChannelListRequest {
+        self.pending_channel_lists
+            .entry(channel_name.to_lowercase())
+            .or_default()
+            .push(reply_channel);
+        ChannelListRequest {
+            channel_name: channel_name.to_string(),
+        }
+    }
+
+    /// Handle SMSG_CHANNEL_LIST.
+    /// Returns GUIDs that need a name query before the member list can be sent.
+    pub fn handle_channel_list(&mut self, mut payload: Bytes) -> Result<Vec<u64>> {
+        let list = ChannelList::decode(&mut payload)?;
+        debug!(
+            "Channel list for [{}]: {} members",
+            list.channel_name,
+            list.members.len()
+        );
+
+        let Some(reply_channels) = self
+            .pending_channel_lists
+            .remove(&list.channel_name.to_lowercase())
+        else {
+            return Ok(Vec::new());
+        };
+
+        let unnamed: Vec<u64> = list
+            .members
+            .iter()
+            .map(|(guid, _)| *guid)
+            .filter(|guid| self.member_name(*guid).is_none())
+            .filter(|guid| !self.pending_name_queries.contains(guid))
+            .collect();
+        self.pending_name_queries.extend(unnamed.iter().copied());
+
+        let received = std::time::Instant::now();
+        for reply_channel in reply_channels {
+            self.pending_member_lists.push(PendingMemberList {
+                reply_channel,
+                channel_name: list.channel_name.clone(),
+                members: list.members.clone(),
+                received,
+            });
+        }
+        Ok(unnamed)
+    }
+
+    /// Take member lists whose names are all resolved (or whose wait timed out).
+    /// Returns (reply channel, channel name, members).
+    pub fn take_ready_member_lists(&mut self) -> Vec<(u64, String, Vec<ChannelMember>)> {
+        let (ready, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_member_lists)
+            .into_iter()
+            .partition(|list| {
+                list.received.elapsed() >= MEMBER_NAME_TIMEOUT
+                    || list
+                        .members
+                        .iter()
+                        .all(|(guid, _)| self.member_name(*guid).is_some())
+            });
+        self.pending_member_lists = waiting;
+
+        ready
+            .into_iter()
+            .map(|list| {
+                let members = list
+                    .members
+                    .iter()
+                    .map(|&(guid, flags)| ChannelMember {
+                        guid,
+                        name: self.member_name(guid),
+                        owner: flags & channel_member_flags::MEMBER_FLAG_OWNER != 0,
+                        moderator: flags & channel_member_flags::MEMBER_FLAG_MODERATOR != 0,
+                        muted: flags & channel_member_flags::MEMBER_FLAG_MUTED != 0,
+                    })
+                    .collect();
+                (list.reply_channel, list.channel_name, members)
+            })
+            .collect()
+    }
+
+    /// Look up a character name from the name cache or guild roster.
+    fn member_name(&self, guid: u64) -> Option<String> {
+        self.player_names
+            .peek(&guid)
+            .map(|p| p.name.clone())
+            .or_else(|| self.guild_roster.get(&guid).map(|m| m.name.clone()))
+    }
+
+    /// Match a notification against a pending !channel command.
+    fn resolve_channel_command(
+        &mut self,
+        notify: &ChannelNotify,
+        desc: &str,
+    ) -> Option<ChannelCommandResult> {
+        let key = notify.channel_name.to_lowercase();
+        let (_, pending) = self.pending_channel_commands.get(&key)?;
+
+        let (joined, left, password_changed) = match (pending, notify.notify_type) {
+            (
+                PendingChannelCommand::Join(channel),
+                chat_notify::CHAT_YOU_JOINED_NOTICE
+                | chat_notify::CHAT_PLAYER_ALREADY_MEMBER_NOTICE,
+            ) => (Some(channel.clone()), None, None),
+            (
+                PendingChannelCommand::Join(_),
+                chat_notify::CHAT_WRONG_PASSWORD_NOTICE
+                | chat_notify::CHAT_BANNED_NOTICE
+                | chat_notify::CHAT_WRONG_FACTION_NOTICE
+                | chat_notify::CHAT_INVALID_NAME_NOTICE
+                | chat_notify::CHAT_NOT_IN_AREA_NOTICE
+                | chat_notify::CHAT_NOT_IN_LFG_NOTICE,
+            ) => (None, None, None),
+            (
+                PendingChannelCommand::Leave,
+                chat_notify::CHAT_YOU_LEFT_NOTICE | chat_notify::CHAT_NOT_MEMBER_NOTICE,
+            ) => (None, Some(notify.channel_name.clone()), None),
+            (
+                PendingChannelCommand::Password(password),
+                chat_notify::CHAT_PASSWORD_CHANGED_NOTICE,
+            ) => {
+                let channel = CustomChannel {
+                    name: notify.channel_name.clone(),
+                    // An empty password removes it
+                    password: (!password.is_empty()).then(|| password.clone()),
+                };
+                (None, None, Some(channel))
+            }
+            // Moderation answers with a result or an error notice; skip unrelated traffic
+            (
+                PendingChannelCommand::Moderate | PendingChannelCommand::Password(_),
+                chat_notify::CHAT_JOINED_NOTICE
+                | chat_notify::CHAT_LEFT_NOTICE
+                | chat_notify::CHAT_YOU_JOINED_NOTICE
+                | chat_notify::CHAT_YOU_LEFT_NOTICE
+                | chat_notify::CHAT_THROTTLED_NOTICE,
+            ) => return None,
+            (PendingChannelCommand::Moderate | PendingChannelCommand::Password(_), _) => {
+                (None, None, None)
+            }
+            // Not an answer to the pending command
+            _ => return None,
+        };
+
+        let (reply_channel, _) = self.pending_channel_commands.remove(&key)?;
+        Some(ChannelCommandResult {
+            reply_channel,
+            message: desc.to_string(),
+            joined,
+            left,
+            password_changed,
+        })
+    }
+
+    /// Classify a notification about the bot itself into a channel event.
+    /// Kicks schedule a rejoin with backoff; repeated kicks or failed joins degrade the channel.
+    fn channel_event(
+        &mut self,
+        notify: &ChannelNotify,
+        desc: String,
+        was_joined: bool,
+    ) -> Option<ChannelEvent> {
+        let key = notify.channel_name.to_lowercase();
+        let about_self = notify.player_guid.is_some() && notify.player_guid == self.self_guid;
+        // Some servers only send YOU_LEFT when kicking, so a YOU_LEFT from a joined
+        // channel that answers no !channel leave is treated as a kick
+        let kicked = match notify.notify_type {
+            chat_notify::CHAT_PLAYER_KICKED_NOTICE => about_self,
+            chat_notify::CHAT_YOU_LEFT_NOTICE => was_joined,
+            _ => false,
+        };
+
+        let (status, message) = match notify.notify_type {
+            chat_notify::CHAT_YOU_JOINED_NOTICE => {
+                // Keep the kick count so a kick loop still backs off
+                if let Some(rejoin) = self.channel_rejoins.get_mut(&key) {
+                    rejoin.due = None;
+                }
+                (ChannelStatus::Joined, desc)
+            }
+            chat_notify::CHAT_PLAYER_KICKED_NOTICE | chat_notify::CHAT_YOU_LEFT_NOTICE
+                if kicked =>
+            {
+                let now = std::time::Instant::now();
+                // The kick notice and YOU_LEFT for the same kick are counted once
+                if self
+                    .recent_kicks
+                    .insert(key.clone(), now)
+                    .is_some_and(|last| now.duration_since(last) < KICK_DEDUPE_WINDOW)
+                {
+                    return None;
+                }
+                let rejoin = self
+                    .channel_rejoins
+                    .entry(key.clone())
+                    .or_insert(ChannelRejoin {
+                        channel_name: notify.channel_name.clone(),
+                        attempts: 0,
+                        last_kick: now,
+                        due: None,
+                    });
+                // Kicks spaced far apart start the backoff over
+                if now.duration_since(rejoin.last_kick) >= REJOIN_RESET_WINDOW {
+                    rejoin.attempts = 0;
+                }
+                rejoin.last_kick = now;
+
+                if rejoin.attempts >= MAX_REJOIN_ATTEMPTS {
+                    self.channel_rejoins.remove(&key);
+                    (
+                        ChannelStatus::Degraded,
+                        format!(
+                            "[{}] Kicked again after {} rejoins, giving up",
+                            notify.channel_name, MAX_REJOIN_ATTEMPTS
+                        ),
+                    )
+                } else {
+                    let delay = REJOIN_BASE_DELAY * 2u32.pow(rejoin.attempts);
+                    rejoin.attempts += 1;
+                    rejoin.due = Some(now + delay);
+                    (
+                        ChannelStatus::Rejoining,
+                        format!(
+                            "[{}] Kicked from channel, rejoining in {}s",
+                            notify.channel_name,
+                            delay.as_secs()
+                        ),
+                    )
+                }
+            }
+            chat_notify::CHAT_PLAYER_BANNED_NOTICE if about_self => {
+                self.channel_rejoins.remove(&key);
+                (
+                    ChannelStatus::Degraded,
+                    format!("[{}] Banned from channel", notify.channel_name),
+                )
+            }
+            chat_notify::CHAT_WRONG_PASSWORD_NOTICE
+            | chat_notify::CHAT_BANNED_NOTICE
+            | chat_notify::CHAT_WRONG_FACTION_NOTICE
+            | chat_notify::CHAT_INVALID_NAME_NOTICE
+            | chat_notify::CHAT_NOT_IN_AREA_NOTICE
+            | chat_notify::CHAT_NOT_IN_LFG_NOTICE => {
+                self.channel_rejoins.remove(&key);
+                (ChannelStatus::Degraded, desc)
+            }
+            chat_notify::CHAT_THROTTLED_NOTICE | chat_notify::CHAT_MUTED_NOTICE => {
+                (ChannelStatus::Notice, desc)
+            }
+            _ => return None,
+        };
+
+        Some(ChannelEvent {
+            channel_name: notify.channel_name.clone(),
+            status,
+            message,
+        })
+    }
+
+    /// Take channels whose rejoin backoff has elapsed.
+    pub fn take_due_channel_rejoins(&mut self) -> Vec<String> {
+        let now = std::time::Instant::now();
+        self.channel_rejoins
+            .values_mut()
+            .filter(|rejoin| rejoin.due.is_some_and(|due| due <= now))
+            .map(|rejoin| {
+                rejoin.due = None;
+                rejoin.channel_name.clone()
+            })
+            .collect()
     }
 
     /// Build CMSG_JOIN_CHANNEL packet.
-    pub fn build_join_channel(&self, channel_name: &str) -> JoinChannelWotLK {
+    pub fn build_join_channel(
+        &self,
+        channel_name: &str,
+        password: Option<&str>,
+    ) -> JoinChannelWotLK {
         use super::chat::channel_ids;
         JoinChannelWotLK {
             channel_id: channel_ids::get_channel_id(channel_name),
             channel_name: channel_name.to_string(),
+            password: password.unwrap_or_default().to_string(),
+        }
+    }
+
+    /// Build CMSG_LEAVE_CHANNEL packet.
+    pub fn build_leave_channel(&self, channel_name: &str) -> LeaveChannel {
+        LeaveChannel {
+            channel_name: channel_name.to_string(),
+        }
+    }
+
+    /// Build a join for a !channel join command and remember who asked for it.
+    pub fn request_channel_join(
+        &mut self,
+        channel: CustomChannel,
+        reply_channel: u64,
+    ) -> JoinChannelWotLK {
+        let join = self.build_join_channel(&channel.name, channel.password.as_deref());
+        self.pending_channel_commands.insert(
+            channel.name.to_lowercase(),
+            (reply_channel, PendingChannelCommand::Join(channel)),
+        );
+        join
+    }
+
+    /// Build a leave for a !channel leave command and remember who asked for it.
+    pub fn request_channel_leave(
+        &mut self,
+        channel_name: &str,
+        reply_channel: u64,
+    ) -> LeaveChannel {
+        self.pending_channel_commands.insert(
+            channel_name.to_lowercase(),
+            (reply_channel, PendingChannelCommand::Leave),
+        );
+        self.build_leave_channel(channel_name)
+    }
+
+    /// Build a moderation packet for a !channel command and remember who asked for it.
+    pub fn request_channel_moderation(
+        &mut self,
+        channel_name: &str,
+        action: ChannelModeration,
+        reply_channel: u64,
+    ) -> ChannelModerate {
+        let pending = match &action {
+            ChannelModeration::Password(password) => {
+                PendingChannelCommand::Password(password.clone())
+            }
+            _ => PendingChannelCommand::Moderate,
+        };
+        self.pending_channel_commands
+            .insert(channel_name.to_lowercase(), (reply_channel, pending));
+        ChannelModerate {
+            channel_name: channel_name.to_string(),
+            action,
         }
     }
 
@@ -436,6 +1305,28 @@ impl GameHandler {
             Some(roster.motd.clone())
         };
 
+        self.guild_info_text = if roster.guild_info.is_empty() {
+            None
+        } else {
+            Some(roster.guild_info.clone())
+        };
+
+        if self
+            .pending_info_update
+            .as_ref()
+            .is_some_and(|p| p.text.trim() == roster.guild_info.trim())
+        {
+            if let Some(pending) = self.pending_info_update.take() {
+                self.guild_command_replies.push(GuildCommandReply {
+                    reply_channel: pending.reply_channel,
+                    message: "Guild information updated.".to_string(),
+                    success: true,
+                });
+            }
+        }
+
+        self.guild_rank_rights = roster.rank_rights;
+
         // Convert to common types
         self.guild_roster.clear();
         for member in roster.members {
@@ -448,8 +1339,11 @@ impl GameHandler {
 
             self.guild_roster
                 .insert(member.guid, member.to_guild_member(&rank_name));
+            self.update_cached_level(member.guid, member.level);
         }
 
+        self.resolve_guild_note_updates();
+
         // Update last request timestamp
         self.last_roster_request = Some(std::time::Instant::now());
 
@@ -530,6 +1424,23 @@ impl GameHandler {
             }
         };
 
+        if let Some(ref target) = target_name {
+            self.resolve_guild_command_event(event.event_type, target, rank_name.as_deref());
+        }
+
+        // Remember members whose roster change this event already announces
+        use crate::protocol::game::guild::guild_events;
+        let member = match event.event_type {
+            guild_events::GE_JOINED | guild_events::GE_LEFT => player_name.as_ref(),
+            guild_events::GE_REMOVED | guild_events::GE_PROMOTED | guild_events::GE_DEMOTED => {
+                target_name.as_ref()
+            }
+            _ => None,
+        };
+        if let Some(member) = member {
+            self.guild_event_members.insert(member.to_lowercase());
+        }
+
         // Skip events from self (except MOTD)
         if event.event_type != crate::protocol::game::guild::guild_events::GE_MOTD {
             if let Some(player) = &self.player {
@@ -539,30 +1450,560 @@ impl GameHandler {
                     }
                 }
             }
-        }
+        }
+
+        // Update MOTD if this is a MOTD event
+        if let Some(motd) = event.motd() {
+            self.guild_motd = Some(motd.to_string());
+
+            if self
+                .pending_motd_update
+                .as_ref()
+                .is_some_and(|p| p.text.trim() == motd.trim())
+            {
+                if let Some(pending) = self.pending_motd_update.take() {
+                    self.guild_command_replies.push(GuildCommandReply {
+                        reply_channel: pending.reply_channel,
+                        message: "Guild MOTD updated.".to_string(),
+                        success: true,
+                    });
+                }
+            }
+        }
+
+        // For MOTD events, player_name can be None (no player in packet)
+        // Use empty string in that case
+        let player_name = match player_name {
+            Some(name) => name,
+            None if event.event_type == crate::protocol::game::guild::guild_events::GE_MOTD => {
+                String::new()
+            }
+            None => return Ok(None),
+        };
+
+        Ok(Some(GuildEventInfo {
+            event_name,
+            player_name,
+            target_name,
+            rank_name,
+            achievement_id: None,
+        }))
+    }
+
+    /// Take the names announced by guild events since the last call.
+    pub fn take_guild_event_members(&mut self) -> HashSet<String> {
+        std::mem::take(&mut self.guild_event_members)
+    }
+
+    // =========================================================================
+    // Guild management
+    // =========================================================================
+
+    /// Check whether the bot's own guild rank has a right (see `guild_rights`).
+    pub fn has_guild_right(&self, right: u32) -> bool {
+        let Some(member) = self.self_guid.and_then(|guid| self.guild_roster.get(&guid)) else {
+            return false;
+        };
+        // Guild master has every right
+        member.rank == 0
+            || self
+                .guild_rank_rights
+                .get(member.rank as usize)
+                .is_some_and(|rights| rights & right == right)
+    }
+
+    /// Build a guild invite/remove/promote/demote command after checking the bot's rank rights.
+    /// The answer arrives as SMSG_GUILD_COMMAND_RESULT or a guild event.
+    pub fn request_guild_member_command(
+        &mut self,
+        action: GuildMemberAction,
+        name: &str,
+        reply_channel: u64,
+    ) -> Result<GuildMemberCommand> {
+        if self.guild_id == 0 {
+            return Err(anyhow!("The bot is not in a guild."));
+        }
+
+        let (right, doing) = match action {
+            GuildMemberAction::Invite => (guild_rights::GR_RIGHT_INVITE, "inviting players"),
+            GuildMemberAction::Remove => (guild_rights::GR_RIGHT_REMOVE, "removing members"),
+            GuildMemberAction::Promote => (guild_rights::GR_RIGHT_PROMOTE, "promoting members"),
+            GuildMemberAction::Demote => (guild_rights::GR_RIGHT_DEMOTE, "demoting members"),
+        };
+        if !self.has_guild_right(right) {
+            return Err(anyhow!("The bot's guild rank does not allow {}.", doing));
+        }
+
+        self.pending_guild_commands.push(PendingGuildCommand {
+            reply_channel,
+            action,
+            name: name.to_string(),
+            sent: std::time::Instant::now(),
+        });
+        Ok(GuildMemberCommand {
+            action,
+            name: name.to_string(),
+        })
+    }
+
+    /// Build a guild MOTD change after checking the length and the bot's rank rights.
+    /// Confirmed by the GE_MOTD guild event the server broadcasts.
+    ///
+    /// Returns None if the MOTD is already set to `text`: the server would not
+    /// broadcast anything, so the success reply is queued right away.
+    pub fn request_set_guild_motd(
+        &mut self,
+        text: &str,
+        reply_channel: u64,
+    ) -> Result<Option<GuildSetMotd>> {
+        self.check_guild_text(
+            text,
+            MAX_GUILD_MOTD_LENGTH,
+            guild_rights::GR_RIGHT_SETMOTD,
+            "setting the MOTD",
+        )?;
+        if self.pending_motd_update.is_some() {
+            return Err(anyhow!("A MOTD change is already in progress."));
+        }
+        if self.guild_motd.as_deref().unwrap_or_default().trim() == text.trim() {
+            self.guild_command_replies.push(GuildCommandReply {
+                reply_channel,
+                message: "Guild MOTD updated.".to_string(),
+                success: true,
+            });
+            return Ok(None);
+        }
+
+        self.pending_motd_update = Some(PendingGuildText {
+            reply_channel,
+            text: text.to_string(),
+            sent: std::time::Instant::now(),
+        });
+        Ok(Some(GuildSetMotd {
+            motd: text.to_string(),
+        }))
+    }
+
+    /// Build a guild information change after checking the length and the bot's rank rights.
+    /// The server does not announce it, so the caller should request the roster afterwards.
+    ///
+    /// Returns None if the information is already `text`; the success reply is
+    /// queued right away.
+    pub fn request_set_guild_info(
+        &mut self,
+        text: &str,
+        reply_channel: u64,
+    ) -> Result<Option<GuildSetInfoText>> {
+        self.check_guild_text(
+            text,
+            MAX_GUILD_INFO_LENGTH,
+            guild_rights::GR_RIGHT_MODIFY_GUILD_INFO,
+            "editing the guild information",
+        )?;
+        if self.pending_info_update.is_some() {
+            return Err(anyhow!(
+                "A guild information change is already in progress."
+            ));
+        }
+        if self.guild_info_text.as_deref().unwrap_or_default().trim() == text.trim() {
+            self.guild_command_replies.push(GuildCommandReply {
+                reply_channel,
+                message: "Guild information updated.".to_string(),
+                success: true,
+            });
+            return Ok(None);
+        }
+
+        self.pending_info_update = Some(PendingGuildText {
+            reply_channel,
+            text: text.to_string(),
+            sent: std::time::Instant::now(),
+        });
+        Ok(Some(GuildSetInfoText {
+            info: text.to_string(),
+        }))
+    }
+
+    fn check_guild_text(&self, text: &str, max_len: usize, right: u32, doing: &str) -> Result<()> {
+        if self.guild_id == 0 {
+            return Err(anyhow!("The bot is not in a guild."));
+        }
+        let len = text.chars().count();
+        if len > max_len {
+            return Err(anyhow!(
+                "Text is too long ({} characters, the limit is {}).",
+                len,
+                max_len
+            ));
+        }
+        if !self.has_guild_right(right) {
+            return Err(anyhow!("The bot's guild rank does not allow {}.", doing));
+        }
+        Ok(())
+    }
+
+    /// Build a public or officer note change after checking the member, the length
+    /// and the bot's rank rights. The server answers with a fresh roster.
+    pub fn request_set_guild_note(
+        &mut self,
+        name: &str,
+        note: &str,
+        officer: bool,
+        reply_channel: u64,
+    ) -> Result<GuildSetNote> {
+        let (right, doing) = if officer {
+            (guild_rights::GR_RIGHT_EOFFNOTE, "editing officer notes")
+        } else {
+            (guild_rights::GR_RIGHT_EPNOTE, "editing public notes")
+        };
+        self.check_guild_text(note, MAX_GUILD_NOTE_LENGTH, right, doing)?;
+        let member = self
+            .search_guild_member(name)
+            .ok_or_else(|| anyhow!("{} is not in the guild.", name))?;
+
+        self.pending_note_updates.push(PendingGuildNote {
+            reply_channel,
+            name: member.name.clone(),
+            note: note.to_string(),
+            officer,
+            sent: std::time::Instant::now(),
+        });
+        Ok(GuildSetNote {
+            name: member.name,
+            note: note.to_string(),
+            officer,
+        })
+    }
+
+    /// Resolve pending note changes that the current roster reflects.
+    fn resolve_guild_note_updates(&mut self) {
+        let (done, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_note_updates)
+            .into_iter()
+            .partition(|p| {
+                self.guild_roster.values().any(|m| {
+                    let current = if p.officer { &m.officer_note } else { &m.note };
+                    m.name.eq_ignore_ascii_case(&p.name) && current.trim() == p.note.trim()
+                })
+            });
+        self.pending_note_updates = pending;
+
+        for p in done {
+            let kind = if p.officer { "officer note" } else { "note" };
+            let message = if p.note.is_empty() {
+                format!("Cleared {}'s {}.", p.name, kind)
+            } else {
+                format!("Updated {}'s {}.", p.name, kind)
+            };
+            self.guild_command_replies.push(GuildCommandReply {
+                reply_channel: p.reply_channel,
+                message,
+                success: true,
+            });
+        }
+    }
+
+    /// Build MSG_GUILD_EVENT_LOG_QUERY, for !guildlog when `reply` is set.
+    pub fn request_guild_event_log(&mut self, reply: Option<(u64, usize)>) -> GuildEventLogQuery {
+        if let Some((reply_channel, count)) = reply {
+            self.pending_guild_log_requests.push_back((
+                reply_channel,
+                count,
+                std::time::Instant::now(),
+            ));
+        }
+        GuildEventLogQuery
+    }
+
+    /// Take !guildlog requests the server did not answer in time.
+    /// Returns their reply channels.
+    pub fn take_expired_guild_log_requests(&mut self) -> Vec<u64> {
+        let mut expired = Vec::new();
+        while let Some((_, _, sent)) = self.pending_guild_log_requests.front() {
+            if sent.elapsed() < GUILD_LOG_TIMEOUT {
+                break;
+            }
+            if let Some((reply_channel, _, _)) = self.pending_guild_log_requests.pop_front() {
+                expired.push(reply_channel);
+            }
+        }
+        expired
+    }
+
+    /// Handle MSG_GUILD_EVENT_LOG_QUERY.
+    /// Queues pending !guildlog replies until their names resolve.
+    pub fn handle_guild_event_log(&mut self, mut payload: Bytes) -> Result<GuildEventLogResult> {
+        let log = GuildEventLog::decode(&mut payload)?;
+        debug!("Guild event log received: {} entries", log.entries.len());
+
+        let now = unix_time();
+        let records: Vec<GuildLogRecord> = log
+            .entries
+            .into_iter()
+            .map(|entry| GuildLogRecord {
+                time: now.saturating_sub(entry.seconds_ago as u64),
+                entry,
+            })
+            .collect();
+
+        let mut unnamed = Vec::new();
+        for r in &records {
+            for guid in std::iter::once(r.entry.player_guid).chain(r.entry.target_guid) {
+                if self.member_name(guid).is_none() && self.pending_name_queries.insert(guid) {
+                    unnamed.push(guid);
+                }
+            }
+        }
+
+        let received = std::time::Instant::now();
+        for (reply_channel, count, _) in self.pending_guild_log_requests.drain(..) {
+            let skip = records.len().saturating_sub(count);
+            self.pending_guild_logs.push(PendingGuildLog {
+                reply_channel: Some(reply_channel),
+                records: records[skip..].to_vec(),
+                received,
+            });
+        }
+
+        let first_since_login = !self.guild_log_synced;
+        self.guild_log_synced = true;
+        Ok(GuildEventLogResult {
+            records,
+            first_since_login,
+            unnamed,
+        })
+    }
+
+    /// Queue guild log records missed while offline, to be posted through the bridge.
+    pub fn queue_missed_guild_events(&mut self, records: Vec<GuildLogRecord>) {
+        if records.is_empty() {
+            return;
+        }
+        info!("Found {} guild events missed while offline", records.len());
+        self.pending_guild_logs.push(PendingGuildLog {
+            reply_channel: None,
+            records,
+            received: std::time::Instant::now(),
+        });
+    }
+
+    /// Take guild logs whose names are all resolved (or whose wait timed out).
+    pub fn take_ready_guild_logs(&mut self) -> Vec<ReadyGuildLog> {
+        let (ready, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_guild_logs)
+            .into_iter()
+            .partition(|log| {
+                log.received.elapsed() >= MEMBER_NAME_TIMEOUT
+                    || log.records.iter().all(|r| {
+                        std::iter::once(r.entry.player_guid)
+                            .chain(r.entry.target_guid)
+                            .all(|guid| self.member_name(guid).is_some())
+                    })
+            });
+        self.pending_guild_logs = waiting;
+
+        let now = unix_time();
+        ready
+            .into_iter()
+            .map(|log| {
+                let entries = log
+                    .records
+                    .iter()
+                    .filter(|r| log.reply_channel.is_some() || !self.is_own_guild_log_entry(r))
+                    .map(|r| GuildLogEntryInfo {
+                        event: self.guild_log_event_info(&r.entry),
+                        seconds_ago: now.saturating_sub(r.time),
+                    })
+                    .collect();
+                ReadyGuildLog {
+                    reply_channel: log.reply_channel,
+                    entries,
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the bot itself caused a guild log entry (never posted as missed).
+    fn is_own_guild_log_entry(&self, record: &GuildLogRecord) -> bool {
+        self.self_guid == Some(record.entry.player_guid)
+    }
+
+    /// Convert a guild log entry into the matching guild event.
+    fn guild_log_event_info(&self, entry: &GuildEventLogEntry) -> GuildEventInfo {
+        use guild_log_events::*;
+
+        let name = |guid: u64| {
+            self.member_name(guid)
+                .unwrap_or_else(|| "Unknown".to_string())
+        };
+        let event_name = match entry.event_type {
+            GUILD_EVENT_LOG_INVITE_PLAYER => "invited",
+            GUILD_EVENT_LOG_JOIN_GUILD => "joined",
+            GUILD_EVENT_LOG_PROMOTE_PLAYER => "promoted",
+            GUILD_EVENT_LOG_DEMOTE_PLAYER => "demoted",
+            GUILD_EVENT_LOG_UNINVITE_PLAYER => "removed",
+            GUILD_EVENT_LOG_LEAVE_GUILD => "left",
+            _ => "unknown",
+        };
+        let rank_name = entry.rank.map(|rank| {
+            self.guild_info
+                .as_ref()
+                .and_then(|info| info.ranks.get(rank as usize))
+                .cloned()
+                .unwrap_or_else(|| format!("Rank {}", rank))
+        });
+
+        GuildEventInfo {
+            event_name: event_name.to_string(),
+            player_name: name(entry.player_guid),
+            target_name: entry.target_guid.map(name),
+            rank_name,
+            achievement_id: None,
+        }
+    }
+
+    /// Handle SMSG_GUILD_COMMAND_RESULT.
+    pub fn handle_guild_command_result(&mut self, mut payload: Bytes) -> Result<()> {
+        let result = GuildCommandResult::decode(&mut payload)?;
+        debug!(
+            "Guild command {} for '{}' returned {}",
+            result.command, result.name, result.result
+        );
+
+        let action = match result.command {
+            guild_commands::GUILD_COMMAND_INVITE => GuildMemberAction::Invite,
+            guild_commands::GUILD_COMMAND_REMOVE => GuildMemberAction::Remove,
+            guild_commands::GUILD_COMMAND_PROMOTE => GuildMemberAction::Promote,
+            guild_commands::GUILD_COMMAND_DEMOTE => GuildMemberAction::Demote,
+            guild_commands::GUILD_COMMAND_EDIT_MOTD => {
+                if result.result != guild_command_errors::ERR_GUILD_COMMAND_SUCCESS {
+                    if let Some(pending) = self.pending_motd_update.take() {
+                        self.guild_command_replies.push(GuildCommandReply {
+                            reply_channel: pending.reply_channel,
+                            message: guild_command_errors::description(result.result, ""),
+                            success: false,
+                        });
+                    }
+                }
+                return Ok(());
+            }
+            guild_commands::GUILD_COMMAND_PUBLIC_NOTE => {
+                if result.result != guild_command_errors::ERR_GUILD_COMMAND_SUCCESS {
+                    let index = self.pending_note_updates.iter().position(|p| {
+                        result.name.is_empty() || p.name.eq_ignore_ascii_case(&result.name)
+                    });
+                    if let Some(index) = index {
+                        let pending = self.pending_note_updates.remove(index);
+                        self.guild_command_replies.push(GuildCommandReply {
+                            reply_channel: pending.reply_channel,
+                            message: guild_command_errors::description(
+                                result.result,
+                                &pending.name,
+                            ),
+                            success: false,
+                        });
+                    }
+                }
+                return Ok(());
+            }
+            _ => return Ok(()),
+        };
+        let Some(pending) = self.take_pending_guild_command(action, &result.name) else {
+            return Ok(());
+        };
 
-        // Update MOTD if this is a MOTD event
-        if let Some(motd) = event.motd() {
-            self.guild_motd = Some(motd.to_string());
-        }
+        let success = result.result == guild_command_errors::ERR_GUILD_COMMAND_SUCCESS;
+        let message = if success && action == GuildMemberAction::Invite {
+            format!("Invited {} to the guild.", pending.name)
+        } else {
+            guild_command_errors::description(result.result, &pending.name)
+        };
+        self.guild_command_replies.push(GuildCommandReply {
+            reply_channel: pending.reply_channel,
+            message,
+            success,
+        });
+        Ok(())
+    }
 
-        // For MOTD events, player_name can be None (no player in packet)
-        // Use empty string in that case
-        let player_name = match player_name {
-            Some(name) => name,
-            None if event.event_type == crate::protocol::game::guild::guild_events::GE_MOTD => {
-                String::new()
-            }
-            None => return Ok(None),
+    /// Resolve a pending remove/promote/demote from the guild event it caused.
+    fn resolve_guild_command_event(&mut self, event_type: u8, target: &str, rank: Option<&str>) {
+        use crate::protocol::game::guild::guild_events;
+
+        let action = match event_type {
+            guild_events::GE_PROMOTED => GuildMemberAction::Promote,
+            guild_events::GE_DEMOTED => GuildMemberAction::Demote,
+            guild_events::GE_REMOVED => GuildMemberAction::Remove,
+            _ => return,
+        };
+        let Some(pending) = self.take_pending_guild_command(action, target) else {
+            return;
         };
 
-        Ok(Some(GuildEventInfo {
-            event_name,
-            player_name,
-            target_name,
-            rank_name,
-            achievement_id: None,
-        }))
+        let rank = rank.unwrap_or("a new rank");
+        let message = match action {
+            GuildMemberAction::Promote => format!("Promoted {} to {}.", target, rank),
+            GuildMemberAction::Demote => format!("Demoted {} to {}.", target, rank),
+            _ => format!("Removed {} from the guild.", target),
+        };
+        self.guild_command_replies.push(GuildCommandReply {
+            reply_channel: pending.reply_channel,
+            message,
+            success: true,
+        });
+    }
+
+    /// Remove the oldest pending command for an action and player name.
+    /// An empty name (results without a name) matches any player.
+    fn take_pending_guild_command(
+        &mut self,
+        action: GuildMemberAction,
+        name: &str,
+    ) -> Option<PendingGuildCommand> {
+        let index = self.pending_guild_commands.iter().position(|p| {
+            p.action == action && (name.is_empty() || p.name.eq_ignore_ascii_case(name))
+        })?;
+        Some(self.pending_guild_commands.remove(index))
+    }
+
+    /// Take resolved !guild commands, timing out those the server never answered.
+    pub fn take_guild_command_replies(&mut self) -> Vec<GuildCommandReply> {
+        let (expired, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_guild_commands)
+            .into_iter()
+            .partition(|p| p.sent.elapsed() >= GUILD_COMMAND_TIMEOUT);
+        self.pending_guild_commands = pending;
+
+        for p in expired {
+            self.guild_command_replies.push(GuildCommandReply {
+                reply_channel: p.reply_channel,
+                message: format!("No answer from the server for {}.", p.name),
+                success: false,
+            });
+        }
+        let (expired, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_note_updates)
+            .into_iter()
+            .partition(|p| p.sent.elapsed() >= GUILD_COMMAND_TIMEOUT);
+        self.pending_note_updates = pending;
+
+        for p in expired {
+            self.guild_command_replies.push(GuildCommandReply {
+                reply_channel: p.reply_channel,
+                message: format!("No answer from the server for {}'s note.", p.name),
+                success: false,
+            });
+        }
+        for slot in [&mut self.pending_motd_update, &mut self.pending_info_update] {
+            let expired = match slot {
+                Some(p) if p.sent.elapsed() >= GUILD_COMMAND_TIMEOUT => slot.take(),
+                _ => None,
+            };
+            if let Some(p) = expired {
+                self.guild_command_replies.push(GuildCommandReply {
+                    reply_channel: p.reply_channel,
+                    message: "No answer from the server for the guild text change.".to_string(),
+                    success: false,
+                });
+            }
+        }
+        std::mem::take(&mut self.guild_command_replies)
     }
 
     /// Build CMSG_GUILD_QUERY packet.
@@ -596,6 +2037,49 @@ impl GameHandler {
         online
     }
 
+    /// Get all guild members, online or not, sorted by name.
+    pub fn get_guildies(&self) -> Vec<GuildMember> {
+        let mut members: Vec<_> = self
+            .guild_roster
+            .values()
+            .filter(|m| !m.name.eq_ignore_ascii_case(&self.character_name))
+            .cloned()
+            .collect();
+
+        members.sort_by(|a, b| a.name.cmp(&b.name));
+        members
+    }
+
+    /// Get the whole guild roster, including the bot, sorted by name.
+    pub fn get_roster(&self) -> Vec<GuildMember> {
+        let mut members: Vec<_> = self.guild_roster.values().cloned().collect();
+        members.sort_by(|a, b| a.name.cmp(&b.name));
+        members
+    }
+
+    /// Get offline guild members not seen for at least `days`, longest absent first.
+    /// With `rank`, only members of that rank (case-insensitive) are listed.
+    pub fn get_inactive_guildies(&self, days: u32, rank: Option<&str>) -> Vec<GuildMember> {
+        let mut inactive: Vec<_> = self
+            .guild_roster
+            .values()
+            .filter(|m| !m.online && m.last_logoff >= days as f32)
+            .filter(|m| match rank {
+                Some(r) => m.rank_name.eq_ignore_ascii_case(r),
+                None => true,
+            })
+            .filter(|m| !m.name.eq_ignore_ascii_case(&self.character_name))
+            .cloned()
+            .collect();
+
+        inactive.sort_by(|a, b| {
+            b.last_logoff
+                .total_cmp(&a.last_logoff)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        inactive
+    }
+
     /// Search for a guild member by name (case-insensitive).
     pub fn search_guild_member(&self, search_name: &str) -> Option<GuildMember> {
         let search_lower = search_name.to_lowercase();
@@ -631,6 +2115,77 @@ impl GameHandler {
         None
     }
 
+    // =========================================================================
+    // Server-wide WHO
+    // =========================================================================
+
+    /// Build a CMSG_WHO query by name and remember who asked for it.
+    /// The server answers WHO queries in order, so replies are matched FIFO.
+    pub fn request_who(&mut self, search_name: &str, reply_channel: u64) -> WhoRequest {
+        self.pending_who_requests.push_back((
+            reply_channel,
+            search_name.to_string(),
+            std::time::Instant::now(),
+        ));
+        WhoRequest::by_name(search_name)
+    }
+
+    /// Take WHO queries the server did not answer in time, so a late reply is
+    /// not paired with a later query. Returns (reply channel, search input).
+    pub fn take_expired_who_requests(&mut self) -> Vec<(u64, String)> {
+        let mut expired = Vec::new();
+        while let Some((_, _, sent)) = self.pending_who_requests.front() {
+            if sent.elapsed() < WHO_TIMEOUT {
+                break;
+            }
+            if let Some((reply_channel, search_name, _)) = self.pending_who_requests.pop_front() {
+                expired.push((reply_channel, search_name));
+            }
+        }
+        expired
+    }
+
+    /// Handle SMSG_WHO.
+    /// Returns the reply channel, original search input and matched players
+    /// for the oldest in-flight query, or None if nothing was pending.
+    pub fn handle_who(
+        &mut self,
+        mut payload: Bytes,
+    ) -> Result<Option<(u64, String, Vec<WhoPlayer>)>> {
+        let response = WhoResponse::decode(&mut payload)?;
+
+        let Some((reply_channel, search_name, _)) = self.pending_who_requests.pop_front() else {
+            debug!(
+                "Ignoring unsolicited SMSG_WHO with {} entries",
+                response.entries.len()
+            );
+            return Ok(None);
+        };
+
+        debug!(
+            "WHO '{}': {} shown of {} matches",
+            search_name,
+            response.entries.len(),
+            response.match_count
+        );
+
+        // WHO results carry no GUID, so cached players are matched by name
+        for entry in &response.entries {
+            let level = entry.level.min(u8::MAX as u32) as u8;
+            let guid = self
+                .player_names
+                .iter()
+                .find(|(_, p)| p.name.eq_ignore_ascii_case(&entry.name))
+                .map(|(&guid, _)| guid);
+            if let Some(guid) = guid {
+                self.update_cached_level(guid, level);
+            }
+        }
+
+        let players = response.entries.iter().map(|e| e.to_who_player()).collect();
+        Ok(Some((reply_channel, search_name, players)))
+    }
+
     /// Get guild MOTD.
     pub fn get_guild_motd(&self) -> Option<&str> {
         self.guild_motd.as_deref()
@@ -644,11 +2199,205 @@ impl GameHandler {
                 .as_ref()
                 .map_or(String::new(), |g| g.name.clone()),
             motd: self.guild_motd.clone().unwrap_or_default(),
-            info: String::new(),
+            info: self.guild_info_text.clone().unwrap_or_default(),
             members: self.guild_roster.values().cloned().collect(),
         }
     }
 
+    // =========================================================================
+    // Groups
+    // =========================================================================
+
+    /// Handle SMSG_GROUP_INVITE.
+    /// Returns None when the server already refused the invite because the bot is grouped.
+    pub fn handle_group_invite(&self, mut payload: Bytes) -> Result<Option<SocialRequestInfo>> {
+        let invite = GroupInvite::decode(&mut payload)?;
+        if !invite.can_accept {
+            debug!("Group invite from {} while already grouped", invite.inviter);
+            return Ok(None);
+        }
+        Ok(Some(SocialRequestInfo {
+            request: SocialRequest::Group,
+            player: invite.inviter,
+            unnamed_guid: None,
+            target_guid: 0,
+        }))
+    }
+
+    /// Handle SMSG_GROUP_LIST.
+    pub fn handle_group_list(&mut self, mut payload: Bytes) -> Result<()> {
+        let list = GroupList::decode(&mut payload)?;
+        if list.members.is_empty() {
+            if self.group.take().is_some() {
+                info!("Left group");
+            }
+            return Ok(());
+        }
+
+        if self.group.is_none() {
+            let names: Vec<&str> = list.members.iter().map(|m| m.name.as_str()).collect();
+            info!(
+                "Joined {} with {}",
+                if list.is_raid() { "raid" } else { "party" },
+                names.join(", ")
+            );
+        }
+        self.group = Some(list);
+        Ok(())
+    }
+
+    /// Handle SMSG_GROUP_DESTROYED and SMSG_GROUP_UNINVITE.
+    pub fn handle_group_removed(&mut self) {
+        if self.group.take().is_some() {
+            info!("Group disbanded or bot was removed from it");
+        }
+    }
+
+    /// Build CMSG_GROUP_DISBAND to leave the current group.
+    pub fn build_group_leave(&self) -> Result<GroupLeave> {
+        if self.group.is_none() {
+            return Err(anyhow!("Not in a group."));
+        }
+        Ok(GroupLeave)
+    }
+
+    /// Build CMSG_GROUP_UNINVITE_GUID for every other member of the group.
+    /// Only the group leader may do this; the server disbands the group once the bot is alone.
+    pub fn build_group_disband(&self) -> Result<Vec<GroupUninvite>> {
+        let Some(group) = &self.group else {
+            return Err(anyhow!("Not in a group."));
+        };
+        if self.self_guid != Some(group.leader_guid) {
+            return Err(anyhow!("Only the group leader can disband the group."));
+        }
+
+        Ok(group
+            .members
+            .iter()
+            .map(|m| GroupUninvite {
+                guid: m.guid,
+                reason: String::new(),
+            })
+            .collect())
+    }
+
+    // =========================================================================
+    // Social requests
+    // =========================================================================
+
+    /// Handle SMSG_DUEL_REQUESTED.
+    pub fn handle_duel_requested(&self, mut payload: Bytes) -> Result<SocialRequestInfo> {
+        let duel = DuelRequested::decode(&mut payload)?;
+        Ok(SocialRequestInfo {
+            request: SocialRequest::Duel,
+            player: self.requester_name(duel.initiator_guid),
+            unnamed_guid: self.unnamed(duel.initiator_guid),
+            target_guid: duel.arbiter_guid,
+        })
+    }
+
+    /// Handle SMSG_TRADE_STATUS.
+    /// Returns a request only when another player proposes a trade.
+    pub fn handle_trade_status(&self, mut payload: Bytes) -> Result<Option<SocialRequestInfo>> {
+        let status = TradeStatus::decode(&mut payload)?;
+        if status.initiator_guid.is_none() {
+            debug!("Trade status {}", status.status);
+        }
+        Ok(status.initiator_guid.map(|guid| SocialRequestInfo {
+            request: SocialRequest::Trade,
+            player: self.requester_name(guid),
+            unnamed_guid: self.unnamed(guid),
+            target_guid: 0,
+        }))
+    }
+
+    /// Handle SMSG_GUILD_INVITE.
+    pub fn handle_guild_invite(&self, mut payload: Bytes) -> Result<SocialRequestInfo> {
+        let invite = GuildInvite::decode(&mut payload)?;
+        debug!(
+            "Guild invite from {} to <{}>",
+            invite.inviter, invite.guild_name
+        );
+        Ok(SocialRequestInfo {
+            request: SocialRequest::Guild,
+            player: invite.inviter,
+            unnamed_guid: None,
+            target_guid: 0,
+        })
+    }
+
+    /// Handle SMSG_PETITION_SHOW_SIGNATURES (a player offered a charter to sign).
+    pub fn handle_petition_offer(&self, mut payload: Bytes) -> Result<SocialRequestInfo> {
+        let petition = PetitionShowSignatures::decode(&mut payload)?;
+        Ok(SocialRequestInfo {
+            request: SocialRequest::Petition,
+            player: self.requester_name(petition.owner_guid),
+            unnamed_guid: self.unnamed(petition.owner_guid),
+            target_guid: petition.petition_guid,
+        })
+    }
+
+    /// Build the packet accepting or declining a social request.
+    pub fn build_social_response(&self, info: &SocialRequestInfo, accept: bool) -> Packet {
+        match info.request {
+            SocialRequest::Duel => DuelResponse {
+                arbiter_guid: info.target_guid,
+                accept,
+            }
+            .into(),
+            SocialRequest::Trade => TradeResponse { accept }.into(),
+            SocialRequest::Group if accept => GroupAccept.into(),
+            SocialRequest::Group => GroupDecline.into(),
+            SocialRequest::Guild => GuildInviteResponse { accept }.into(),
+            SocialRequest::Petition => PetitionResponse {
+                petition_guid: info.target_guid,
+                accept,
+            }
+            .into(),
+        }
+    }
+
+    /// Name of the player behind a social request, if known.
+    fn requester_name(&self, guid: Guid) -> String {
+        self.member_name(guid)
+            .unwrap_or_else(|| "An unknown player".to_string())
+    }
+
+    /// The GUID itself if its name still has to be queried.
+    fn unnamed(&self, guid: Guid) -> Option<Guid> {
+        self.member_name(guid).is_none().then_some(guid)
+    }
+
+    /// Hold a social request notice until the requester's name resolves.
+    ///
+    /// Uses the same name query deadline and retry as chat messages. Held
+    /// notices come back from `take_ready_social_notices`.
+    pub fn hold_social_notice(&mut self, guid: Guid, notice: SocialNotice) {
+        self.track_pending_name(guid);
+        self.pending_social_notices
+            .entry(guid)
+            .or_default()
+            .push(notice);
+    }
+
+    /// Take held social notices whose requester name resolved or timed out.
+    pub fn take_ready_social_notices(&mut self) -> Vec<SocialNotice> {
+        std::mem::take(&mut self.ready_social_notices)
+    }
+
+    /// Release the notices held for a GUID, under `name` if one is known.
+    fn release_social_notices(&mut self, guid: Guid, name: Option<String>) {
+        let Some(notices) = self.pending_social_notices.remove(&guid) else {
+            return;
+        };
+        for mut notice in notices {
+            if let Some(name) = &name {
+                notice.player = name.clone();
+            }
+            self.ready_social_notices.push(notice);
+        }
+    }
+
     // =========================================================================
     // Server notification messages
     // =========================================================================
@@ -820,6 +2569,7 @@ impl GameHandler {
 
         let packet = InvalidatePlayer::decode(&mut payload)?;
         if self.player_names.pop(&packet.guid).is_some() {
+            self.player_names_changed = true;
             debug!(
                 "Removed player {} from name cache (SMSG_INVALIDATE_PLAYER)",
                 packet.guid
@@ -833,17 +2583,25 @@ impl GameHandler {
         GameObjUse { guid }
     }
 
+    /// Handle SMSG_COMPRESSED_UPDATE_OBJECT.
+    /// Inflates the payload and runs it through the regular update object parser.
+    pub fn handle_compressed_update_object(
+        &mut self,
+        mut payload: Bytes,
+        sit_enabled: bool,
+    ) -> Result<Option<u64>> {
+        let packet = CompressedUpdateObject::decode(&mut payload)?;
+        self.handle_update_object(packet.data, sit_enabled)
+    }
+
     /// Handle SMSG_UPDATE_OBJECT.
+    /// Keeps the nearby object registry up to date.
     /// Returns Some(guid) if we should interact with an object (sit on chair).
     pub fn handle_update_object(
         &mut self,
         mut payload: Bytes,
         sit_enabled: bool,
     ) -> Result<Option<u64>> {
-        if !sit_enabled || self.tried_to_sit {
-            return Ok(None);
-        }
-
         if payload.remaining() < 4 {
             return Err(anyhow!(
                 "handle_update_object: need 4 bytes for block_count, have {}",
@@ -851,6 +2609,7 @@ impl GameHandler {
             ));
         }
         let block_count = payload.get_u32_le();
+        let look_for_chair = sit_enabled && !self.tried_to_sit;
         let mut closest_chair_guid = None;
         let mut min_distance_sq = f32::MAX;
 
@@ -863,13 +2622,17 @@ impl GameHandler {
             match block_type {
                 0 => {
                     // UPDATETYPE_VALUES
-                    let _guid = read_packed_guid(&mut payload)?;
-                    self.parse_update_fields(&mut payload)?;
+                    let guid = read_packed_guid(&mut payload)?;
+                    let fields = self.parse_update_fields(&mut payload)?;
+                    self.nearby_objects.update_fields(guid, &fields);
+                    self.update_nearby_level(guid);
                 }
                 1 => {
                     // UPDATETYPE_MOVEMENT
-                    let _guid = read_packed_guid(&mut payload)?;
-                    self.parse_movement(&mut payload)?;
+                    let guid = read_packed_guid(&mut payload)?;
+                    let movement = self.parse_movement(&mut payload)?;
+                    self.nearby_objects
+                        .update_position(guid, movement.position());
                 }
                 2 | 3 => {
                     // UPDATETYPE_CREATE_OBJECT, UPDATETYPE_CREATE_OBJECT2
@@ -881,17 +2644,21 @@ impl GameHandler {
                     }
                     let obj_type = payload.get_u8();
                     let movement = self.parse_movement(&mut payload)?;
-                    self.parse_update_fields(&mut payload)?;
+                    let fields = self.parse_update_fields(&mut payload)?;
 
                     // Check for self update
                     if (movement.flags & 0x1) == 0x1 {
                         // UPDATEFLAG_SELF
                         self.world_position = Some((movement.x, movement.y, movement.z));
+                    } else if Some(guid) != self.self_guid {
+                        self.nearby_objects
+                            .create(guid, obj_type, movement.position(), &fields);
+                        self.update_nearby_level(guid);
                     }
 
                     // Check for chair
                     // GAMEOBJECT_TYPE_GENERIC, GAMEOBJECT_TYPE_CHAIR
-                    if obj_type == 5 || obj_type == 7 {
+                    if look_for_chair && (obj_type == 5 || obj_type == 7) {
                         if let Some((px, py, pz)) = self.world_position {
                             if close_to(movement.x, px, 2.0)
                                 && close_to(movement.y, py, 2.0)
@@ -925,7 +2692,10 @@ impl GameHandler {
                     }
                     let count = payload.get_u32_le();
                     for _ in 0..count {
-                        read_packed_guid(&mut payload)?;
+                        let guid = read_packed_guid(&mut payload)?;
+                        if block_type == 4 {
+                            self.nearby_objects.remove(guid);
+                        }
                     }
                 }
                 _ => {
@@ -942,9 +2712,42 @@ impl GameHandler {
         }
     }
 
-    fn parse_update_fields(&self, buf: &mut Bytes) -> Result<()> {
+    /// Handle SMSG_DESTROY_OBJECT.
+    pub fn handle_destroy_object(&mut self, mut payload: Bytes) -> Result<()> {
+        let packet = DestroyObject::decode(&mut payload)?;
+        if self.nearby_objects.remove(packet.guid) {
+            debug!("Object {} destroyed", packet.guid);
+        }
+        Ok(())
+    }
+
+    /// Collect nearby players whose names are unknown and mark them as queried.
+    /// The caller is expected to send a CMSG_NAME_QUERY for each returned GUID.
+    pub fn take_unnamed_nearby_players(&mut self) -> Vec<u64> {
+        let unnamed: Vec<u64> = self
+            .nearby_objects
+            .players()
+            .map(|p| p.guid)
+            .filter(|guid| {
+                !self.player_names.contains(guid) && !self.pending_name_queries.contains(guid)
+            })
+            .collect();
+        self.pending_name_queries.extend(unnamed.iter().copied());
+        unnamed
+    }
+
+    /// Get objects around the bot, closest first.
+    pub fn get_nearby_entities(&self) -> Vec<NearbyEntity> {
+        self.nearby_objects.snapshot(self.world_position, |guid| {
+            self.player_names.peek(&guid).map(|p| p.name.clone())
+        })
+    }
+
+    /// Parse an update field block.
+    /// Returns (field index, value) pairs for every field set in the mask.
+    fn parse_update_fields(&self, buf: &mut Bytes) -> Result<Vec<(u32, u32)>> {
         if buf.remaining() < 1 {
-            return Ok(());
+            return Ok(Vec::new());
         }
         let count = buf.get_u8();
         let mask_bytes = (count as usize) * 4;
@@ -956,14 +2759,15 @@ impl GameHandler {
                 buf.remaining()
             ));
         }
-        let mut counts = Vec::with_capacity(count as usize);
+        let mut masks = Vec::with_capacity(count as usize);
         for _ in 0..count {
-            counts.push(buf.get_u32_le());
+            masks.push(buf.get_u32_le());
         }
 
-        for c in counts {
-            // skip 4 bytes for each set bit
-            let set_bits = c.count_ones() as usize;
+        let mut fields = Vec::new();
+        for (block, mask) in masks.into_iter().enumerate() {
+            // 4 bytes for each set bit
+            let set_bits = mask.count_ones() as usize;
             let field_bytes = set_bits * 4;
             if buf.remaining() < field_bytes {
                 return Err(anyhow!(
@@ -973,9 +2777,13 @@ impl GameHandler {
                     buf.remaining()
                 ));
             }
-            buf.advance(field_bytes);
+            for bit in 0..32 {
+                if mask & (1 << bit) != 0 {
+                    fields.push((block as u32 * 32 + bit, buf.get_u32_le()));
+                }
+            }
         }
-        Ok(())
+        Ok(fields)
     }
 
     fn parse_movement(&self, buf: &mut Bytes) -> Result<Movement> {
@@ -1255,10 +3063,29 @@ struct Movement {
     z: f32,
 }
 
+impl Movement {
+    /// Position carried by the movement block, if any.
+    fn position(&self) -> Option<(f32, f32, f32)> {
+        // UPDATEFLAG_LIVING, UPDATEFLAG_STATIONARY_POSITION, UPDATEFLAG_POSITION
+        if self.flags & (0x20 | 0x40 | 0x100) != 0 {
+            Some((self.x, self.y, self.z))
+        } else {
+            None
+        }
+    }
+}
+
 fn close_to(x: f32, y: f32, precision: f32) -> bool {
     (x - y).abs() < precision
 }
 
+/// Current Unix time in seconds.
+pub fn unix_time() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
 #[cfg(feature = "test_guild_dashboard")]
 fn generate_test_roster() -> HashMap<u64, GuildMember> {
     use rand::Rng;
@@ -1291,3 +3118,426 @@ fn generate_test_roster() -> HashMap<u64, GuildMember> {
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(event_type: u8, player_guid: u64, time: u64) -> GuildLogRecord {
+        GuildLogRecord {
+            time,
+            entry: GuildEventLogEntry {
+                event_type,
+                player_guid,
+                target_guid: None,
+                rank: None,
+                seconds_ago: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_guild_log_missed() {
+        use guild_log_events::*;
+
+        let seen = GuildLogSeen {
+            records: vec![record(GUILD_EVENT_LOG_JOIN_GUILD, 1, 1000)],
+            last_online: 2000,
+        };
+        let records = vec![
+            // Already in the last log (fetched again with a second of drift)
+            record(GUILD_EVENT_LOG_JOIN_GUILD, 1, 1001),
+            // Seen live while online
+            record(GUILD_EVENT_LOG_LEAVE_GUILD, 2, 1500),
+            // Happened while offline
+            record(GUILD_EVENT_LOG_JOIN_GUILD, 3, 2500),
+        ];
+
+        let missed = seen.missed(&records);
+        assert_eq!(missed.len(), 1);
+        assert_eq!(missed[0].entry.player_guid, 3);
+    }
+
+    fn achievement_chat(chat_type: u8, guid: u64, achievement_id: u32) -> Bytes {
+        use bytes::BufMut;
+
+        let message = b"%s has earned the achievement $a!";
+        let mut buf = bytes::BytesMut::new();
+        buf.put_u8(chat_type);
+        buf.put_u32_le(0);
+        buf.put_u64_le(guid);
+        buf.put_u32_le(0);
+        buf.put_u64_le(guid);
+        buf.put_u32_le(message.len() as u32 + 1);
+        buf.put_slice(message);
+        buf.put_u8(0);
+        buf.put_u8(0); // Chat tag
+        buf.put_u32_le(achievement_id);
+        buf.freeze()
+    }
+
+    #[test]
+    fn test_achievement_dedupe() {
+        use crate::protocol::game::chat::chat_events::*;
+
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        handler.guild_roster.insert(
+            7,
+            GuildMember {
+                guid: 7,
+                name: "Thrall".to_string(),
+                level: 80,
+                class: None,
+                rank: 1,
+                rank_name: "Officer".to_string(),
+                zone_id: 0,
+                online: true,
+                last_logoff: 0.0,
+                note: String::new(),
+                officer_note: String::new(),
+            },
+        );
+
+        let first = handler
+            .handle_messagechat(achievement_chat(CHAT_MSG_ACHIEVEMENT, 7, 6))
+            .unwrap();
+        match first {
+            Some(ChatProcessingResult::GuildEvent(event)) => {
+                assert_eq!(event.event_name, "achievement");
+                assert_eq!(event.player_name, "Thrall");
+                assert_eq!(event.achievement_id, Some(6));
+            }
+            other => panic!("expected achievement event, got {:?}", other),
+        }
+
+        // The guild announcement of the same earn is dropped
+        let second = handler
+            .handle_messagechat(achievement_chat(CHAT_MSG_GUILD_ACHIEVEMENT, 7, 6))
+            .unwrap();
+        assert!(second.is_none());
+
+        // A different achievement still goes through
+        let third = handler
+            .handle_messagechat(achievement_chat(CHAT_MSG_GUILD_ACHIEVEMENT, 7, 7))
+            .unwrap();
+        assert!(matches!(third, Some(ChatProcessingResult::GuildEvent(_))));
+    }
+
+    #[test]
+    fn test_get_inactive_guildies() {
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        let members = [
+            ("Thrall", "Officer", false, 45.5),
+            ("Jaina", "Member", false, 90.0),
+            ("Arthas", "Member", false, 3.0),
+            ("Sylvanas", "Member", true, 0.0),
+            ("Bot", "Member", false, 120.0),
+        ];
+        for (guid, (name, rank_name, online, last_logoff)) in members.into_iter().enumerate() {
+            handler.guild_roster.insert(
+                guid as u64,
+                GuildMember {
+                    guid: guid as u64,
+                    name: name.to_string(),
+                    level: 80,
+                    class: None,
+                    rank: 1,
+                    rank_name: rank_name.to_string(),
+                    zone_id: 0,
+                    online,
+                    last_logoff,
+                    note: String::new(),
+                    officer_note: String::new(),
+                },
+            );
+        }
+
+        let names = |members: Vec<GuildMember>| -> Vec<String> {
+            members.into_iter().map(|m| m.name).collect()
+        };
+        assert_eq!(
+            names(handler.get_inactive_guildies(30, None)),
+            vec!["Jaina", "Thrall"]
+        );
+        assert_eq!(
+            names(handler.get_inactive_guildies(1, Some("member"))),
+            vec!["Jaina", "Arthas"]
+        );
+        assert!(handler.get_inactive_guildies(100, None).is_empty());
+    }
+
+    #[test]
+    fn test_player_names_load_and_invalidate() {
+        use bytes::BufMut;
+
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        let player = |guid: u64, name: &str| Player {
+            guid,
+            name: name.to_string(),
+            level: 0,
+            class: None,
+            race: None,
+            zone_id: 0,
+        };
+        handler.load_player_names(vec![player(1, "Thrall"), player(2, "Jaina")]);
+        assert_eq!(handler.member_name(2).as_deref(), Some("Jaina"));
+        assert!(handler.take_changed_player_names().is_none());
+
+        let mut payload = bytes::BytesMut::new();
+        payload.put_u64_le(1);
+        handler.handle_invalidate_player(payload.freeze()).unwrap();
+        let saved = handler.take_changed_player_names().unwrap();
+        assert_eq!(
+            saved.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["Jaina"]
+        );
+        assert!(handler.take_changed_player_names().is_none());
+
+        // A level seen in update fields is stored with the cached name
+        handler.nearby_objects.create(
+            2,
+            crate::protocol::game::objects::object_types::TYPEID_PLAYER,
+            None,
+            &[(
+                crate::protocol::game::objects::update_fields::UNIT_FIELD_LEVEL,
+                70,
+            )],
+        );
+        handler.update_nearby_level(2);
+        let saved = handler.take_changed_player_names().unwrap();
+        assert_eq!(saved[0].level, 70);
+    }
+
+    #[test]
+    fn test_pending_message_timeout() {
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        handler.guild_roster.insert(
+            8,
+            GuildMember {
+                guid: 8,
+                name: "Jaina".to_string(),
+                level: 80,
+                class: None,
+                rank: 1,
+                rank_name: "Member".to_string(),
+                zone_id: 0,
+                online: true,
+                last_logoff: 0.0,
+                note: String::new(),
+                officer_note: String::new(),
+            },
+        );
+        let chat = |guid: u64, content: &str| ChatMessage {
+            chat_type: crate::common::types::ChatType::Say,
+            language: 0,
+            sender_guid: guid,
+            sender_name: format!("Unknown-{}", guid),
+            channel_name: None,
+            content: content.to_string(),
+            format: None,
+            achievement_id: None,
+        };
+        handler.queue_pending_message(7, chat(7, "first"));
+        handler.queue_pending_message(8, chat(8, "second"));
+        handler.queue_pending_message(7, chat(7, "third"));
+        let start = std::time::Instant::now();
+
+        let (retries, expired) = handler.take_expired_pending_messages(start);
+        assert!(retries.is_empty() && expired.is_empty());
+
+        // The first deadline retries the query once
+        let (retries, expired) = handler.take_expired_pending_messages(start + NAME_QUERY_TIMEOUT);
+        assert_eq!(retries, vec![7, 8]);
+        assert!(expired.is_empty());
+
+        // The second relays with the roster name, or the placeholder label
+        let (retries, expired) =
+            handler.take_expired_pending_messages(start + NAME_QUERY_TIMEOUT * 2);
+        assert!(retries.is_empty());
+        let relayed: Vec<(&str, &str)> = expired
+            .iter()
+            .map(|m| (m.sender_name.as_str(), m.content.as_str()))
+            .collect();
+        assert_eq!(
+            relayed,
+            vec![
+                ("Unknown-7", "first"),
+                ("Unknown-7", "third"),
+                ("Jaina", "second")
+            ]
+        );
+        assert!(handler.pending_messages.is_empty());
+        assert_eq!(
+            handler.take_pending_message_stats(),
+            PendingMessageStats {
+                late: 3,
+                dropped: 0
+            }
+        );
+
+        // Evicted GUIDs are counted as dropped
+        for guid in 0..=MAX_PENDING_GUIDS as u64 {
+            handler.queue_pending_message(100 + guid, chat(100 + guid, "spam"));
+        }
+        assert_eq!(
+            handler.take_pending_message_stats(),
+            PendingMessageStats {
+                late: 0,
+                dropped: 1
+            }
+        );
+        assert_eq!(
+            PendingMessageStats {
+                late: 2,
+                dropped: 1
+            }
+            .summary(),
+            "Messages from unresolved senders: 2 relayed late, 1 dropped."
+        );
+    }
+
+    #[test]
+    fn test_social_notice_waits_for_name() {
+        use crate::common::types::{SocialAction, SocialRequest};
+
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        let notice = SocialNotice {
+            request: SocialRequest::Duel,
+            player: "An unknown player".to_string(),
+            action: SocialAction::Decline,
+        };
+        handler.hold_social_notice(9, notice);
+        let start = std::time::Instant::now();
+        assert!(handler.take_ready_social_notices().is_empty());
+
+        // The name query is retried once like a chat message's, then the notice goes out
+        let (retries, _) = handler.take_expired_pending_messages(start + NAME_QUERY_TIMEOUT);
+        assert_eq!(retries, vec![9]);
+        assert!(handler.take_ready_social_notices().is_empty());
+        handler.take_expired_pending_messages(start + NAME_QUERY_TIMEOUT * 2);
+        let ready = handler.take_ready_social_notices();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].player, "An unknown player");
+    }
+
+    #[test]
+    fn test_unchanged_guild_motd() {
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        handler.guild_id = 1;
+        handler.self_guid = Some(5);
+        handler.guild_roster.insert(
+            5,
+            GuildMember {
+                guid: 5,
+                name: "Bot".to_string(),
+                level: 80,
+                class: None,
+                rank: 0,
+                rank_name: "Guild Master".to_string(),
+                zone_id: 0,
+                online: true,
+                last_logoff: 0.0,
+                note: String::new(),
+                officer_note: String::new(),
+            },
+        );
+        handler.guild_motd = Some("Raid at 8".to_string());
+
+        // The server sends no GE_MOTD for an unchanged MOTD, so it succeeds at once
+        assert!(handler
+            .request_set_guild_motd("Raid at 8 ", 3)
+            .unwrap()
+            .is_none());
+        let replies = handler.take_guild_command_replies();
+        assert_eq!(replies.len(), 1);
+        assert!(replies[0].success);
+        assert_eq!(replies[0].reply_channel, 3);
+
+        assert!(handler
+            .request_set_guild_motd("Raid at 9", 3)
+            .unwrap()
+            .is_some());
+        assert!(handler.take_guild_command_replies().is_empty());
+    }
+
+    #[test]
+    fn test_guild_log_requests_expire() {
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        handler.request_guild_event_log(Some((1, 10)));
+        handler.request_guild_event_log(None);
+        handler.request_guild_event_log(Some((2, 5)));
+        assert!(handler.take_expired_guild_log_requests().is_empty());
+
+        handler.pending_guild_log_requests[0].2 -= GUILD_LOG_TIMEOUT;
+        assert_eq!(handler.take_expired_guild_log_requests(), vec![1]);
+        assert_eq!(handler.pending_guild_log_requests.len(), 1);
+        assert_eq!(handler.pending_guild_log_requests[0].0, 2);
+    }
+
+    #[test]
+    fn test_who_requests_expire() {
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        handler.request_who("Thrall", 1);
+        handler.request_who("Jaina", 2);
+        assert!(handler.take_expired_who_requests().is_empty());
+
+        // The first query was sent long ago and is answered with an error instead
+        handler.pending_who_requests[0].2 -= WHO_TIMEOUT;
+        assert_eq!(
+            handler.take_expired_who_requests(),
+            vec![(1, "Thrall".to_string())]
+        );
+        assert_eq!(handler.pending_who_requests.len(), 1);
+        assert_eq!(handler.pending_who_requests[0].1, "Jaina");
+    }
+
+    #[test]
+    fn test_unexpected_you_left_is_kick() {
+        let notice = |notify_type: u8, channel: &str| {
+            let mut buf = vec![notify_type];
+            buf.extend_from_slice(channel.as_bytes());
+            buf.push(0);
+            Bytes::from(buf)
+        };
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+
+        // YOU_LEFT from a channel the bot never joined is just logged
+        let result = handler
+            .handle_channel_notify(notice(chat_notify::CHAT_YOU_LEFT_NOTICE, "Trade"))
+            .unwrap();
+        assert!(result.event.is_none());
+
+        handler
+            .handle_channel_notify(notice(chat_notify::CHAT_YOU_JOINED_NOTICE, "Guildies"))
+            .unwrap();
+        let result = handler
+            .handle_channel_notify(notice(chat_notify::CHAT_YOU_LEFT_NOTICE, "Guildies"))
+            .unwrap();
+        assert!(matches!(
+            result.event,
+            Some(ChannelEvent {
+                status: ChannelStatus::Rejoining,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_channel_password_change() {
+        let mut handler = GameHandler::new("ACCOUNT", &[0; 40], 1, "Bot");
+        handler.request_channel_moderation(
+            "Guildies",
+            ChannelModeration::Password("secret".to_string()),
+            7,
+        );
+
+        let mut buf = vec![chat_notify::CHAT_PASSWORD_CHANGED_NOTICE];
+        buf.extend_from_slice(b"Guildies\0");
+        let result = handler.handle_channel_notify(Bytes::from(buf)).unwrap();
+        let command = result.command.unwrap();
+        assert_eq!(command.reply_channel, 7);
+        let changed = command.password_changed.unwrap();
+        assert_eq!(changed.name, "Guildies");
+        assert_eq!(changed.password.as_deref(), Some("secret"));
+    }
+}
@@ -2,10 +2,14 @@
 
 pub mod chat;
 pub mod connector;
+pub mod group;
 pub mod guild;
 pub mod handler;
 pub mod header;
+pub mod objects;
 pub mod packets;
+pub mod social;
+pub mod who;
 
 pub use connector::{new_game_connection, GameConnection};
 pub use handler::{ChatProcessingResult, GameHandler};
@@ -0,0 +1,229 @@
+//! Nearby object tracking.
+//!
+//! Keeps a registry of players, creatures and game objects visible to the bot,
+//! fed by SMSG_UPDATE_OBJECT blocks and SMSG_DESTROY_OBJECT.
+
+use std::collections::HashMap;
+
+use crate::common::resources::{Class, Race};
+use crate::common::types::{EntityKind, Guid, NearbyEntity};
+
+/// Object type IDs sent in UPDATETYPE_CREATE_OBJECT blocks.
+#[allow(dead_code)]
+pub mod object_types {
+    pub const TYPEID_OBJECT: u8 = 0;
+    pub const TYPEID_ITEM: u8 = 1;
+    pub const TYPEID_CONTAINER: u8 = 2;
+    pub const TYPEID_UNIT: u8 = 3;
+    pub const TYPEID_PLAYER: u8 = 4;
+    pub const TYPEID_GAMEOBJECT: u8 = 5;
+    pub const TYPEID_DYNAMICOBJECT: u8 = 6;
+    pub const TYPEID_CORPSE: u8 = 7;
+}
+
+/// Update field indices we care about (WotLK 3.3.5a).
+pub mod update_fields {
+    pub const OBJECT_FIELD_ENTRY: u32 = 0x0003;
+    /// race | class << 8 | gender << 16 | power type << 24
+    pub const UNIT_FIELD_BYTES_0: u32 = 0x0017;
+    pub const UNIT_FIELD_LEVEL: u32 = 0x0036;
+}
+
+/// A tracked world object.
+#[derive(Debug, Clone)]
+pub struct TrackedObject {
+    pub guid: Guid,
+    pub kind: EntityKind,
+    pub entry: u32,
+    pub level: u8,
+    pub bytes_0: u32,
+    pub position: Option<(f32, f32, f32)>,
+}
+
+impl TrackedObject {
+    /// Apply (field index, value) pairs from an update block.
+    /// Unit fields are ignored for game objects since their indices overlap.
+    fn apply_fields(&mut self, fields: &[(u32, u32)]) {
+        for &(index, value) in fields {
+            match index {
+                update_fields::OBJECT_FIELD_ENTRY => self.entry = value,
+                update_fields::UNIT_FIELD_BYTES_0 if self.kind != EntityKind::GameObject => {
+                    self.bytes_0 = value
+                }
+                update_fields::UNIT_FIELD_LEVEL if self.kind != EntityKind::GameObject => {
+                    self.level = value.min(u8::MAX as u32) as u8
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn race(&self) -> Option<Race> {
+        Race::from_id((self.bytes_0 & 0xFF) as u8)
+    }
+
+    pub fn class(&self) -> Option<Class> {
+        Class::from_id(((self.bytes_0 >> 8) & 0xFF) as u8)
+    }
+}
+
+/// Registry of objects currently in the bot's visibility range.
+#[derive(Debug, Default)]
+pub struct ObjectRegistry {
+    objects: HashMap<Guid, TrackedObject>,
+}
+
+impl ObjectRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track an object from a create block.
+    /// Object types other than units, players and game objects are ignored.
+    pub fn create(
+        &mut self,
+        guid: Guid,
+        type_id: u8,
+        position: Option<(f32, f32, f32)>,
+        fields: &[(u32, u32)],
+    ) {
+        let kind = match type_id {
+            object_types::TYPEID_UNIT => EntityKind::Creature,
+            object_types::TYPEID_PLAYER => EntityKind::Player,
+            object_types::TYPEID_GAMEOBJECT => EntityKind::GameObject,
+            _ => return,
+        };
+
+        let mut object = TrackedObject {
+            guid,
+            kind,
+            entry: 0,
+            level: 0,
+            bytes_0: 0,
+            position,
+        };
+        object.apply_fields(fields);
+        self.objects.insert(guid, object);
+    }
+
+    /// Get a tracked object.
+    pub fn get(&self, guid: Guid) -> Option<&TrackedObject> {
+        self.objects.get(&guid)
+    }
+
+    /// Apply a values block to a tracked object.
+    pub fn update_fields(&mut self, guid: Guid, fields: &[(u32, u32)]) {
+        if let Some(object) = self.objects.get_mut(&guid) {
+            object.apply_fields(fields);
+        }
+    }
+
+    /// Apply a movement block to a tracked object.
+    pub fn update_position(&mut self, guid: Guid, position: Option<(f32, f32, f32)>) {
+        if let (Some(object), Some(_)) = (self.objects.get_mut(&guid), position) {
+            object.position = position;
+        }
+    }
+
+    /// Stop tracking an object (destroyed or out of range).
+    pub fn remove(&mut self, guid: Guid) -> bool {
+        self.objects.remove(&guid).is_some()
+    }
+
+    /// Iterate over tracked players.
+    pub fn players(&self) -> impl Iterator<Item = &TrackedObject> {
+        self.objects
+            .values()
+            .filter(|o| o.kind == EntityKind::Player)
+    }
+
+    /// Build a snapshot of tracked objects, closest first.
+    /// `name_of` resolves player names (e.g. from the name cache).
+    pub fn snapshot(
+        &self,
+        origin: Option<(f32, f32, f32)>,
+        name_of: impl Fn(Guid) -> Option<String>,
+    ) -> Vec<NearbyEntity> {
+        let mut entities: Vec<NearbyEntity> = self
+            .objects
+            .values()
+            .map(|o| NearbyEntity {
+                guid: o.guid,
+                kind: o.kind,
+                name: if o.kind == EntityKind::Player {
+                    name_of(o.guid)
+                } else {
+                    None
+                },
+                entry: o.entry,
+                level: o.level,
+                class: o.class(),
+                race: o.race(),
+                distance: match (origin, o.position) {
+                    (Some((ox, oy, oz)), Some((x, y, z))) => {
+                        let (dx, dy, dz) = (x - ox, y - oy, z - oz);
+                        Some((dx * dx + dy * dy + dz * dz).sqrt())
+                    }
+                    _ => None,
+                },
+            })
+            .collect();
+
+        entities.sort_by(|a, b| {
+            a.distance
+                .unwrap_or(f32::MAX)
+                .total_cmp(&b.distance.unwrap_or(f32::MAX))
+        });
+        entities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_lifecycle() {
+        let mut registry = ObjectRegistry::new();
+
+        // Orc Shaman, level 80
+        let player_fields = [
+            (update_fields::UNIT_FIELD_BYTES_0, 2 | (7 << 8)),
+            (update_fields::UNIT_FIELD_LEVEL, 80),
+        ];
+        registry.create(
+            1,
+            object_types::TYPEID_PLAYER,
+            Some((10.0, 0.0, 0.0)),
+            &player_fields,
+        );
+        registry.create(
+            2,
+            object_types::TYPEID_UNIT,
+            Some((3.0, 4.0, 0.0)),
+            &[(update_fields::OBJECT_FIELD_ENTRY, 1234)],
+        );
+        registry.create(3, object_types::TYPEID_ITEM, None, &[]);
+        assert!(!registry.remove(3));
+
+        registry.update_fields(2, &[(update_fields::UNIT_FIELD_LEVEL, 12)]);
+        registry.update_position(1, Some((20.0, 0.0, 0.0)));
+
+        let snapshot = registry.snapshot(Some((0.0, 0.0, 0.0)), |guid| {
+            (guid == 1).then(|| "Thrall".to_string())
+        });
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].kind, EntityKind::Creature);
+        assert_eq!(snapshot[0].entry, 1234);
+        assert_eq!(snapshot[0].level, 12);
+        assert_eq!(snapshot[0].distance, Some(5.0));
+        assert_eq!(snapshot[1].name.as_deref(), Some("Thrall"));
+        assert_eq!(snapshot[1].race, Some(Race::Orc));
+        assert_eq!(snapshot[1].class, Some(Class::Shaman));
+        assert_eq!(snapshot[1].distance, Some(20.0));
+
+        assert!(registry.remove(1));
+        assert!(!registry.remove(1));
+        assert_eq!(registry.players().count(), 0);
+    }
+}
@@ -463,6 +463,86 @@ impl PacketDecode for InvalidatePlayer {
     }
 }
 
+/// SMSG_DESTROY_OBJECT packet.
+/// Sent when an object is removed from the client's view.
+#[derive(Debug, Clone)]
+pub struct DestroyObject {
+    pub guid: u64,
+}
+
+impl PacketDecode for DestroyObject {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 8 {
+            return Err(anyhow!(
+                "SMSG_DESTROY_OBJECT packet too short: need {} bytes, got {}",
+                8,
+                buf.remaining()
+            ));
+        }
+        // Trailing "on death" flag is not needed
+        Ok(DestroyObject {
+            guid: buf.get_u64_le(),
+        })
+    }
+}
+
+/// Upper bound on the inflated size of SMSG_COMPRESSED_UPDATE_OBJECT.
+/// Guards against corrupt or hostile size headers.
+const MAX_UPDATE_OBJECT_SIZE: u32 = 4 * 1024 * 1024;
+
+/// SMSG_COMPRESSED_UPDATE_OBJECT packet.
+/// A zlib-deflated SMSG_UPDATE_OBJECT payload prefixed with its inflated size.
+#[derive(Debug, Clone)]
+pub struct CompressedUpdateObject {
+    /// Inflated payload, laid out exactly like SMSG_UPDATE_OBJECT.
+    pub data: Bytes,
+}
+
+impl PacketDecode for CompressedUpdateObject {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        use std::io::Read;
+
+        if buf.remaining() < 4 {
+            return Err(anyhow!(
+                "SMSG_COMPRESSED_UPDATE_OBJECT packet too short: need {} bytes, got {}",
+                4,
+                buf.remaining()
+            ));
+        }
+        let size = buf.get_u32_le();
+        if size > MAX_UPDATE_OBJECT_SIZE {
+            return Err(anyhow!(
+                "SMSG_COMPRESSED_UPDATE_OBJECT inflated size {} exceeds limit {}",
+                size,
+                MAX_UPDATE_OBJECT_SIZE
+            ));
+        }
+
+        let mut data = Vec::with_capacity(size as usize);
+        flate2::read::ZlibDecoder::new(buf.as_ref())
+            .take(size as u64)
+            .read_to_end(&mut data)
+            .map_err(|e| anyhow!("SMSG_COMPRESSED_UPDATE_OBJECT inflate failed: {}", e))?;
+        buf.advance(buf.remaining());
+
+        if data.len() != size as usize {
+            return Err(anyhow!(
+                "SMSG_COMPRESSED_UPDATE_OBJECT inflated to {} bytes, expected {}",
+                data.len(),
+                size
+            ));
+        }
+
+        Ok(CompressedUpdateObject {
+            data: Bytes::from(data),
+        })
+    }
+}
+
 /// CMSG_GAMEOBJ_USE packet.
 #[derive(Debug, Clone)]
 pub struct GameObjUse {
@@ -537,3 +617,32 @@ impl From<TimeSyncResp> for crate::protocol::packets::Packet {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compressed_update_object_decode() {
+        use std::io::Write;
+
+        let raw: Vec<u8> = (0..200u8).collect();
+        let mut encoder =
+            flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&raw).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut payload = BytesMut::new();
+        payload.put_u32_le(raw.len() as u32);
+        payload.put_slice(&compressed);
+
+        let packet = CompressedUpdateObject::decode(&mut payload.freeze()).unwrap();
+        assert_eq!(packet.data.as_ref(), raw.as_slice());
+
+        // A size header that disagrees with the stream is rejected
+        let mut payload = BytesMut::new();
+        payload.put_u32_le(raw.len() as u32 + 1);
+        payload.put_slice(&compressed);
+        assert!(CompressedUpdateObject::decode(&mut payload.freeze()).is_err());
+    }
+}
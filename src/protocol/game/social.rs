@@ -0,0 +1,224 @@
+//! Duel, trade and petition requests.
+//!
+//! Handles SMSG_DUEL_REQUESTED, SMSG_TRADE_STATUS and SMSG_PETITION_SHOW_SIGNATURES
+//! parsing, and the matching accept/decline packets.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::common::types::Guid;
+use crate::protocol::packets::{PacketDecode, PacketEncode};
+use anyhow::{anyhow, Result};
+
+/// Trade status codes from SMSG_TRADE_STATUS.
+#[allow(dead_code)]
+pub mod trade_status {
+    pub const TRADE_STATUS_BUSY: u32 = 0;
+    pub const TRADE_STATUS_BEGIN_TRADE: u32 = 1;
+    pub const TRADE_STATUS_OPEN_WINDOW: u32 = 2;
+    pub const TRADE_STATUS_TRADE_CANCELED: u32 = 3;
+    pub const TRADE_STATUS_TRADE_ACCEPT: u32 = 4;
+}
+
+/// SMSG_DUEL_REQUESTED packet.
+#[derive(Debug, Clone)]
+pub struct DuelRequested {
+    /// Duel flag game object, echoed back in the answer.
+    pub arbiter_guid: Guid,
+    pub initiator_guid: Guid,
+}
+
+impl PacketDecode for DuelRequested {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 16 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                16,
+                buf.remaining()
+            ));
+        }
+
+        Ok(DuelRequested {
+            arbiter_guid: buf.get_u64_le(),
+            initiator_guid: buf.get_u64_le(),
+        })
+    }
+}
+
+/// CMSG_DUEL_ACCEPTED or CMSG_DUEL_CANCELLED packet.
+#[derive(Debug, Clone)]
+pub struct DuelResponse {
+    pub arbiter_guid: Guid,
+    pub accept: bool,
+}
+
+impl PacketEncode for DuelResponse {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.arbiter_guid);
+    }
+}
+
+impl From<DuelResponse> for crate::protocol::packets::Packet {
+    fn from(resp: DuelResponse) -> Self {
+        let opcode = if resp.accept {
+            crate::protocol::packets::opcodes::CMSG_DUEL_ACCEPTED
+        } else {
+            crate::protocol::packets::opcodes::CMSG_DUEL_CANCELLED
+        };
+        let mut buf = BytesMut::new();
+        resp.encode(&mut buf);
+        crate::protocol::packets::Packet::new(opcode, buf.freeze())
+    }
+}
+
+/// SMSG_TRADE_STATUS packet.
+#[derive(Debug, Clone)]
+pub struct TradeStatus {
+    pub status: u32,
+    /// Player proposing the trade (TRADE_STATUS_BEGIN_TRADE only).
+    pub initiator_guid: Option<Guid>,
+}
+
+impl PacketDecode for TradeStatus {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 4 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                4,
+                buf.remaining()
+            ));
+        }
+
+        let status = buf.get_u32_le();
+        let initiator_guid = if status == trade_status::TRADE_STATUS_BEGIN_TRADE {
+            if buf.remaining() < 8 {
+                return Err(anyhow!(
+                    "Packet too short: need {} bytes, got {}",
+                    8,
+                    buf.remaining()
+                ));
+            }
+            Some(buf.get_u64_le())
+        } else {
+            None
+        };
+
+        Ok(TradeStatus {
+            status,
+            initiator_guid,
+        })
+    }
+}
+
+/// CMSG_BEGIN_TRADE or CMSG_CANCEL_TRADE packet (empty payload).
+#[derive(Debug, Clone)]
+pub struct TradeResponse {
+    pub accept: bool,
+}
+
+impl From<TradeResponse> for crate::protocol::packets::Packet {
+    fn from(resp: TradeResponse) -> Self {
+        let opcode = if resp.accept {
+            crate::protocol::packets::opcodes::CMSG_BEGIN_TRADE
+        } else {
+            crate::protocol::packets::opcodes::CMSG_CANCEL_TRADE
+        };
+        crate::protocol::packets::Packet::new(opcode, Bytes::new())
+    }
+}
+
+/// SMSG_PETITION_SHOW_SIGNATURES packet, sent when a player offers a charter.
+#[derive(Debug, Clone)]
+pub struct PetitionShowSignatures {
+    pub petition_guid: Guid,
+    pub owner_guid: Guid,
+}
+
+impl PacketDecode for PetitionShowSignatures {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 16 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                16,
+                buf.remaining()
+            ));
+        }
+
+        let petition_guid = buf.get_u64_le();
+        let owner_guid = buf.get_u64_le();
+        // Petition id and the signature list follow
+
+        Ok(PetitionShowSignatures {
+            petition_guid,
+            owner_guid,
+        })
+    }
+}
+
+/// CMSG_PETITION_SIGN or MSG_PETITION_DECLINE packet.
+#[derive(Debug, Clone)]
+pub struct PetitionResponse {
+    pub petition_guid: Guid,
+    pub accept: bool,
+}
+
+impl PacketEncode for PetitionResponse {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u64_le(self.petition_guid);
+        if self.accept {
+            buf.put_u8(0); // Unknown
+        }
+    }
+}
+
+impl From<PetitionResponse> for crate::protocol::packets::Packet {
+    fn from(resp: PetitionResponse) -> Self {
+        let opcode = if resp.accept {
+            crate::protocol::packets::opcodes::CMSG_PETITION_SIGN
+        } else {
+            crate::protocol::packets::opcodes::MSG_PETITION_DECLINE
+        };
+        let mut buf = BytesMut::new();
+        resp.encode(&mut buf);
+        crate::protocol::packets::Packet::new(opcode, buf.freeze())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trade_status_decode() {
+        let mut data = BytesMut::new();
+        data.put_u32_le(trade_status::TRADE_STATUS_BEGIN_TRADE);
+        data.put_u64_le(42);
+        let status = TradeStatus::decode(&mut data.freeze()).unwrap();
+        assert_eq!(status.initiator_guid, Some(42));
+
+        let mut data = BytesMut::new();
+        data.put_u32_le(trade_status::TRADE_STATUS_TRADE_CANCELED);
+        let status = TradeStatus::decode(&mut data.freeze()).unwrap();
+        assert_eq!(status.status, trade_status::TRADE_STATUS_TRADE_CANCELED);
+        assert_eq!(status.initiator_guid, None);
+    }
+
+    #[test]
+    fn test_duel_response_opcode() {
+        let packet: crate::protocol::packets::Packet = DuelResponse {
+            arbiter_guid: 7,
+            accept: false,
+        }
+        .into();
+        assert_eq!(
+            packet.opcode,
+            crate::protocol::packets::opcodes::CMSG_DUEL_CANCELLED
+        );
+        assert_eq!(packet.payload[..], 7u64.to_le_bytes());
+    }
+}
@@ -0,0 +1,220 @@
+//! Server-wide WHO queries.
+//!
+//! Handles CMSG_WHO sending and SMSG_WHO parsing.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::common::resources::{Class, Race};
+use crate::common::types::WhoPlayer;
+use crate::protocol::packets::{read_cstring, PacketDecode, PacketEncode, MAX_CSTRING_SHORT};
+use anyhow::{anyhow, Result};
+
+/// Maximum number of zone filters the server accepts.
+pub const MAX_WHO_ZONES: usize = 10;
+
+/// Maximum number of free-text search strings the server accepts.
+pub const MAX_WHO_STRINGS: usize = 4;
+
+/// CMSG_WHO packet.
+#[derive(Debug, Clone)]
+pub struct WhoRequest {
+    pub level_min: u32,
+    pub level_max: u32,
+    pub player_name: String,
+    pub guild_name: String,
+    pub race_mask: u32,
+    pub class_mask: u32,
+    pub zones: Vec<u32>,
+    pub strings: Vec<String>,
+}
+
+impl WhoRequest {
+    /// Create a request matching any player whose name contains `name`.
+    pub fn by_name(name: impl Into<String>) -> Self {
+        Self {
+            player_name: name.into(),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for WhoRequest {
+    fn default() -> Self {
+        Self {
+            level_min: 0,
+            level_max: 100,
+            player_name: String::new(),
+            guild_name: String::new(),
+            race_mask: 0xFFFFFFFF,
+            class_mask: 0xFFFFFFFF,
+            zones: Vec::new(),
+            strings: Vec::new(),
+        }
+    }
+}
+
+impl PacketEncode for WhoRequest {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u32_le(self.level_min);
+        buf.put_u32_le(self.level_max);
+        buf.put_slice(self.player_name.as_bytes());
+        buf.put_u8(0);
+        buf.put_slice(self.guild_name.as_bytes());
+        buf.put_u8(0);
+        buf.put_u32_le(self.race_mask);
+        buf.put_u32_le(self.class_mask);
+
+        let zones = &self.zones[..self.zones.len().min(MAX_WHO_ZONES)];
+        buf.put_u32_le(zones.len() as u32);
+        for zone_id in zones {
+            buf.put_u32_le(*zone_id);
+        }
+
+        let strings = &self.strings[..self.strings.len().min(MAX_WHO_STRINGS)];
+        buf.put_u32_le(strings.len() as u32);
+        for s in strings {
+            buf.put_slice(s.as_bytes());
+            buf.put_u8(0);
+        }
+    }
+}
+
+impl From<WhoRequest> for crate::protocol::packets::Packet {
+    fn from(req: WhoRequest) -> Self {
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+        crate::protocol::packets::Packet::new(
+            crate::protocol::packets::opcodes::CMSG_WHO,
+            buf.freeze(),
+        )
+    }
+}
+
+/// A single entry from SMSG_WHO.
+#[derive(Debug, Clone)]
+pub struct WhoEntry {
+    pub name: String,
+    pub guild_name: String,
+    pub level: u32,
+    pub class: u32,
+    pub race: u32,
+    pub zone_id: u32,
+}
+
+impl WhoEntry {
+    /// Convert to common WhoPlayer type.
+    pub fn to_who_player(&self) -> WhoPlayer {
+        WhoPlayer {
+            name: self.name.clone(),
+            guild_name: self.guild_name.clone(),
+            level: self.level.min(u8::MAX as u32) as u8,
+            class: Class::from_id(self.class as u8),
+            race: Race::from_id(self.race as u8),
+            zone_id: self.zone_id,
+        }
+    }
+}
+
+/// SMSG_WHO response.
+#[derive(Debug, Clone, Default)]
+pub struct WhoResponse {
+    /// Total number of players matching the query (may exceed `entries.len()`).
+    pub match_count: u32,
+    pub entries: Vec<WhoEntry>,
+}
+
+impl PacketDecode for WhoResponse {
+    type Error = anyhow::Error;
+
+    fn decode(buf: &mut Bytes) -> Result<Self, Self::Error> {
+        if buf.remaining() < 8 {
+            return Err(anyhow!(
+                "Packet too short: need {} bytes, got {}",
+                8,
+                buf.remaining()
+            ));
+        }
+
+        let displayed_count = buf.get_u32_le();
+        let match_count = buf.get_u32_le();
+
+        let mut entries = Vec::with_capacity(displayed_count.min(50) as usize);
+        for _ in 0..displayed_count {
+            let name = read_cstring(buf, MAX_CSTRING_SHORT)?;
+            let guild_name = read_cstring(buf, MAX_CSTRING_SHORT)?;
+
+            if buf.remaining() < 17 {
+                return Err(anyhow!(
+                    "Packet too short: need {} bytes, got {}",
+                    17,
+                    buf.remaining()
+                ));
+            }
+            let level = buf.get_u32_le();
+            let class = buf.get_u32_le();
+            let race = buf.get_u32_le();
+            buf.advance(1); // Gender
+            let zone_id = buf.get_u32_le();
+
+            entries.push(WhoEntry {
+                name,
+                guild_name,
+                level,
+                class,
+                race,
+                zone_id,
+            });
+        }
+
+        Ok(WhoResponse {
+            match_count,
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_who_request_encode() {
+        let req = WhoRequest::by_name("Arthas");
+        let mut buf = BytesMut::new();
+        req.encode(&mut buf);
+
+        // 4 + 4 + "Arthas\0" + "\0" + 4 + 4 + 4 (zones) + 4 (strings)
+        assert_eq!(buf.len(), 8 + 7 + 1 + 8 + 8);
+        assert_eq!(buf[0..4], 0u32.to_le_bytes());
+        assert_eq!(buf[4..8], 100u32.to_le_bytes());
+        assert_eq!(&buf[8..15], b"Arthas\0");
+        assert_eq!(buf[15], 0);
+        assert_eq!(buf[16..20], 0xFFFFFFFFu32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_who_response_decode() {
+        let mut data = BytesMut::new();
+        data.put_u32_le(1);
+        data.put_u32_le(3);
+        data.put_slice(b"Thrall\0");
+        data.put_slice(b"Horde\0");
+        data.put_u32_le(80);
+        data.put_u32_le(7);
+        data.put_u32_le(2);
+        data.put_u8(0);
+        data.put_u32_le(1637);
+
+        let resp = WhoResponse::decode(&mut data.freeze()).unwrap();
+        assert_eq!(resp.match_count, 3);
+        assert_eq!(resp.entries.len(), 1);
+
+        let player = resp.entries[0].to_who_player();
+        assert_eq!(player.name, "Thrall");
+        assert_eq!(player.guild_name, "Horde");
+        assert_eq!(player.level, 80);
+        assert_eq!(player.class, Some(Class::Shaman));
+        assert_eq!(player.race, Some(Race::Orc));
+        assert_eq!(player.zone_id, 1637);
+    }
+}
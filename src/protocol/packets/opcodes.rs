@@ -126,7 +126,17 @@ pub const SMSG_GM_MESSAGECHAT: u16 = 0x03B3;
 pub const CMSG_JOIN_CHANNEL: u16 = 0x0097;
 pub const CMSG_LEAVE_CHANNEL: u16 = 0x0098;
 pub const SMSG_CHANNEL_NOTIFY: u16 = 0x0099;
+pub const CMSG_CHANNEL_LIST: u16 = 0x009A;
 pub const SMSG_CHANNEL_LIST: u16 = 0x009B;
+pub const CMSG_CHANNEL_PASSWORD: u16 = 0x009C;
+pub const CMSG_CHANNEL_SET_OWNER: u16 = 0x009D;
+pub const CMSG_CHANNEL_MUTE: u16 = 0x00A1;
+pub const CMSG_CHANNEL_UNMUTE: u16 = 0x00A2;
+pub const CMSG_CHANNEL_KICK: u16 = 0x00A4;
+pub const CMSG_CHANNEL_BAN: u16 = 0x00A5;
+pub const CMSG_CHANNEL_UNBAN: u16 = 0x00A6;
+pub const CMSG_CHANNEL_ANNOUNCEMENTS: u16 = 0x00A7;
+pub const CMSG_CHANNEL_MODERATE: u16 = 0x00A8;
 
 pub const SMSG_CHAT_PLAYER_NOT_FOUND: u16 = 0x02A9;
 
@@ -136,6 +146,44 @@ pub const SMSG_GUILD_QUERY: u16 = 0x0055;
 pub const CMSG_GUILD_ROSTER: u16 = 0x0089;
 pub const SMSG_GUILD_ROSTER: u16 = 0x008A;
 pub const SMSG_GUILD_EVENT: u16 = 0x0092;
+pub const CMSG_GUILD_INVITE: u16 = 0x0082;
+pub const SMSG_GUILD_INVITE: u16 = 0x0083;
+pub const CMSG_GUILD_ACCEPT: u16 = 0x0084;
+pub const CMSG_GUILD_DECLINE: u16 = 0x0085;
+pub const CMSG_GUILD_PROMOTE: u16 = 0x008B;
+pub const CMSG_GUILD_DEMOTE: u16 = 0x008C;
+pub const CMSG_GUILD_REMOVE: u16 = 0x008E;
+pub const SMSG_GUILD_COMMAND_RESULT: u16 = 0x0093;
+pub const CMSG_GUILD_MOTD: u16 = 0x0091;
+pub const CMSG_GUILD_INFO_TEXT: u16 = 0x02FC;
+pub const CMSG_GUILD_SET_PUBLIC_NOTE: u16 = 0x0234;
+pub const CMSG_GUILD_SET_OFFICER_NOTE: u16 = 0x0235;
+pub const MSG_GUILD_EVENT_LOG_QUERY: u16 = 0x03FE;
+
+// --- Social requests ---
+pub const SMSG_DUEL_REQUESTED: u16 = 0x0167;
+pub const CMSG_DUEL_ACCEPTED: u16 = 0x016C;
+pub const CMSG_DUEL_CANCELLED: u16 = 0x016D;
+pub const CMSG_BEGIN_TRADE: u16 = 0x0117;
+pub const CMSG_CANCEL_TRADE: u16 = 0x011C;
+pub const SMSG_TRADE_STATUS: u16 = 0x0120;
+pub const SMSG_PETITION_SHOW_SIGNATURES: u16 = 0x01BF;
+pub const CMSG_PETITION_SIGN: u16 = 0x01C0;
+pub const MSG_PETITION_DECLINE: u16 = 0x01C2;
+
+// --- Group ---
+pub const SMSG_GROUP_INVITE: u16 = 0x006F;
+pub const CMSG_GROUP_ACCEPT: u16 = 0x0072;
+pub const CMSG_GROUP_DECLINE: u16 = 0x0073;
+pub const CMSG_GROUP_UNINVITE_GUID: u16 = 0x0076;
+pub const SMSG_GROUP_UNINVITE: u16 = 0x0077;
+pub const CMSG_GROUP_DISBAND: u16 = 0x007B;
+pub const SMSG_GROUP_DESTROYED: u16 = 0x007C;
+pub const SMSG_GROUP_LIST: u16 = 0x007D;
+
+// --- Who ---
+pub const CMSG_WHO: u16 = 0x0062;
+pub const SMSG_WHO: u16 = 0x0063;
 
 // --- Name queries ---
 pub const CMSG_NAME_QUERY: u16 = 0x0050;
@@ -155,6 +203,7 @@ pub const SMSG_INVALIDATE_PLAYER: u16 = 0x031C;
 pub const SMSG_INIT_WORLD_STATES: u16 = 0x02C2;
 pub const SMSG_UPDATE_OBJECT: u16 = 0x00A9;
 pub const SMSG_COMPRESSED_UPDATE_OBJECT: u16 = 0x01F6;
+pub const SMSG_DESTROY_OBJECT: u16 = 0x00AA;
 
 pub const CMSG_GAMEOBJ_USE: u16 = 0x00B1;
 
@@ -177,12 +226,55 @@ pub fn opcode_name(opcode: u16) -> &'static str {
         CMSG_MESSAGECHAT => "CMSG_MESSAGECHAT",
         CMSG_JOIN_CHANNEL => "CMSG_JOIN_CHANNEL",
         SMSG_CHANNEL_NOTIFY => "SMSG_CHANNEL_NOTIFY",
+        CMSG_CHANNEL_LIST => "CMSG_CHANNEL_LIST",
+        SMSG_CHANNEL_LIST => "SMSG_CHANNEL_LIST",
+        CMSG_CHANNEL_PASSWORD => "CMSG_CHANNEL_PASSWORD",
+        CMSG_CHANNEL_SET_OWNER => "CMSG_CHANNEL_SET_OWNER",
+        CMSG_CHANNEL_MUTE => "CMSG_CHANNEL_MUTE",
+        CMSG_CHANNEL_UNMUTE => "CMSG_CHANNEL_UNMUTE",
+        CMSG_CHANNEL_KICK => "CMSG_CHANNEL_KICK",
+        CMSG_CHANNEL_BAN => "CMSG_CHANNEL_BAN",
+        CMSG_CHANNEL_UNBAN => "CMSG_CHANNEL_UNBAN",
+        CMSG_CHANNEL_ANNOUNCEMENTS => "CMSG_CHANNEL_ANNOUNCEMENTS",
+        CMSG_CHANNEL_MODERATE => "CMSG_CHANNEL_MODERATE",
         SMSG_CHAT_PLAYER_NOT_FOUND => "SMSG_CHAT_PLAYER_NOT_FOUND",
         CMSG_GUILD_QUERY => "CMSG_GUILD_QUERY",
         SMSG_GUILD_QUERY => "SMSG_GUILD_QUERY",
         CMSG_GUILD_ROSTER => "CMSG_GUILD_ROSTER",
         SMSG_GUILD_ROSTER => "SMSG_GUILD_ROSTER",
         SMSG_GUILD_EVENT => "SMSG_GUILD_EVENT",
+        CMSG_GUILD_INVITE => "CMSG_GUILD_INVITE",
+        SMSG_GUILD_INVITE => "SMSG_GUILD_INVITE",
+        CMSG_GUILD_ACCEPT => "CMSG_GUILD_ACCEPT",
+        CMSG_GUILD_DECLINE => "CMSG_GUILD_DECLINE",
+        CMSG_GUILD_PROMOTE => "CMSG_GUILD_PROMOTE",
+        CMSG_GUILD_DEMOTE => "CMSG_GUILD_DEMOTE",
+        CMSG_GUILD_REMOVE => "CMSG_GUILD_REMOVE",
+        SMSG_GUILD_COMMAND_RESULT => "SMSG_GUILD_COMMAND_RESULT",
+        CMSG_GUILD_MOTD => "CMSG_GUILD_MOTD",
+        CMSG_GUILD_INFO_TEXT => "CMSG_GUILD_INFO_TEXT",
+        CMSG_GUILD_SET_PUBLIC_NOTE => "CMSG_GUILD_SET_PUBLIC_NOTE",
+        CMSG_GUILD_SET_OFFICER_NOTE => "CMSG_GUILD_SET_OFFICER_NOTE",
+        MSG_GUILD_EVENT_LOG_QUERY => "MSG_GUILD_EVENT_LOG_QUERY",
+        SMSG_DUEL_REQUESTED => "SMSG_DUEL_REQUESTED",
+        CMSG_DUEL_ACCEPTED => "CMSG_DUEL_ACCEPTED",
+        CMSG_DUEL_CANCELLED => "CMSG_DUEL_CANCELLED",
+        CMSG_BEGIN_TRADE => "CMSG_BEGIN_TRADE",
+        CMSG_CANCEL_TRADE => "CMSG_CANCEL_TRADE",
+        SMSG_TRADE_STATUS => "SMSG_TRADE_STATUS",
+        SMSG_PETITION_SHOW_SIGNATURES => "SMSG_PETITION_SHOW_SIGNATURES",
+        CMSG_PETITION_SIGN => "CMSG_PETITION_SIGN",
+        MSG_PETITION_DECLINE => "MSG_PETITION_DECLINE",
+        SMSG_GROUP_INVITE => "SMSG_GROUP_INVITE",
+        CMSG_GROUP_ACCEPT => "CMSG_GROUP_ACCEPT",
+        CMSG_GROUP_DECLINE => "CMSG_GROUP_DECLINE",
+        CMSG_GROUP_UNINVITE_GUID => "CMSG_GROUP_UNINVITE_GUID",
+        SMSG_GROUP_UNINVITE => "SMSG_GROUP_UNINVITE",
+        CMSG_GROUP_DISBAND => "CMSG_GROUP_DISBAND",
+        SMSG_GROUP_DESTROYED => "SMSG_GROUP_DESTROYED",
+        SMSG_GROUP_LIST => "SMSG_GROUP_LIST",
+        CMSG_WHO => "CMSG_WHO",
+        SMSG_WHO => "SMSG_WHO",
         CMSG_NAME_QUERY => "CMSG_NAME_QUERY",
         SMSG_NAME_QUERY => "SMSG_NAME_QUERY",
         SMSG_WARDEN_DATA => "SMSG_WARDEN_DATA",
@@ -192,6 +284,10 @@ pub fn opcode_name(opcode: u16) -> &'static str {
         SMSG_GM_MESSAGECHAT => "SMSG_GM_MESSAGECHAT",
         SMSG_SERVER_MESSAGE => "SMSG_SERVER_MESSAGE",
         SMSG_INIT_WORLD_STATES => "SMSG_INIT_WORLD_STATES",
+        SMSG_UPDATE_OBJECT => "SMSG_UPDATE_OBJECT",
+        SMSG_COMPRESSED_UPDATE_OBJECT => "SMSG_COMPRESSED_UPDATE_OBJECT",
+        SMSG_DESTROY_OBJECT => "SMSG_DESTROY_OBJECT",
+        SMSG_INVALIDATE_PLAYER => "SMSG_INVALIDATE_PLAYER",
         CMSG_GAMEOBJ_USE => "CMSG_GAMEOBJ_USE",
         _ => "UNKNOWN",
     }